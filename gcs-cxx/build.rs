@@ -22,6 +22,7 @@ fn main() {
     let platform_include_win = format!("{godot_path}/platform/{}", platform);
     cxx_build::bridges(vec![
         "src/godot/variant.rs",
+        "src/godot/dictionary.rs",
         "src/component/component_value.rs",
         "src/component/component_definition.rs",
         "src/ecs_world.rs",
@@ -29,12 +30,17 @@ fn main() {
     .include(Path::new("include"))
     .include(Path::new(&godot_path))
     .include(Path::new(&platform_include_win))
-    .files(vec!["src/godot/string.cpp", "src/godot/variant.cpp"])
+    .files(vec![
+        "src/godot/string.cpp",
+        "src/godot/variant.cpp",
+        "src/godot/dictionary.cpp",
+    ])
     .flag_if_supported("-std=g++14")
     .define("RUST_CXX_NO_EXCEPTIONS", None)
     .compile("godot-component-system");
 
     println!("cargo:rerun-if-changed=src/godot/variant.rs");
+    println!("cargo:rerun-if-changed=src/godot/dictionary.rs");
     println!("cargo:rerun-if-changed=src/component/component_value.rs");
     println!("cargo:rerun-if-changed=src/component/component_definition.rs");
     println!("cargo:rerun-if-changed=src/component/component_data.rs");
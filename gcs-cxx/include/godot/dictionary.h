@@ -0,0 +1,20 @@
+#ifndef GODOT_COMPONENT_SYSTEM_DICTIONARY_H
+#define GODOT_COMPONENT_SYSTEM_DICTIONARY_H
+#include "core/dictionary.h"
+#include "rust/cxx.h"
+#include "gcs-cxx/include/godot/variant.h"
+
+namespace gcs {
+    namespace ffi {
+        using Dictionary = ::Dictionary;
+
+        rust::Vec<rust::string> dictionary_keys(const Dictionary &dictionary);
+
+        const Variant &dictionary_get(const Dictionary &dictionary, rust::string key);
+
+        void dictionary_set(Dictionary &dictionary, rust::string key, const Variant &value);
+
+        std::unique_ptr<Dictionary> variant_as_dictionary(const Variant &variant);
+    }
+}
+#endif //GODOT_COMPONENT_SYSTEM_DICTIONARY_H
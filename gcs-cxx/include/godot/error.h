@@ -0,0 +1,30 @@
+#ifndef GODOT_COMPONENT_SYSTEM_ERROR_H
+#define GODOT_COMPONENT_SYSTEM_ERROR_H
+#include "core/error_list.h"
+#include "gcs-cxx/src/ecs_world.rs.h"
+
+inline Error error_from_error_code(gcs::ffi::ErrorCode code) {
+    switch (code) {
+        case gcs::ffi::ErrorCode::Ok:
+            return OK;
+        case gcs::ffi::ErrorCode::DoesNotExist:
+            return ERR_DOES_NOT_EXIST;
+        case gcs::ffi::ErrorCode::AlreadyExists:
+            return ERR_ALREADY_EXISTS;
+        case gcs::ffi::ErrorCode::Busy:
+            return ERR_BUSY;
+        case gcs::ffi::ErrorCode::Unavailable:
+            return ERR_UNAVAILABLE;
+        case gcs::ffi::ErrorCode::InvalidParameter:
+            return ERR_INVALID_PARAMETER;
+        case gcs::ffi::ErrorCode::CantOpen:
+            return ERR_CANT_OPEN;
+        case gcs::ffi::ErrorCode::ParseError:
+            return ERR_PARSE_ERROR;
+        case gcs::ffi::ErrorCode::InvalidData:
+            return ERR_INVALID_DATA;
+        default:
+            return FAILED;
+    }
+}
+#endif //GODOT_COMPONENT_SYSTEM_ERROR_H
@@ -1,5 +1,6 @@
 #ifndef GODOT_COMPONENT_SYSTEM_VARIANT_H
 #define GODOT_COMPONENT_SYSTEM_VARIANT_H
+#include <memory>
 #include "core/variant.h"
 #include "rust/cxx.h"
 
@@ -8,6 +9,17 @@ namespace gcs {
         using Variant = ::Variant;
         using VariantType = Variant::Type;
 
+        struct Vector2;
+        struct Rect2;
+        struct Vector3;
+        struct Transform2D;
+        struct Plane;
+        struct Quat;
+        struct AABB;
+        struct Basis;
+        struct Transform;
+        struct Color;
+
         int64_t variant_as_i64(const Variant &variant);
 
         rust::string variant_as_string(const Variant &variant);
@@ -16,6 +28,50 @@ namespace gcs {
 
         double variant_as_f64(const Variant &variant);
 
+        Vector2 variant_as_vector2(const Variant &variant);
+
+        Rect2 variant_as_rect2(const Variant &variant);
+
+        Vector3 variant_as_vector3(const Variant &variant);
+
+        Transform2D variant_as_transform2d(const Variant &variant);
+
+        Plane variant_as_plane(const Variant &variant);
+
+        Quat variant_as_quat(const Variant &variant);
+
+        AABB variant_as_aabb(const Variant &variant);
+
+        Basis variant_as_basis(const Variant &variant);
+
+        Transform variant_as_transform(const Variant &variant);
+
+        Color variant_as_color(const Variant &variant);
+
+        rust::Vec<uint8_t> variant_as_pool_byte_array(const Variant &variant);
+
+        rust::Vec<int32_t> variant_as_pool_int_array(const Variant &variant);
+
+        rust::Vec<float> variant_as_pool_real_array(const Variant &variant);
+
+        rust::Vec<rust::string> variant_as_pool_string_array(const Variant &variant);
+
+        rust::Vec<Vector2> variant_as_pool_vector2_array(const Variant &variant);
+
+        rust::Vec<Vector3> variant_as_pool_vector3_array(const Variant &variant);
+
+        rust::Vec<Color> variant_as_pool_color_array(const Variant &variant);
+
+        size_t variant_array_len(const Variant &array);
+
+        const Variant &variant_array_get(const Variant &array, size_t index);
+
+        size_t variant_dictionary_len(const Variant &dictionary);
+
+        const Variant &variant_dictionary_key(const Variant &dictionary, size_t index);
+
+        const Variant &variant_dictionary_get(const Variant &dictionary, const Variant &key);
+
         const Variant &empty_variant();
 
         const Variant &variant_from_i64(int64_t value);
@@ -25,6 +81,50 @@ namespace gcs {
         const Variant &variant_from_bool(bool value);
 
         const Variant &variant_from_f64(double value);
+
+        const Variant &variant_from_vector2(Vector2 value);
+
+        const Variant &variant_from_rect2(Rect2 value);
+
+        const Variant &variant_from_vector3(Vector3 value);
+
+        const Variant &variant_from_transform2d(Transform2D value);
+
+        const Variant &variant_from_plane(Plane value);
+
+        const Variant &variant_from_quat(Quat value);
+
+        const Variant &variant_from_aabb(AABB value);
+
+        const Variant &variant_from_basis(Basis value);
+
+        const Variant &variant_from_transform(Transform value);
+
+        const Variant &variant_from_color(Color value);
+
+        const Variant &variant_from_pool_byte_array(rust::Slice<const uint8_t> value);
+
+        const Variant &variant_from_pool_int_array(rust::Slice<const int32_t> value);
+
+        const Variant &variant_from_pool_real_array(rust::Slice<const float> value);
+
+        const Variant &variant_from_pool_string_array(rust::Slice<const rust::string> value);
+
+        const Variant &variant_from_pool_vector2_array(rust::Slice<const Vector2> value);
+
+        const Variant &variant_from_pool_vector3_array(rust::Slice<const Vector3> value);
+
+        const Variant &variant_from_pool_color_array(rust::Slice<const Color> value);
+
+        std::unique_ptr<Variant> new_array_variant();
+
+        std::unique_ptr<Variant> new_dictionary_variant();
+
+        const Variant &release_variant(std::unique_ptr<Variant> value);
+
+        void variant_array_push(Variant &array, const Variant &value);
+
+        void variant_dictionary_set(Variant &dictionary, const Variant &key, const Variant &value);
     }
 }
 #endif //GODOT_COMPONENT_SYSTEM_VARIANT_H
@@ -1,13 +1,24 @@
-use crate::component::component_value::CXXComponentValue;
+use crate::component::component_definition::CXXComponentDefinition;
+use crate::component::component_value::{
+    component_value_from_variant, entity_value_from_variant, variant_from_component_value,
+    CXXComponentValue,
+};
 use crate::entity::CXXEntityId;
+use crate::godot::dictionary::ffi::{dictionary_get, dictionary_keys, dictionary_set, Dictionary};
 use cxx::{type_id, ExternType};
 use gcs::component::component_data::ComponentData;
-use std::collections::HashMap;
+use gcs::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+use gcs::component::field_layout::{FieldLayout, FieldValues};
+use gcs::variant::VariantType;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::pin::Pin;
+use std::rc::Rc;
 
 #[derive(Default, Clone)]
 pub struct CXXComponentData {
     entity: CXXEntityId,
-    fields: HashMap<String, CXXComponentValue>,
+    fields: FieldValues<CXXComponentValue>,
 }
 
 impl ComponentData for CXXComponentData {
@@ -15,9 +26,13 @@ impl ComponentData for CXXComponentData {
     type ComponentValueType = CXXComponentValue;
 
     fn new(entity: CXXEntityId) -> Self {
+        Self::with_layout(entity, Rc::default())
+    }
+
+    fn with_layout(entity: CXXEntityId, layout: Rc<FieldLayout>) -> Self {
         Self {
             entity,
-            fields: HashMap::new(),
+            fields: FieldValues::new(layout),
         }
     }
 
@@ -25,29 +40,150 @@ impl ComponentData for CXXComponentData {
         self.entity
     }
 
-    fn get_field(&self, field: String) -> &Self::ComponentValueType {
-        if self.fields.contains_key(&field) {
-            &self.fields.get(&field).unwrap()
-        } else {
-            &Self::ComponentValueType::Nil
-        }
+    fn get_layout(&self) -> &FieldLayout {
+        self.fields.get_layout()
+    }
+
+    fn get_field(&self, field: &str) -> &Self::ComponentValueType {
+        self.fields
+            .get(field)
+            .unwrap_or(&Self::ComponentValueType::Nil)
+    }
+
+    fn set_field(&mut self, field: &str, value: &Self::ComponentValueType) {
+        self.fields.set(field, value.clone());
     }
 
-    fn set_field(&mut self, field: String, value: &Self::ComponentValueType) {
-        self.fields.insert(field, value.clone());
+    fn get_field_at(&self, index: usize) -> &Self::ComponentValueType {
+        self.fields
+            .get_at(index)
+            .unwrap_or(&Self::ComponentValueType::Nil)
+    }
+
+    fn set_field_at(&mut self, index: usize, value: &Self::ComponentValueType) {
+        self.fields.set_at(index, value.clone());
     }
 }
 
 impl CXXComponentData {
     pub(crate) fn get_field(&self, field: String) -> &CXXComponentValue {
-        ComponentData::get_field(self, field)
+        ComponentData::get_field(self, &field)
     }
 
     pub(crate) fn set_field(&mut self, field: String, value: &CXXComponentValue) {
-        ComponentData::set_field(self, field, &value)
+        ComponentData::set_field(self, &field, &value)
+    }
+
+    pub(crate) fn get_entity(&self) -> Box<CXXEntityId> {
+        Box::new(ComponentData::get_entity(self))
     }
+
+    /// Creates component data from a Dictionary that has exactly the fields of `definition`.
+    pub(crate) fn from_dictionary(
+        entity: CXXEntityId,
+        component: &str,
+        definition: &CXXComponentDefinition,
+        dictionary: &Dictionary,
+    ) -> Result<Self, DictionaryConversionError> {
+        let fields = definition.get_fields();
+        let keys = dictionary_keys(dictionary);
+        let unknown_fields: Vec<String> = keys
+            .iter()
+            .filter(|key| !fields.iter().any(|field| field.get_name() == **key))
+            .cloned()
+            .collect();
+        let missing_fields: Vec<String> = fields
+            .iter()
+            .map(|field| field.get_name())
+            .filter(|name| !keys.contains(name))
+            .collect();
+        if !unknown_fields.is_empty() || !missing_fields.is_empty() {
+            return Err(DictionaryConversionError {
+                component: component.to_string(),
+                unknown_fields,
+                missing_fields,
+                invalid_fields: Vec::new(),
+            });
+        }
+
+        let mut data = Self::with_layout(entity, Rc::new(FieldLayout::from_definition(definition)));
+        let mut invalid_fields = Vec::new();
+        for field in fields {
+            let variant = dictionary_get(dictionary, field.get_name());
+            let value = match field.get_type() {
+                VariantType::Entity => match entity_value_from_variant(variant) {
+                    None => {
+                        invalid_fields.push(field.get_name());
+                        continue;
+                    }
+                    Some(value) => value,
+                },
+                _ => *component_value_from_variant(variant),
+            };
+            ComponentData::set_field(&mut data, &field.get_name(), &value);
+        }
+        if !invalid_fields.is_empty() {
+            return Err(DictionaryConversionError {
+                component: component.to_string(),
+                unknown_fields: Vec::new(),
+                missing_fields: Vec::new(),
+                invalid_fields,
+            });
+        }
+        Ok(data)
+    }
+
+    /// Writes every field of `definition` into `dictionary`.
+    pub(crate) fn to_dictionary(
+        &self,
+        definition: &CXXComponentDefinition,
+        mut dictionary: Pin<&mut Dictionary>,
+    ) {
+        for field in definition.get_fields() {
+            let value = ComponentData::get_field(self, &field.get_name());
+            dictionary_set(
+                dictionary.as_mut(),
+                field.get_name(),
+                variant_from_component_value(value),
+            );
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) struct DictionaryConversionError {
+    component: String,
+    unknown_fields: Vec<String>,
+    missing_fields: Vec<String>,
+    invalid_fields: Vec<String>,
 }
 
+impl Display for DictionaryConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Dictionary does not match component '{}'",
+            self.component
+        )?;
+        if !self.unknown_fields.is_empty() {
+            write!(f, ", unknown fields: {}", self.unknown_fields.join(", "))?;
+        }
+        if !self.missing_fields.is_empty() {
+            write!(f, ", missing fields: {}", self.missing_fields.join(", "))?;
+        }
+        if !self.invalid_fields.is_empty() {
+            write!(
+                f,
+                ", invalid values for: {}",
+                self.invalid_fields.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for DictionaryConversionError {}
+
 pub(crate) fn create_component_data(entity: &CXXEntityId) -> Box<CXXComponentData> {
     Box::new(CXXComponentData::new(*entity))
 }
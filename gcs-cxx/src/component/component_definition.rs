@@ -1,4 +1,5 @@
 use crate::component::component_definition::ffi::CXXComponentFieldDefinition;
+use crate::godot::variant::CXXVariantType;
 use cxx::{type_id, ExternType};
 use gcs::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
 use gcs::variant::VariantType;
@@ -31,9 +32,21 @@ pub mod ffi {
     extern "C++" {
         type VariantType = crate::godot::variant::CXXVariantType;
     }
+
+    impl Vec<CXXComponentFieldDefinition> {}
 }
 
 impl ComponentFieldDefinition for CXXComponentFieldDefinition {
+    fn create(name: String, field_type: VariantType) -> Self
+    where
+        Self: Sized,
+    {
+        Self {
+            name,
+            field_type: CXXVariantType(field_type),
+        }
+    }
+
     fn get_type(&self) -> VariantType {
         self.field_type.0
     }
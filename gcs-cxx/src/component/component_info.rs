@@ -4,6 +4,8 @@ use gcs::component::component_info::ComponentInfo;
 #[derive(Hash, Eq, PartialEq, Clone, Copy, Default)]
 pub struct CXXComponentInfo {
     hash: u64,
+    index: u32,
+    world_id: u64,
 }
 
 unsafe impl ExternType for CXXComponentInfo {
@@ -16,14 +18,32 @@ impl ComponentInfo for CXXComponentInfo {
         self.hash
     }
 
-    fn create(hash: u64) -> Self
+    fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    fn get_world_id(&self) -> u64 {
+        self.world_id
+    }
+
+    fn create(hash: u64, index: u32, world_id: u64) -> Self
     where
         Self: Sized,
     {
-        CXXComponentInfo { hash }
+        CXXComponentInfo {
+            hash,
+            index,
+            world_id,
+        }
+    }
+}
+
+impl CXXComponentInfo {
+    pub(crate) fn get_hash(&self) -> u64 {
+        ComponentInfo::get_hash(self)
     }
 }
 
 pub(crate) fn create_component_info(hash: u64) -> Box<CXXComponentInfo> {
-    Box::new(CXXComponentInfo::create(hash))
+    Box::new(CXXComponentInfo::create(hash, 0, 0))
 }
@@ -1,11 +1,31 @@
 use crate::component::component_value::ffi::{
-    empty_variant, variant_from_bool, variant_from_f64, variant_from_i64, variant_from_string,
+    empty_variant, new_array_variant, new_dictionary_variant, release_variant, variant_array_push,
+    variant_dictionary_set, variant_from_aabb, variant_from_basis, variant_from_bool,
+    variant_from_color, variant_from_f64, variant_from_i64, variant_from_plane,
+    variant_from_pool_byte_array, variant_from_pool_color_array, variant_from_pool_int_array,
+    variant_from_pool_real_array, variant_from_pool_string_array, variant_from_pool_vector2_array,
+    variant_from_pool_vector3_array, variant_from_quat, variant_from_rect2, variant_from_string,
+    variant_from_transform, variant_from_transform2d, variant_from_vector2, variant_from_vector3,
 };
+use crate::entity::CXXEntityId;
 use crate::godot::variant::ffi::{
-    variant_as_bool, variant_as_f64, variant_as_i64, variant_as_string, CXXVariantType, Variant,
+    variant_array_get, variant_array_len, variant_as_aabb, variant_as_basis, variant_as_bool,
+    variant_as_color, variant_as_f64, variant_as_i64, variant_as_plane, variant_as_pool_byte_array,
+    variant_as_pool_color_array, variant_as_pool_int_array, variant_as_pool_real_array,
+    variant_as_pool_string_array, variant_as_pool_vector2_array, variant_as_pool_vector3_array,
+    variant_as_quat, variant_as_rect2, variant_as_string, variant_as_transform,
+    variant_as_transform2d, variant_as_vector2, variant_as_vector3, variant_dictionary_get,
+    variant_dictionary_key, variant_dictionary_len, CXXColor, CXXVariantType, CXXVector2,
+    CXXVector3, Variant,
 };
 use cxx::{type_id, ExternType};
-use gcs::component::component_value::ComponentValue;
+use gcs::component::component_value::{
+    array_to_string, dictionary_to_string, pool_array_to_string, ComponentValue,
+};
+use gcs::component::value_conversion::Scalar;
+use gcs::component::value_dictionary::ValueDictionary;
+use gcs::entity::EntityId;
+use gcs::math::{Aabb, Basis, Color, Plane, Quat, Rect2, Transform, Transform2D, Vector2, Vector3};
 use gcs::variant::VariantType;
 
 #[cxx::bridge(namespace = gcs::ffi)]
@@ -19,15 +39,61 @@ pub mod ffi {
 
     unsafe extern "C++" {
         include!("gcs-cxx/include/godot/variant.h");
+        include!("gcs-cxx/src/godot/variant.rs.h");
         include!("rust/cxx.h");
         pub type Variant = crate::godot::variant::ffi::Variant;
+        #[cxx_name = "Vector2"]
+        type CXXVector2 = crate::godot::variant::ffi::CXXVector2;
+        #[cxx_name = "Rect2"]
+        type CXXRect2 = crate::godot::variant::ffi::CXXRect2;
+        #[cxx_name = "Vector3"]
+        type CXXVector3 = crate::godot::variant::ffi::CXXVector3;
+        #[cxx_name = "Transform2D"]
+        type CXXTransform2D = crate::godot::variant::ffi::CXXTransform2D;
+        #[cxx_name = "Plane"]
+        type CXXPlane = crate::godot::variant::ffi::CXXPlane;
+        #[cxx_name = "Quat"]
+        type CXXQuat = crate::godot::variant::ffi::CXXQuat;
+        #[cxx_name = "AABB"]
+        type CXXAabb = crate::godot::variant::ffi::CXXAabb;
+        #[cxx_name = "Basis"]
+        type CXXBasis = crate::godot::variant::ffi::CXXBasis;
+        #[cxx_name = "Transform"]
+        type CXXTransform = crate::godot::variant::ffi::CXXTransform;
+        #[cxx_name = "Color"]
+        type CXXColor = crate::godot::variant::ffi::CXXColor;
 
         pub(crate) fn empty_variant() -> &'static Variant;
         pub(crate) fn variant_from_i64(value: i64) -> &'static Variant;
         pub(crate) fn variant_from_string(value: String) -> &'static Variant;
         pub(crate) fn variant_from_bool(value: bool) -> &'static Variant;
         pub(crate) fn variant_from_f64(value: f64) -> &'static Variant;
-
+        pub(crate) fn variant_from_vector2(value: CXXVector2) -> &'static Variant;
+        pub(crate) fn variant_from_rect2(value: CXXRect2) -> &'static Variant;
+        pub(crate) fn variant_from_vector3(value: CXXVector3) -> &'static Variant;
+        pub(crate) fn variant_from_transform2d(value: CXXTransform2D) -> &'static Variant;
+        pub(crate) fn variant_from_plane(value: CXXPlane) -> &'static Variant;
+        pub(crate) fn variant_from_quat(value: CXXQuat) -> &'static Variant;
+        pub(crate) fn variant_from_aabb(value: CXXAabb) -> &'static Variant;
+        pub(crate) fn variant_from_basis(value: CXXBasis) -> &'static Variant;
+        pub(crate) fn variant_from_transform(value: CXXTransform) -> &'static Variant;
+        pub(crate) fn variant_from_color(value: CXXColor) -> &'static Variant;
+        pub(crate) fn variant_from_pool_byte_array(value: &[u8]) -> &'static Variant;
+        pub(crate) fn variant_from_pool_int_array(value: &[i32]) -> &'static Variant;
+        pub(crate) fn variant_from_pool_real_array(value: &[f32]) -> &'static Variant;
+        pub(crate) fn variant_from_pool_string_array(value: &[String]) -> &'static Variant;
+        pub(crate) fn variant_from_pool_vector2_array(value: &[CXXVector2]) -> &'static Variant;
+        pub(crate) fn variant_from_pool_vector3_array(value: &[CXXVector3]) -> &'static Variant;
+        pub(crate) fn variant_from_pool_color_array(value: &[CXXColor]) -> &'static Variant;
+        pub(crate) fn new_array_variant() -> UniquePtr<Variant>;
+        pub(crate) fn new_dictionary_variant() -> UniquePtr<Variant>;
+        pub(crate) fn release_variant(value: UniquePtr<Variant>) -> &'static Variant;
+        pub(crate) fn variant_array_push(array: Pin<&mut Variant>, value: &Variant);
+        pub(crate) fn variant_dictionary_set(
+            dictionary: Pin<&mut Variant>,
+            key: &Variant,
+            value: &Variant,
+        );
     }
 }
 
@@ -38,6 +104,26 @@ pub enum CXXComponentValue {
     String(String),
     Bool(bool),
     Real(f64),
+    Vector2(Vector2),
+    Rect2(Rect2),
+    Vector3(Vector3),
+    Transform2D(Transform2D),
+    Plane(Plane),
+    Quat(Quat),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform(Transform),
+    Color(Color),
+    Array(Vec<CXXComponentValue>),
+    Dictionary(ValueDictionary<CXXComponentValue>),
+    PoolByteArray(Vec<u8>),
+    PoolIntArray(Vec<i32>),
+    PoolRealArray(Vec<f32>),
+    PoolStringArray(Vec<String>),
+    PoolVector2Array(Vec<Vector2>),
+    PoolVector3Array(Vec<Vector3>),
+    PoolColorArray(Vec<Color>),
+    Entity(CXXEntityId),
 }
 
 impl Default for CXXComponentValue {
@@ -47,6 +133,8 @@ impl Default for CXXComponentValue {
 }
 
 impl ComponentValue for CXXComponentValue {
+    type EntityIdType = CXXEntityId;
+
     fn get_type(&self) -> VariantType {
         match self {
             CXXComponentValue::Nil => VariantType::Nil,
@@ -54,6 +142,37 @@ impl ComponentValue for CXXComponentValue {
             CXXComponentValue::String(_) => VariantType::String,
             CXXComponentValue::Bool(_) => VariantType::Bool,
             CXXComponentValue::Real(_) => VariantType::Real,
+            CXXComponentValue::Vector2(_) => VariantType::Vector2,
+            CXXComponentValue::Rect2(_) => VariantType::Rect2,
+            CXXComponentValue::Vector3(_) => VariantType::Vector3,
+            CXXComponentValue::Transform2D(_) => VariantType::Transform2D,
+            CXXComponentValue::Plane(_) => VariantType::Plan,
+            CXXComponentValue::Quat(_) => VariantType::Quat,
+            CXXComponentValue::Aabb(_) => VariantType::Aaab,
+            CXXComponentValue::Basis(_) => VariantType::Basis,
+            CXXComponentValue::Transform(_) => VariantType::Transform,
+            CXXComponentValue::Color(_) => VariantType::Color,
+            CXXComponentValue::Array(_) => VariantType::Array,
+            CXXComponentValue::Dictionary(_) => VariantType::Dictionary,
+            CXXComponentValue::PoolByteArray(_) => VariantType::PoolByteArray,
+            CXXComponentValue::PoolIntArray(_) => VariantType::PoolIntArray,
+            CXXComponentValue::PoolRealArray(_) => VariantType::PoolRealArray,
+            CXXComponentValue::PoolStringArray(_) => VariantType::PoolStringArray,
+            CXXComponentValue::PoolVector2Array(_) => VariantType::PoolVector2Array,
+            CXXComponentValue::PoolVector3Array(_) => VariantType::PoolVector3Array,
+            CXXComponentValue::PoolColorArray(_) => VariantType::PoolColorArray,
+            CXXComponentValue::Entity(_) => VariantType::Entity,
+        }
+    }
+
+    fn as_scalar(&self) -> Scalar<'_> {
+        match self {
+            CXXComponentValue::Nil => Scalar::Nil,
+            CXXComponentValue::Bool(value) => Scalar::Bool(*value),
+            CXXComponentValue::Int(value) => Scalar::Int(*value),
+            CXXComponentValue::Real(value) => Scalar::Real(*value),
+            CXXComponentValue::String(value) => Scalar::String(value),
+            _ => Scalar::Other(self.get_type()),
         }
     }
 
@@ -67,28 +186,6 @@ impl ComponentValue for CXXComponentValue {
         *self = CXXComponentValue::Int(value)
     }
 
-    fn get_int(&self) -> i64 {
-        match self {
-            CXXComponentValue::Nil => 0,
-            CXXComponentValue::Int(value) => *value,
-            CXXComponentValue::String(value) => {
-                let result = str::parse::<i64>(value);
-                match result {
-                    Ok(value) => value,
-                    Err(_) => 0,
-                }
-            }
-            CXXComponentValue::Bool(value) => {
-                if *value {
-                    1
-                } else {
-                    0
-                }
-            }
-            CXXComponentValue::Real(value) => *value as i64,
-        }
-    }
-
     fn set_string(&mut self, value: String) {
         *self = CXXComponentValue::String(value)
     }
@@ -100,6 +197,26 @@ impl ComponentValue for CXXComponentValue {
             CXXComponentValue::String(value) => value.clone(),
             CXXComponentValue::Bool(value) => value.to_string(),
             CXXComponentValue::Real(value) => value.to_string(),
+            CXXComponentValue::Vector2(value) => value.to_string(),
+            CXXComponentValue::Rect2(value) => value.to_string(),
+            CXXComponentValue::Vector3(value) => value.to_string(),
+            CXXComponentValue::Transform2D(value) => value.to_string(),
+            CXXComponentValue::Plane(value) => value.to_string(),
+            CXXComponentValue::Quat(value) => value.to_string(),
+            CXXComponentValue::Aabb(value) => value.to_string(),
+            CXXComponentValue::Basis(value) => value.to_string(),
+            CXXComponentValue::Transform(value) => value.to_string(),
+            CXXComponentValue::Color(value) => value.to_string(),
+            CXXComponentValue::Array(value) => array_to_string(value),
+            CXXComponentValue::Dictionary(value) => dictionary_to_string(value),
+            CXXComponentValue::PoolByteArray(value) => pool_array_to_string(value),
+            CXXComponentValue::PoolIntArray(value) => pool_array_to_string(value),
+            CXXComponentValue::PoolRealArray(value) => pool_array_to_string(value),
+            CXXComponentValue::PoolStringArray(value) => pool_array_to_string(value),
+            CXXComponentValue::PoolVector2Array(value) => pool_array_to_string(value),
+            CXXComponentValue::PoolVector3Array(value) => pool_array_to_string(value),
+            CXXComponentValue::PoolColorArray(value) => pool_array_to_string(value),
+            CXXComponentValue::Entity(value) => value.as_string(),
         }
     }
 
@@ -107,39 +224,227 @@ impl ComponentValue for CXXComponentValue {
         *self = CXXComponentValue::Bool(value)
     }
 
-    fn get_bool(&self) -> bool {
+    fn set_real(&mut self, value: f64) {
+        *self = CXXComponentValue::Real(value)
+    }
+
+    fn set_vector2(&mut self, value: Vector2) {
+        *self = CXXComponentValue::Vector2(value)
+    }
+
+    fn get_vector2(&self) -> Vector2 {
         match self {
-            CXXComponentValue::Nil => false,
-            CXXComponentValue::Int(value) => *value != 0,
-            CXXComponentValue::String(value) => value.is_empty(),
-            CXXComponentValue::Bool(value) => *value,
-            CXXComponentValue::Real(value) => *value != 0.0,
+            CXXComponentValue::Vector2(value) => *value,
+            _ => Vector2::default(),
         }
     }
 
-    fn set_real(&mut self, value: f64) {
-        *self = CXXComponentValue::Real(value)
+    fn set_rect2(&mut self, value: Rect2) {
+        *self = CXXComponentValue::Rect2(value)
     }
 
-    fn get_real(&self) -> f64 {
+    fn get_rect2(&self) -> Rect2 {
         match self {
-            CXXComponentValue::Nil => 0.0,
-            CXXComponentValue::Int(value) => *value as f64,
-            CXXComponentValue::String(value) => {
-                let result = str::parse::<f64>(value);
-                match result {
-                    Ok(value) => value,
-                    Err(_) => 0.0,
-                }
-            }
-            CXXComponentValue::Bool(value) => {
-                if *value {
-                    1.0
-                } else {
-                    0.0
-                }
-            }
-            CXXComponentValue::Real(value) => *value,
+            CXXComponentValue::Rect2(value) => *value,
+            _ => Rect2::default(),
+        }
+    }
+
+    fn set_vector3(&mut self, value: Vector3) {
+        *self = CXXComponentValue::Vector3(value)
+    }
+
+    fn get_vector3(&self) -> Vector3 {
+        match self {
+            CXXComponentValue::Vector3(value) => *value,
+            _ => Vector3::default(),
+        }
+    }
+
+    fn set_transform2d(&mut self, value: Transform2D) {
+        *self = CXXComponentValue::Transform2D(value)
+    }
+
+    fn get_transform2d(&self) -> Transform2D {
+        match self {
+            CXXComponentValue::Transform2D(value) => *value,
+            _ => Transform2D::default(),
+        }
+    }
+
+    fn set_plane(&mut self, value: Plane) {
+        *self = CXXComponentValue::Plane(value)
+    }
+
+    fn get_plane(&self) -> Plane {
+        match self {
+            CXXComponentValue::Plane(value) => *value,
+            _ => Plane::default(),
+        }
+    }
+
+    fn set_quat(&mut self, value: Quat) {
+        *self = CXXComponentValue::Quat(value)
+    }
+
+    fn get_quat(&self) -> Quat {
+        match self {
+            CXXComponentValue::Quat(value) => *value,
+            _ => Quat::default(),
+        }
+    }
+
+    fn set_aabb(&mut self, value: Aabb) {
+        *self = CXXComponentValue::Aabb(value)
+    }
+
+    fn get_aabb(&self) -> Aabb {
+        match self {
+            CXXComponentValue::Aabb(value) => *value,
+            _ => Aabb::default(),
+        }
+    }
+
+    fn set_basis(&mut self, value: Basis) {
+        *self = CXXComponentValue::Basis(value)
+    }
+
+    fn get_basis(&self) -> Basis {
+        match self {
+            CXXComponentValue::Basis(value) => *value,
+            _ => Basis::default(),
+        }
+    }
+
+    fn set_transform(&mut self, value: Transform) {
+        *self = CXXComponentValue::Transform(value)
+    }
+
+    fn get_transform(&self) -> Transform {
+        match self {
+            CXXComponentValue::Transform(value) => *value,
+            _ => Transform::default(),
+        }
+    }
+
+    fn set_color(&mut self, value: Color) {
+        *self = CXXComponentValue::Color(value)
+    }
+
+    fn get_color(&self) -> Color {
+        match self {
+            CXXComponentValue::Color(value) => *value,
+            _ => Color::default(),
+        }
+    }
+
+    fn set_array(&mut self, value: Vec<CXXComponentValue>) {
+        *self = CXXComponentValue::Array(value)
+    }
+
+    fn get_array(&self) -> Vec<CXXComponentValue> {
+        match self {
+            CXXComponentValue::Array(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_dictionary(&mut self, value: ValueDictionary<CXXComponentValue>) {
+        *self = CXXComponentValue::Dictionary(value)
+    }
+
+    fn get_dictionary(&self) -> ValueDictionary<CXXComponentValue> {
+        match self {
+            CXXComponentValue::Dictionary(value) => value.clone(),
+            _ => ValueDictionary::new(),
+        }
+    }
+
+    fn set_pool_byte_array(&mut self, value: Vec<u8>) {
+        *self = CXXComponentValue::PoolByteArray(value)
+    }
+
+    fn get_pool_byte_array(&self) -> Vec<u8> {
+        match self {
+            CXXComponentValue::PoolByteArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_int_array(&mut self, value: Vec<i32>) {
+        *self = CXXComponentValue::PoolIntArray(value)
+    }
+
+    fn get_pool_int_array(&self) -> Vec<i32> {
+        match self {
+            CXXComponentValue::PoolIntArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_real_array(&mut self, value: Vec<f32>) {
+        *self = CXXComponentValue::PoolRealArray(value)
+    }
+
+    fn get_pool_real_array(&self) -> Vec<f32> {
+        match self {
+            CXXComponentValue::PoolRealArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_string_array(&mut self, value: Vec<String>) {
+        *self = CXXComponentValue::PoolStringArray(value)
+    }
+
+    fn get_pool_string_array(&self) -> Vec<String> {
+        match self {
+            CXXComponentValue::PoolStringArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_vector2_array(&mut self, value: Vec<Vector2>) {
+        *self = CXXComponentValue::PoolVector2Array(value)
+    }
+
+    fn get_pool_vector2_array(&self) -> Vec<Vector2> {
+        match self {
+            CXXComponentValue::PoolVector2Array(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_vector3_array(&mut self, value: Vec<Vector3>) {
+        *self = CXXComponentValue::PoolVector3Array(value)
+    }
+
+    fn get_pool_vector3_array(&self) -> Vec<Vector3> {
+        match self {
+            CXXComponentValue::PoolVector3Array(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_color_array(&mut self, value: Vec<Color>) {
+        *self = CXXComponentValue::PoolColorArray(value)
+    }
+
+    fn get_pool_color_array(&self) -> Vec<Color> {
+        match self {
+            CXXComponentValue::PoolColorArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_entity(&mut self, value: CXXEntityId) {
+        *self = CXXComponentValue::Entity(value)
+    }
+
+    fn get_entity(&self) -> Option<CXXEntityId> {
+        match self {
+            CXXComponentValue::Entity(value) => Some(*value),
+            _ => None,
         }
     }
 }
@@ -149,17 +454,68 @@ unsafe impl ExternType for CXXComponentValue {
     type Kind = cxx::kind::Opaque;
 }
 
-fn variant_from_component_value(value: &CXXComponentValue) -> &'static Variant {
+pub(crate) fn variant_from_component_value(value: &CXXComponentValue) -> &'static Variant {
     match value.clone() {
         CXXComponentValue::Nil => empty_variant(),
         CXXComponentValue::Int(value) => variant_from_i64(value),
         CXXComponentValue::String(value) => variant_from_string(value.clone()),
         CXXComponentValue::Bool(value) => variant_from_bool(value),
         CXXComponentValue::Real(value) => variant_from_f64(value),
+        CXXComponentValue::Vector2(value) => variant_from_vector2(value.into()),
+        CXXComponentValue::Rect2(value) => variant_from_rect2(value.into()),
+        CXXComponentValue::Vector3(value) => variant_from_vector3(value.into()),
+        CXXComponentValue::Transform2D(value) => variant_from_transform2d(value.into()),
+        CXXComponentValue::Plane(value) => variant_from_plane(value.into()),
+        CXXComponentValue::Quat(value) => variant_from_quat(value.into()),
+        CXXComponentValue::Aabb(value) => variant_from_aabb(value.into()),
+        CXXComponentValue::Basis(value) => variant_from_basis(value.into()),
+        CXXComponentValue::Transform(value) => variant_from_transform(value.into()),
+        CXXComponentValue::Color(value) => variant_from_color(value.into()),
+        CXXComponentValue::Entity(value) => variant_from_string(value.as_string()),
+        CXXComponentValue::Array(values) => {
+            let mut array = new_array_variant();
+            for value in values.iter() {
+                variant_array_push(array.pin_mut(), variant_from_component_value(value));
+            }
+            release_variant(array)
+        }
+        CXXComponentValue::Dictionary(values) => {
+            let mut dictionary = new_dictionary_variant();
+            for (key, value) in values.iter() {
+                variant_dictionary_set(
+                    dictionary.pin_mut(),
+                    variant_from_component_value(key),
+                    variant_from_component_value(value),
+                );
+            }
+            release_variant(dictionary)
+        }
+        CXXComponentValue::PoolByteArray(values) => variant_from_pool_byte_array(&values),
+        CXXComponentValue::PoolIntArray(values) => variant_from_pool_int_array(&values),
+        CXXComponentValue::PoolRealArray(values) => variant_from_pool_real_array(&values),
+        CXXComponentValue::PoolStringArray(values) => variant_from_pool_string_array(&values),
+        CXXComponentValue::PoolVector2Array(values) => variant_from_pool_vector2_array(
+            &values
+                .into_iter()
+                .map(CXXVector2::from)
+                .collect::<Vec<CXXVector2>>(),
+        ),
+        CXXComponentValue::PoolVector3Array(values) => variant_from_pool_vector3_array(
+            &values
+                .into_iter()
+                .map(CXXVector3::from)
+                .collect::<Vec<CXXVector3>>(),
+        ),
+        CXXComponentValue::PoolColorArray(values) => variant_from_pool_color_array(
+            &values
+                .into_iter()
+                .map(CXXColor::from)
+                .collect::<Vec<CXXColor>>(),
+        ),
     }
 }
 
-fn component_value_from_variant(value: &Variant) -> Box<CXXComponentValue> {
+pub(crate) fn component_value_from_variant(value: &Variant) -> Box<CXXComponentValue> {
     let variant_type: CXXVariantType = value.get_type();
 
     match variant_type.0 {
@@ -169,35 +525,23 @@ fn component_value_from_variant(value: &Variant) -> Box<CXXComponentValue> {
         VariantType::Real => Box::new(CXXComponentValue::Real(variant_as_f64(value))),
         VariantType::String => Box::new(CXXComponentValue::String(variant_as_string(value))),
         VariantType::Vector2 => {
-            unimplemented!()
-        }
-        VariantType::Rect2 => {
-            unimplemented!()
+            Box::new(CXXComponentValue::Vector2(variant_as_vector2(value).into()))
         }
+        VariantType::Rect2 => Box::new(CXXComponentValue::Rect2(variant_as_rect2(value).into())),
         VariantType::Vector3 => {
-            unimplemented!()
-        }
-        VariantType::Transform2D => {
-            unimplemented!()
-        }
-        VariantType::Plan => {
-            unimplemented!()
-        }
-        VariantType::Quat => {
-            unimplemented!()
-        }
-        VariantType::Aaab => {
-            unimplemented!()
-        }
-        VariantType::Basis => {
-            unimplemented!()
-        }
-        VariantType::Transform => {
-            unimplemented!()
-        }
-        VariantType::Color => {
-            unimplemented!()
+            Box::new(CXXComponentValue::Vector3(variant_as_vector3(value).into()))
         }
+        VariantType::Transform2D => Box::new(CXXComponentValue::Transform2D(
+            variant_as_transform2d(value).into(),
+        )),
+        VariantType::Plan => Box::new(CXXComponentValue::Plane(variant_as_plane(value).into())),
+        VariantType::Quat => Box::new(CXXComponentValue::Quat(variant_as_quat(value).into())),
+        VariantType::Aaab => Box::new(CXXComponentValue::Aabb(variant_as_aabb(value).into())),
+        VariantType::Basis => Box::new(CXXComponentValue::Basis(variant_as_basis(value).into())),
+        VariantType::Transform => Box::new(CXXComponentValue::Transform(
+            variant_as_transform(value).into(),
+        )),
+        VariantType::Color => Box::new(CXXComponentValue::Color(variant_as_color(value).into())),
         VariantType::NodePath => {
             unimplemented!()
         }
@@ -208,34 +552,68 @@ fn component_value_from_variant(value: &Variant) -> Box<CXXComponentValue> {
             unimplemented!()
         }
         VariantType::Dictionary => {
-            unimplemented!()
+            let dictionary = (0..variant_dictionary_len(value))
+                .map(|index| {
+                    let key = variant_dictionary_key(value, index);
+                    (
+                        *component_value_from_variant(key),
+                        *component_value_from_variant(variant_dictionary_get(value, key)),
+                    )
+                })
+                .collect();
+            Box::new(CXXComponentValue::Dictionary(dictionary))
         }
         VariantType::Array => {
-            unimplemented!()
-        }
-        VariantType::PoolByteArray => {
-            unimplemented!()
-        }
-        VariantType::PoolIntArray => {
-            unimplemented!()
+            let array = (0..variant_array_len(value))
+                .map(|index| *component_value_from_variant(variant_array_get(value, index)))
+                .collect();
+            Box::new(CXXComponentValue::Array(array))
         }
-        VariantType::PoolRealArray => {
-            unimplemented!()
-        }
-        VariantType::PoolStringArray => {
-            unimplemented!()
-        }
-        VariantType::PoolVector2Array => {
-            unimplemented!()
-        }
-        VariantType::PoolVector3Array => {
-            unimplemented!()
-        }
-        VariantType::PoolColorArray => {
-            unimplemented!()
-        }
-        VariantType::VariantMax => {
+        VariantType::PoolByteArray => Box::new(CXXComponentValue::PoolByteArray(
+            variant_as_pool_byte_array(value),
+        )),
+        VariantType::PoolIntArray => Box::new(CXXComponentValue::PoolIntArray(
+            variant_as_pool_int_array(value),
+        )),
+        VariantType::PoolRealArray => Box::new(CXXComponentValue::PoolRealArray(
+            variant_as_pool_real_array(value),
+        )),
+        VariantType::PoolStringArray => Box::new(CXXComponentValue::PoolStringArray(
+            variant_as_pool_string_array(value),
+        )),
+        VariantType::PoolVector2Array => Box::new(CXXComponentValue::PoolVector2Array(
+            variant_as_pool_vector2_array(value)
+                .into_iter()
+                .map(Vector2::from)
+                .collect(),
+        )),
+        VariantType::PoolVector3Array => Box::new(CXXComponentValue::PoolVector3Array(
+            variant_as_pool_vector3_array(value)
+                .into_iter()
+                .map(Vector3::from)
+                .collect(),
+        )),
+        VariantType::PoolColorArray => Box::new(CXXComponentValue::PoolColorArray(
+            variant_as_pool_color_array(value)
+                .into_iter()
+                .map(Color::from)
+                .collect(),
+        )),
+        VariantType::VariantMax | VariantType::Entity => {
             unimplemented!()
         }
     }
 }
+
+/// Entity references are stored as their id in Godot, so they can only be told apart from
+/// strings by the type of the field they are read for. `None` if `value` is not an entity id.
+pub(crate) fn entity_value_from_variant(value: &Variant) -> Option<CXXComponentValue> {
+    let variant_type: CXXVariantType = value.get_type();
+    match variant_type.0 {
+        VariantType::Nil => Some(CXXComponentValue::Nil),
+        VariantType::String => CXXEntityId::parse_str(&variant_as_string(value))
+            .ok()
+            .map(CXXComponentValue::Entity),
+        _ => None,
+    }
+}
@@ -1,27 +1,54 @@
+use std::path::Path;
+use std::pin::Pin;
 use std::string::String;
 
 use cxx::{type_id, ExternType};
 
+use gcs::component::component_definition::ComponentDefinition;
+use gcs::entity::entity_id_generator::EntityIdGeneration;
+use gcs::entity::EntityId;
 use gcs::world::ecs_world::{create_ecs_world, ECSWorld};
+use gcs::world::entity_reference::EntityReferencePolicy;
+use gcs::world::replay::ReplayLog;
 
 use crate::component::component_data::create_component_data;
 use crate::component::component_data::CXXComponentData;
+use crate::component::component_definition::ffi::CXXComponentFieldDefinition;
 use crate::component::component_definition::CXXComponentDefinition;
 use crate::component::component_info::create_component_info;
 use crate::component::component_info::CXXComponentInfo;
+use crate::ecs_world::ffi::ErrorCode;
 use crate::entity::create_entity;
 use crate::entity::entity_id_from_string;
 use crate::entity::CXXEntityId;
 use crate::entity::EntityIdResult;
-use crate::godot::error::GCSResult;
+use crate::godot::dictionary::ffi::{variant_as_dictionary, Dictionary};
+use crate::godot::error::{BatchResult, GCSResult};
+use crate::godot::variant::ffi::{variant_array_get, variant_array_len, Variant};
 
 #[cxx::bridge(namespace = gcs::ffi)]
 pub mod ffi {
+    /// Mirrors the subset of Godot's `Error` enum that the world API can return.
+    #[derive(Debug)]
+    enum ErrorCode {
+        Ok,
+        Failed,
+        DoesNotExist,
+        AlreadyExists,
+        Busy,
+        Unavailable,
+        InvalidParameter,
+        CantOpen,
+        ParseError,
+        InvalidData,
+    }
+
     extern "Rust" {
         type UnitResult;
 
         fn is_error(&self) -> bool;
         fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
     }
 
     extern "Rust" {
@@ -30,6 +57,56 @@ pub mod ffi {
         fn is_error(&self) -> bool;
         fn get_result(&self) -> Vec<String>;
         fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
+    }
+
+    extern "Rust" {
+        type StringResult;
+
+        fn is_error(&self) -> bool;
+        fn get_result(&self) -> String;
+        fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
+    }
+
+    extern "Rust" {
+        type ComponentFieldDefinitionVecResult;
+
+        fn is_error(&self) -> bool;
+        fn get_result(&self) -> Vec<ComponentFieldDefinition>;
+        fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
+    }
+
+    extern "Rust" {
+        type ComponentDataResult;
+
+        fn is_error(&self) -> bool;
+        fn get_result(&self) -> Box<CXXComponentData>;
+        fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
+    }
+
+    extern "Rust" {
+        type ComponentDataVecResult;
+
+        fn is_error(&self) -> bool;
+        fn get_len(&self) -> usize;
+        fn get_item(&self, index: usize) -> &CXXComponentData;
+        fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
+    }
+
+    extern "Rust" {
+        type BatchResult;
+
+        fn is_error(&self) -> bool;
+        fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
+        fn get_len(&self) -> usize;
+        fn get_entities(&self) -> Vec<String>;
+        fn get_item_error(&self, index: usize) -> String;
+        fn get_error_codes(&self) -> Vec<u8>;
     }
 
     extern "Rust" {
@@ -38,11 +115,14 @@ pub mod ffi {
         fn is_error(&self) -> bool;
         fn get_result(&self) -> Box<CXXEntityId>;
         fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
     }
 
     extern "Rust" {
         #[cxx_name = "ComponentInfo"]
         type CXXComponentInfo;
+
+        fn get_hash(&self) -> u64;
     }
 
     extern "Rust" {
@@ -50,6 +130,7 @@ pub mod ffi {
         type CXXComponentData;
         fn get_field(self: &CXXComponentData, field: String) -> &ComponentValue;
         fn set_field(self: &mut CXXComponentData, field: String, value: &ComponentValue);
+        fn get_entity(self: &CXXComponentData) -> Box<CXXEntityId>;
         fn create_component_data(entity: &CXXEntityId) -> Box<CXXComponentData>;
     }
     extern "Rust" {
@@ -67,6 +148,7 @@ pub mod ffi {
         fn is_error(&self) -> bool;
         fn get_result(&self) -> Box<CXXComponentInfo>;
         fn get_error(&self) -> String;
+        fn get_error_code(&self) -> ErrorCode;
 
     }
 
@@ -83,6 +165,15 @@ pub mod ffi {
 
         fn register_entity(&mut self, id: &CXXEntityId) -> Box<UnitResult>;
 
+        fn get_component_names(&self) -> Vec<String>;
+        fn get_component_info(&self, name: String) -> Box<ComponentInfoResult>;
+        fn get_component_name(&self, info: &CXXComponentInfo) -> Box<StringResult>;
+        fn get_component_fields(&self, name: String) -> Box<ComponentFieldDefinitionVecResult>;
+        fn get_component_fields_by_info(
+            &self,
+            info: &CXXComponentInfo,
+        ) -> Box<ComponentFieldDefinitionVecResult>;
+
         pub fn set_component_data(
             &mut self,
             entity_id: &CXXEntityId,
@@ -92,10 +183,101 @@ pub mod ffi {
 
         fn is_component_added_to_entity(&self, entity_id: &CXXEntityId, component: String) -> bool;
 
+        fn get_component_of_entity(
+            &self,
+            entity_id: &CXXEntityId,
+            component: String,
+        ) -> Box<ComponentDataResult>;
+
+        fn get_component_data(
+            &self,
+            component: String,
+            include_disabled: bool,
+        ) -> Box<ComponentDataVecResult>;
+
+        fn component_data_to_dictionary(
+            &self,
+            component: String,
+            data: &CXXComponentData,
+            dictionary: Pin<&mut Dictionary>,
+        ) -> Box<UnitResult>;
+
+        fn component_data_from_dictionary(
+            &self,
+            entity_id: &CXXEntityId,
+            component: String,
+            dictionary: &Dictionary,
+        ) -> Box<ComponentDataResult>;
+
         fn get_components_of_entity(&self, entity_id: &CXXEntityId) -> Box<StringVecResult>;
 
         fn create_entity(self: &mut CXXECSWorld) -> Box<CXXEntityId>;
 
+        fn spawn_entity(&mut self, components: Vec<String>) -> Box<EntityIdResult>;
+        fn release_entity(&mut self, entity_id: &CXXEntityId) -> Box<UnitResult>;
+        /// -1 if the entity does not exist or is parked in the entity pool.
+        fn get_entity_generation(&self, entity_id: &CXXEntityId) -> i64;
+        fn get_pooled_entity_count(&self) -> usize;
+
+        fn spawn_batch(
+            &mut self,
+            count: usize,
+            component: String,
+            dictionary: &Dictionary,
+        ) -> Box<BatchResult>;
+
+        fn set_component_data_batch(
+            &mut self,
+            entity_ids: Vec<String>,
+            component: String,
+            dictionaries: &Variant,
+        ) -> Box<BatchResult>;
+
+        fn set_entity_id_generation(&mut self, mode: u8, seed: u64) -> Box<UnitResult>;
+
+        fn despawn_entity(&mut self, entity_id: &CXXEntityId) -> Box<UnitResult>;
+        fn set_entity_reference_policy(&mut self, policy: u8) -> Box<UnitResult>;
+        fn set_component_entity_reference_policy(
+            &mut self,
+            component: String,
+            policy: u8,
+        ) -> Box<UnitResult>;
+
+        fn remove_component_from_entity(
+            &mut self,
+            entity_id: &CXXEntityId,
+            component: String,
+        ) -> Box<UnitResult>;
+
+        fn set_entity_enabled(&mut self, entity_id: &CXXEntityId, enabled: bool)
+            -> Box<UnitResult>;
+        fn is_entity_enabled(&self, entity_id: &CXXEntityId) -> bool;
+        fn set_component_enabled(
+            &mut self,
+            entity_id: &CXXEntityId,
+            component: String,
+            enabled: bool,
+        ) -> Box<UnitResult>;
+        fn is_component_enabled(&self, entity_id: &CXXEntityId, component: String) -> bool;
+
+        fn enable_journal(&mut self);
+        fn disable_journal(&mut self);
+        fn is_journal_enabled(&self) -> bool;
+        fn begin_journal_group(&mut self, name: String) -> Box<UnitResult>;
+        fn end_journal_group(&mut self) -> Box<UnitResult>;
+        fn undo(&mut self) -> Box<UnitResult>;
+        fn redo(&mut self) -> Box<UnitResult>;
+        fn get_undo_name(&self) -> String;
+        fn get_redo_name(&self) -> String;
+
+        fn start_recording(&mut self, seed: u64);
+        fn stop_recording(&mut self, path: String) -> Box<UnitResult>;
+        fn is_recording(&self) -> bool;
+        fn advance_frame(&mut self);
+        fn get_frame(&self) -> u64;
+        fn checksum(&self) -> u64;
+        fn replay(&mut self, path: String) -> Box<UnitResult>;
+
         #[cxx_name = "create_ecs_world"]
         pub fn create_cxx_ecs_world() -> Box<CXXECSWorld>;
     }
@@ -105,15 +287,39 @@ pub mod ffi {
         include!("gcs-cxx/include/godot/variant.h");
         include!("gcs-cxx/src/component/component_definition.rs.h");
         include!("gcs-cxx/src/component/component_value.rs.h");
+        include!("gcs-cxx/include/godot/dictionary.h");
 
         type ComponentDefinition = crate::component::component_definition::CXXComponentDefinition;
+        type ComponentFieldDefinition =
+            crate::component::component_definition::ffi::CXXComponentFieldDefinition;
         type ComponentValue = crate::component::component_value::CXXComponentValue;
+        type Dictionary = crate::godot::dictionary::ffi::Dictionary;
+        type Variant = crate::godot::variant::ffi::Variant;
     }
 }
 
 type ComponentInfoResult = GCSResult<Box<CXXComponentInfo>>;
 type UnitResult = GCSResult<()>;
 type StringVecResult = GCSResult<Vec<String>>;
+type StringResult = GCSResult<String>;
+type ComponentFieldDefinitionVecResult = GCSResult<Vec<CXXComponentFieldDefinition>>;
+type ComponentDataResult = GCSResult<Box<CXXComponentData>>;
+type ComponentDataVecResult = GCSResult<Vec<CXXComponentData>>;
+
+impl ComponentDataVecResult {
+    fn get_len(&self) -> usize {
+        self.result.as_ref().map_or(0, |result| result.len())
+    }
+
+    fn get_item(&self, index: usize) -> &CXXComponentData {
+        match &self.result {
+            None => {
+                panic!("Attempted to access result when is_error() is true")
+            }
+            Some(result) => &result[index],
+        }
+    }
+}
 
 pub(crate) struct CXXECSWorld(ECSWorld<CXXComponentDefinition, CXXComponentData, CXXComponentInfo>);
 
@@ -128,7 +334,7 @@ impl CXXECSWorld {
             .register_component(name, component_definition.clone());
         Box::new(match result {
             Ok(info) => ComponentInfoResult::new_result(Box::new(info)),
-            Err(error) => ComponentInfoResult::new_error(error),
+            Err(err) => ComponentInfoResult::from_world_error(err),
         })
     }
 
@@ -136,7 +342,61 @@ impl CXXECSWorld {
         let result = self.0.register_entity(id);
         Box::new(match result {
             Ok(_) => UnitResult::new_result(()),
-            Err(err) => UnitResult::new_error(err.to_string()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn get_component_names(self: &CXXECSWorld) -> Vec<String> {
+        self.0.get_component_names()
+    }
+
+    fn get_component_info(self: &CXXECSWorld, name: String) -> Box<ComponentInfoResult> {
+        Box::new(match self.0.get_component_info(name.clone()) {
+            Some(info) => ComponentInfoResult::new_result(Box::new(info)),
+            None => ComponentInfoResult::new_error(
+                ErrorCode::DoesNotExist,
+                format!("Component with name '{}' is not registered", name),
+            ),
+        })
+    }
+
+    fn get_component_name(self: &CXXECSWorld, info: &CXXComponentInfo) -> Box<StringResult> {
+        Box::new(match self.0.get_component_name(info) {
+            Some(name) => StringResult::new_result(name),
+            None => StringResult::new_error(
+                ErrorCode::DoesNotExist,
+                format!("No component is registered with hash {}", info.get_hash()),
+            ),
+        })
+    }
+
+    fn get_component_fields(
+        self: &CXXECSWorld,
+        name: String,
+    ) -> Box<ComponentFieldDefinitionVecResult> {
+        Box::new(match self.0.get_component_definition(name.clone()) {
+            Some(definition) => {
+                ComponentFieldDefinitionVecResult::new_result(definition.get_fields())
+            }
+            None => ComponentFieldDefinitionVecResult::new_error(
+                ErrorCode::DoesNotExist,
+                format!("Component with name '{}' is not registered", name),
+            ),
+        })
+    }
+
+    fn get_component_fields_by_info(
+        self: &CXXECSWorld,
+        info: &CXXComponentInfo,
+    ) -> Box<ComponentFieldDefinitionVecResult> {
+        Box::new(match self.0.get_component_definition_by_info(info) {
+            Some(definition) => {
+                ComponentFieldDefinitionVecResult::new_result(definition.get_fields())
+            }
+            None => ComponentFieldDefinitionVecResult::new_error(
+                ErrorCode::DoesNotExist,
+                format!("No component is registered with hash {}", info.get_hash()),
+            ),
         })
     }
 
@@ -149,7 +409,7 @@ impl CXXECSWorld {
         let result = self.0.set_component_data(entity_id, component, data);
         Box::new(match result {
             Ok(_) => UnitResult::new_result(()),
-            Err(err) => UnitResult::new_error(err.to_string()),
+            Err(err) => UnitResult::from_world_error(err),
         })
     }
 
@@ -160,7 +420,7 @@ impl CXXECSWorld {
         let result = self.0.get_components_of_entity(entity_id);
         Box::new(match result {
             Ok(value) => StringVecResult::new_result(value),
-            Err(err) => StringVecResult::new_error(err.to_string()),
+            Err(err) => StringVecResult::from_world_error(err),
         })
     }
 
@@ -172,9 +432,420 @@ impl CXXECSWorld {
         self.0.is_component_added_to_entity(entity_id, component)
     }
 
+    fn get_component_of_entity(
+        self: &CXXECSWorld,
+        entity_id: &CXXEntityId,
+        component: String,
+    ) -> Box<ComponentDataResult> {
+        Box::new(match self.0.get_component_of_entity(entity_id, component) {
+            Ok(data) => ComponentDataResult::new_result(Box::new(data)),
+            Err(err) => ComponentDataResult::from_world_error(err),
+        })
+    }
+
+    fn get_component_data(
+        self: &CXXECSWorld,
+        component: String,
+        include_disabled: bool,
+    ) -> Box<ComponentDataVecResult> {
+        let data = if include_disabled {
+            self.0.get_component_data_including_disabled(component)
+        } else {
+            self.0.get_component_data(component)
+        };
+        Box::new(match data {
+            Ok(data) => ComponentDataVecResult::new_result(data),
+            Err(err) => ComponentDataVecResult::from_world_error(err),
+        })
+    }
+
+    fn component_data_to_dictionary(
+        self: &CXXECSWorld,
+        component: String,
+        data: &CXXComponentData,
+        dictionary: Pin<&mut Dictionary>,
+    ) -> Box<UnitResult> {
+        Box::new(match self.0.get_component_definition(component.clone()) {
+            Some(definition) => {
+                data.to_dictionary(definition, dictionary);
+                UnitResult::new_result(())
+            }
+            None => UnitResult::new_error(
+                ErrorCode::DoesNotExist,
+                format!("Component with name '{}' is not registered", component),
+            ),
+        })
+    }
+
+    fn component_data_from_dictionary(
+        self: &CXXECSWorld,
+        entity_id: &CXXEntityId,
+        component: String,
+        dictionary: &Dictionary,
+    ) -> Box<ComponentDataResult> {
+        let definition = match self.0.get_component_definition(component.clone()) {
+            Some(definition) => definition,
+            None => {
+                return Box::new(ComponentDataResult::new_error(
+                    ErrorCode::DoesNotExist,
+                    format!("Component with name '{}' is not registered", component),
+                ))
+            }
+        };
+        Box::new(
+            match CXXComponentData::from_dictionary(*entity_id, &component, definition, dictionary)
+            {
+                Ok(data) => ComponentDataResult::new_result(Box::new(data)),
+                Err(err) => ComponentDataResult::new_error(ErrorCode::InvalidData, err.to_string()),
+            },
+        )
+    }
+
     fn create_entity(self: &mut CXXECSWorld) -> Box<CXXEntityId> {
         self.0.create_entity()
     }
+
+    fn spawn_entity(self: &mut CXXECSWorld, components: Vec<String>) -> Box<EntityIdResult> {
+        Box::new(match self.0.spawn_entity(components) {
+            Ok(entity) => EntityIdResult::new_result(Box::new(entity)),
+            Err(err) => EntityIdResult::from_world_error(err),
+        })
+    }
+
+    fn release_entity(self: &mut CXXECSWorld, entity_id: &CXXEntityId) -> Box<UnitResult> {
+        Box::new(match self.0.release_entity(entity_id) {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn get_entity_generation(self: &CXXECSWorld, entity_id: &CXXEntityId) -> i64 {
+        self.0
+            .get_entity_generation(entity_id)
+            .map_or(-1, i64::from)
+    }
+
+    fn get_pooled_entity_count(self: &CXXECSWorld) -> usize {
+        self.0.get_pooled_entity_count()
+    }
+
+    fn spawn_batch(
+        self: &mut CXXECSWorld,
+        count: usize,
+        component: String,
+        dictionary: &Dictionary,
+    ) -> Box<BatchResult> {
+        let definition = match self.0.get_component_definition(component.clone()) {
+            Some(definition) => definition,
+            None => {
+                return Box::new(BatchResult::new_error(
+                    ErrorCode::DoesNotExist,
+                    format!("Component with name '{}' is not registered", component),
+                ))
+            }
+        };
+        let data = match CXXComponentData::from_dictionary(
+            CXXEntityId::default(),
+            &component,
+            definition,
+            dictionary,
+        ) {
+            Ok(data) => data,
+            Err(err) => {
+                return Box::new(BatchResult::new_error(
+                    ErrorCode::InvalidData,
+                    err.to_string(),
+                ))
+            }
+        };
+        Box::new(match self.0.spawn_batch(count, component, &data) {
+            Ok(entities) => BatchResult::new_items(
+                entities
+                    .iter()
+                    .map(|entity| GCSResult::new_result(entity.as_string()))
+                    .collect(),
+            ),
+            Err(err) => {
+                let err = GCSResult::<()>::from_world_error(err);
+                BatchResult::new_error(err.get_error_code(), err.get_error())
+            }
+        })
+    }
+
+    /// `dictionaries` is an Array with the data of every entity in `entity_ids` as a Dictionary.
+    fn set_component_data_batch(
+        self: &mut CXXECSWorld,
+        entity_ids: Vec<String>,
+        component: String,
+        dictionaries: &Variant,
+    ) -> Box<BatchResult> {
+        if entity_ids.len() != variant_array_len(dictionaries) {
+            return Box::new(BatchResult::new_error(
+                ErrorCode::InvalidParameter,
+                format!(
+                    "{} entities were given, but data for {}",
+                    entity_ids.len(),
+                    variant_array_len(dictionaries)
+                ),
+            ));
+        }
+        let definition = match self.0.get_component_definition(component.clone()) {
+            Some(definition) => definition,
+            None => {
+                return Box::new(BatchResult::new_error(
+                    ErrorCode::DoesNotExist,
+                    format!("Component with name '{}' is not registered", component),
+                ))
+            }
+        };
+
+        let mut items = Vec::with_capacity(entity_ids.len());
+        let mut entities = Vec::new();
+        let mut data = Vec::new();
+        for (index, entity_id) in entity_ids.iter().enumerate() {
+            let entity = match CXXEntityId::parse_str(entity_id) {
+                Ok(entity) => entity,
+                Err(err) => {
+                    items.push(GCSResult::new_error(ErrorCode::InvalidParameter, err));
+                    continue;
+                }
+            };
+            let dictionary = variant_as_dictionary(variant_array_get(dictionaries, index));
+            match CXXComponentData::from_dictionary(entity, &component, definition, &dictionary) {
+                Ok(entity_data) => {
+                    items.push(GCSResult::new_result(entity.as_string()));
+                    entities.push(entity);
+                    data.push(entity_data);
+                }
+                Err(err) => items.push(GCSResult::new_error(
+                    ErrorCode::InvalidData,
+                    err.to_string(),
+                )),
+            }
+        }
+
+        let mut results = match self.0.set_component_data_batch(&entities, component, &data) {
+            Ok(results) => results.into_iter(),
+            Err(err) => {
+                let err = GCSResult::<()>::from_world_error(err);
+                return Box::new(BatchResult::new_error(
+                    err.get_error_code(),
+                    err.get_error(),
+                ));
+            }
+        };
+        for item in items.iter_mut().filter(|item| !item.is_error()) {
+            if let Err(err) = results.next().unwrap() {
+                *item = GCSResult::from_world_error(err);
+            }
+        }
+        Box::new(BatchResult::new_items(items))
+    }
+
+    fn despawn_entity(self: &mut CXXECSWorld, entity_id: &CXXEntityId) -> Box<UnitResult> {
+        let result = self.0.despawn_entity(entity_id);
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn remove_component_from_entity(
+        self: &mut CXXECSWorld,
+        entity_id: &CXXEntityId,
+        component: String,
+    ) -> Box<UnitResult> {
+        let result = self.0.remove_component_from_entity(entity_id, component);
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn set_entity_enabled(
+        self: &mut CXXECSWorld,
+        entity_id: &CXXEntityId,
+        enabled: bool,
+    ) -> Box<UnitResult> {
+        Box::new(match self.0.set_entity_enabled(entity_id, enabled) {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn is_entity_enabled(self: &CXXECSWorld, entity_id: &CXXEntityId) -> bool {
+        self.0.is_entity_enabled(entity_id)
+    }
+
+    fn set_component_enabled(
+        self: &mut CXXECSWorld,
+        entity_id: &CXXEntityId,
+        component: String,
+        enabled: bool,
+    ) -> Box<UnitResult> {
+        let result = self.0.set_component_enabled(entity_id, component, enabled);
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn is_component_enabled(
+        self: &CXXECSWorld,
+        entity_id: &CXXEntityId,
+        component: String,
+    ) -> bool {
+        self.0.is_component_enabled(entity_id, component)
+    }
+
+    fn enable_journal(self: &mut CXXECSWorld) {
+        self.0.enable_journal()
+    }
+
+    fn disable_journal(self: &mut CXXECSWorld) {
+        self.0.disable_journal()
+    }
+
+    fn is_journal_enabled(self: &CXXECSWorld) -> bool {
+        self.0.is_journal_enabled()
+    }
+
+    fn begin_journal_group(self: &mut CXXECSWorld, name: String) -> Box<UnitResult> {
+        let result = self.0.begin_journal_group(name);
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn end_journal_group(self: &mut CXXECSWorld) -> Box<UnitResult> {
+        let result = self.0.end_journal_group();
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn undo(self: &mut CXXECSWorld) -> Box<UnitResult> {
+        let result = self.0.undo();
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn redo(self: &mut CXXECSWorld) -> Box<UnitResult> {
+        let result = self.0.redo();
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+
+    fn get_undo_name(self: &CXXECSWorld) -> String {
+        self.0.get_undo_name().unwrap_or_default()
+    }
+
+    fn get_redo_name(self: &CXXECSWorld) -> String {
+        self.0.get_redo_name().unwrap_or_default()
+    }
+
+    fn start_recording(self: &mut CXXECSWorld, seed: u64) {
+        self.0.start_recording(seed)
+    }
+
+    fn stop_recording(self: &mut CXXECSWorld, path: String) -> Box<UnitResult> {
+        Box::new(match self.0.stop_recording() {
+            None => UnitResult::new_error(
+                ErrorCode::Unavailable,
+                "The world is not recording".to_string(),
+            ),
+            Some(log) => match log.save(Path::new(&path)) {
+                Ok(_) => UnitResult::new_result(()),
+                Err(err) => UnitResult::from_world_error(err),
+            },
+        })
+    }
+
+    fn is_recording(self: &CXXECSWorld) -> bool {
+        self.0.is_recording()
+    }
+
+    fn advance_frame(self: &mut CXXECSWorld) {
+        self.0.advance_frame()
+    }
+
+    fn set_entity_id_generation(self: &mut CXXECSWorld, mode: u8, seed: u64) -> Box<UnitResult> {
+        let id_generation = match mode {
+            0 => EntityIdGeneration::Random,
+            1 => EntityIdGeneration::Seeded(seed),
+            2 => EntityIdGeneration::Sequential,
+            3 => EntityIdGeneration::TimeOrdered,
+            _ => {
+                return Box::new(UnitResult::new_error(
+                    ErrorCode::InvalidParameter,
+                    format!("{} is not a valid entity id generation mode", mode),
+                ))
+            }
+        };
+        self.0.set_entity_id_generation(id_generation);
+        Box::new(UnitResult::new_result(()))
+    }
+
+    fn set_entity_reference_policy(self: &mut CXXECSWorld, policy: u8) -> Box<UnitResult> {
+        Box::new(match entity_reference_policy_from_u8(policy) {
+            Ok(policy) => {
+                self.0.set_entity_reference_policy(policy);
+                UnitResult::new_result(())
+            }
+            Err(err) => err,
+        })
+    }
+
+    fn set_component_entity_reference_policy(
+        self: &mut CXXECSWorld,
+        component: String,
+        policy: u8,
+    ) -> Box<UnitResult> {
+        Box::new(match entity_reference_policy_from_u8(policy) {
+            Ok(policy) => match self
+                .0
+                .set_component_entity_reference_policy(component, policy)
+            {
+                Ok(_) => UnitResult::new_result(()),
+                Err(err) => UnitResult::from_world_error(err),
+            },
+            Err(err) => err,
+        })
+    }
+
+    fn get_frame(self: &CXXECSWorld) -> u64 {
+        self.0.get_frame()
+    }
+
+    fn checksum(self: &CXXECSWorld) -> u64 {
+        self.0.checksum()
+    }
+
+    fn replay(self: &mut CXXECSWorld, path: String) -> Box<UnitResult> {
+        let result = ReplayLog::load(Path::new(&path)).and_then(|log| self.0.replay(&log));
+        Box::new(match result {
+            Ok(_) => UnitResult::new_result(()),
+            Err(err) => UnitResult::from_world_error(err),
+        })
+    }
+}
+
+fn entity_reference_policy_from_u8(policy: u8) -> Result<EntityReferencePolicy, UnitResult> {
+    match policy {
+        0 => Ok(EntityReferencePolicy::SetNil),
+        1 => Ok(EntityReferencePolicy::RemoveComponent),
+        2 => Ok(EntityReferencePolicy::DespawnEntity),
+        _ => Err(UnitResult::new_error(
+            ErrorCode::InvalidParameter,
+            format!("{} is not a valid entity reference policy", policy),
+        )),
+    }
 }
 
 pub(crate) fn create_cxx_ecs_world() -> Box<CXXECSWorld> {
@@ -182,7 +853,7 @@ pub(crate) fn create_cxx_ecs_world() -> Box<CXXECSWorld> {
         CXXComponentDefinition,
         CXXComponentData,
         CXXComponentInfo,
-    >()))
+    >(EntityIdGeneration::Random)))
 }
 
 unsafe impl ExternType for CXXECSWorld {
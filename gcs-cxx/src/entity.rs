@@ -3,6 +3,7 @@ use uuid::Uuid;
 
 use gcs::entity::EntityId;
 
+use crate::ecs_world::ffi::ErrorCode;
 use crate::godot::error::GCSResult;
 
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
@@ -18,6 +19,13 @@ impl EntityId for CXXEntityId {
         CXXEntityId(Uuid::new_v4())
     }
 
+    fn from_u128(value: u128) -> Self
+    where
+        Self: Sized,
+    {
+        CXXEntityId(Uuid::from_u128(value))
+    }
+
     fn as_string(&self) -> String {
         self.0.to_hyphenated().to_string()
     }
@@ -52,6 +60,6 @@ pub(crate) fn create_entity() -> Box<CXXEntityId> {
 pub(crate) fn entity_id_from_string(id: String) -> Box<EntityIdResult> {
     Box::new(match CXXEntityId::parse_str(id.as_str()) {
         Ok(value) => EntityIdResult::new_result(Box::new(value)),
-        Err(err) => EntityIdResult::new_error(err),
+        Err(err) => EntityIdResult::new_error(ErrorCode::InvalidParameter, err),
     })
 }
@@ -1,2 +1,3 @@
+pub(crate) mod dictionary;
 pub(crate) mod error;
 pub(crate) mod variant;
@@ -0,0 +1,27 @@
+#include "godot/dictionary.h"
+#include "gcs-cxx/include/godot/string.h" // NOLINT(modernize-deprecated-headers)
+
+namespace gcs {
+    namespace ffi {
+        rust::Vec<rust::string> dictionary_keys(const Dictionary &dictionary) {
+            auto keys = rust::Vec<rust::string>();
+            auto godot_keys = dictionary.keys();
+            for (int i = 0; i < godot_keys.size(); ++i) {
+                keys.push_back(godot_string_to_rust_string(godot_keys[i].operator String()));
+            }
+            return keys;
+        }
+
+        const Variant &dictionary_get(const Dictionary &dictionary, rust::string key) {
+            return dictionary[String(key.c_str())];
+        }
+
+        void dictionary_set(Dictionary &dictionary, rust::string key, const Variant &value) {
+            dictionary[String(key.c_str())] = value;
+        }
+
+        std::unique_ptr<Dictionary> variant_as_dictionary(const Variant &variant) {
+            return std::unique_ptr<Dictionary>(new Dictionary(variant.operator Dictionary()));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+#[cxx::bridge(namespace = gcs::ffi)]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("gcs-cxx/include/godot/dictionary.h");
+        include!("rust/cxx.h");
+        type Variant = crate::godot::variant::ffi::Variant;
+        pub type Dictionary;
+
+        pub fn dictionary_keys(dictionary: &Dictionary) -> Vec<String>;
+        pub fn dictionary_get(dictionary: &Dictionary, key: String) -> &Variant;
+        pub fn dictionary_set(dictionary: Pin<&mut Dictionary>, key: String, value: &Variant);
+        pub fn variant_as_dictionary(variant: &Variant) -> UniquePtr<Dictionary>;
+    }
+}
@@ -1,6 +1,11 @@
+use gcs::world::errors::{ErrorKind, WorldError};
+
+use crate::ecs_world::ffi::ErrorCode;
+
 pub(crate) struct GCSResult<T: Clone> {
     pub(crate) result: Option<T>,
     pub(crate) error: String,
+    pub(crate) error_code: ErrorCode,
 }
 
 impl<T: Clone> GCSResult<T> {
@@ -9,16 +14,23 @@ impl<T: Clone> GCSResult<T> {
         Self {
             result,
             error: String::default(),
+            error_code: ErrorCode::Ok,
         }
     }
 
-    pub(crate) fn new_error(error: String) -> Self {
+    pub(crate) fn new_error(error_code: ErrorCode, error: String) -> Self {
         Self {
             result: None,
             error,
+            error_code,
         }
     }
 
+    pub(crate) fn from_world_error<E: Into<WorldError>>(error: E) -> Self {
+        let error = error.into();
+        Self::new_error(get_error_code(error.get_kind()), error.to_string())
+    }
+
     pub(crate) fn is_error(&self) -> bool {
         match self.result {
             None => true,
@@ -38,4 +50,98 @@ impl<T: Clone> GCSResult<T> {
     pub(crate) fn get_error(&self) -> String {
         self.error.clone()
     }
+
+    pub(crate) fn get_error_code(&self) -> ErrorCode {
+        self.error_code
+    }
+}
+
+/// Result of a batch call. Either the whole batch failed, or every item has a result of its own,
+/// the id of its entity if it succeeded.
+pub(crate) struct BatchResult {
+    pub(crate) error: Option<GCSResult<()>>,
+    pub(crate) items: Vec<GCSResult<String>>,
+}
+
+impl BatchResult {
+    pub(crate) fn new_items(items: Vec<GCSResult<String>>) -> Self {
+        Self { error: None, items }
+    }
+
+    pub(crate) fn new_error(error_code: ErrorCode, error: String) -> Self {
+        Self {
+            error: Some(GCSResult::new_error(error_code, error)),
+            items: Vec::new(),
+        }
+    }
+
+    /// True if the batch or any of its items failed.
+    pub(crate) fn is_error(&self) -> bool {
+        self.error.is_some() || self.items.iter().any(|item| item.is_error())
+    }
+
+    pub(crate) fn get_error(&self) -> String {
+        if let Some(error) = &self.error {
+            return error.get_error();
+        }
+        let failed: Vec<&GCSResult<String>> =
+            self.items.iter().filter(|item| item.is_error()).collect();
+        match failed.first() {
+            None => String::default(),
+            Some(first) => format!(
+                "{} of {} items failed, first error: {}",
+                failed.len(),
+                self.items.len(),
+                first.get_error()
+            ),
+        }
+    }
+
+    pub(crate) fn get_error_code(&self) -> ErrorCode {
+        match &self.error {
+            Some(error) => error.get_error_code(),
+            None => self
+                .items
+                .iter()
+                .map(|item| item.get_error_code())
+                .find(|code| *code != ErrorCode::Ok)
+                .unwrap_or(ErrorCode::Ok),
+        }
+    }
+
+    pub(crate) fn get_len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Entity ids of all items, empty for the items that failed.
+    pub(crate) fn get_entities(&self) -> Vec<String> {
+        self.items
+            .iter()
+            .map(|item| item.result.clone().unwrap_or_default())
+            .collect()
+    }
+
+    pub(crate) fn get_item_error(&self, index: usize) -> String {
+        self.items[index].get_error()
+    }
+
+    /// Error codes of all items, as the representation of their `ErrorCode`.
+    pub(crate) fn get_error_codes(&self) -> Vec<u8> {
+        self.items
+            .iter()
+            .map(|item| item.get_error_code().repr)
+            .collect()
+    }
+}
+
+fn get_error_code(kind: ErrorKind) -> ErrorCode {
+    match kind {
+        ErrorKind::NotFound => ErrorCode::DoesNotExist,
+        ErrorKind::AlreadyExists => ErrorCode::AlreadyExists,
+        ErrorKind::InUse => ErrorCode::Busy,
+        ErrorKind::Unavailable => ErrorCode::Unavailable,
+        ErrorKind::Io => ErrorCode::CantOpen,
+        ErrorKind::Parse => ErrorCode::ParseError,
+        ErrorKind::InvalidData => ErrorCode::InvalidData,
+    }
 }
@@ -1,8 +1,139 @@
 #include "godot/variant.h"
 #include "gcs-cxx/include/godot/string.h" // NOLINT(modernize-deprecated-headers)
+#include "gcs-cxx/src/godot/variant.rs.h"
 
 namespace gcs {
     namespace ffi {
+        static Vector2 from_godot(const ::Vector2 &value) {
+            return Vector2{value.x, value.y};
+        }
+
+        static ::Vector2 to_godot(const Vector2 &value) {
+            return ::Vector2(value.x, value.y);
+        }
+
+        static Rect2 from_godot(const ::Rect2 &value) {
+            return Rect2{from_godot(value.position), from_godot(value.size)};
+        }
+
+        static ::Rect2 to_godot(const Rect2 &value) {
+            return ::Rect2(to_godot(value.position), to_godot(value.size));
+        }
+
+        static Vector3 from_godot(const ::Vector3 &value) {
+            return Vector3{value.x, value.y, value.z};
+        }
+
+        static ::Vector3 to_godot(const Vector3 &value) {
+            return ::Vector3(value.x, value.y, value.z);
+        }
+
+        static Transform2D from_godot(const ::Transform2D &value) {
+            return Transform2D{
+                    from_godot(value.elements[0]),
+                    from_godot(value.elements[1]),
+                    from_godot(value.elements[2])
+            };
+        }
+
+        static ::Transform2D to_godot(const Transform2D &value) {
+            ::Transform2D result;
+            result.elements[0] = to_godot(value.x);
+            result.elements[1] = to_godot(value.y);
+            result.elements[2] = to_godot(value.origin);
+            return result;
+        }
+
+        static Plane from_godot(const ::Plane &value) {
+            return Plane{from_godot(value.normal), value.d};
+        }
+
+        static ::Plane to_godot(const Plane &value) {
+            return ::Plane(to_godot(value.normal), value.d);
+        }
+
+        static Quat from_godot(const ::Quat &value) {
+            return Quat{value.x, value.y, value.z, value.w};
+        }
+
+        static ::Quat to_godot(const Quat &value) {
+            return ::Quat(value.x, value.y, value.z, value.w);
+        }
+
+        static AABB from_godot(const ::AABB &value) {
+            return AABB{from_godot(value.position), from_godot(value.size)};
+        }
+
+        static ::AABB to_godot(const AABB &value) {
+            return ::AABB(to_godot(value.position), to_godot(value.size));
+        }
+
+        static Basis from_godot(const ::Basis &value) {
+            return Basis{
+                    from_godot(value.get_axis(0)),
+                    from_godot(value.get_axis(1)),
+                    from_godot(value.get_axis(2))
+            };
+        }
+
+        static ::Basis to_godot(const Basis &value) {
+            ::Basis result;
+            result.set_axis(0, to_godot(value.x));
+            result.set_axis(1, to_godot(value.y));
+            result.set_axis(2, to_godot(value.z));
+            return result;
+        }
+
+        static Transform from_godot(const ::Transform &value) {
+            return Transform{from_godot(value.basis), from_godot(value.origin)};
+        }
+
+        static ::Transform to_godot(const Transform &value) {
+            return ::Transform(to_godot(value.basis), to_godot(value.origin));
+        }
+
+        static Color from_godot(const ::Color &value) {
+            return Color{value.r, value.g, value.b, value.a};
+        }
+
+        static ::Color to_godot(const Color &value) {
+            return ::Color(value.r, value.g, value.b, value.a);
+        }
+
+        static String from_rust(const rust::string &value) {
+            return String::utf8(value.data(), static_cast<int>(value.size()));
+        }
+
+        // Packed arrays cross the bridge in one call, converting each element on the way.
+        template<typename TRust, typename TGodot, typename TConvert>
+        static rust::Vec<TRust> vec_from_pool(const PoolVector<TGodot> &pool, TConvert convert) {
+            rust::Vec<TRust> values;
+            values.reserve(pool.size());
+            auto read = pool.read();
+            for (int i = 0; i < pool.size(); ++i) {
+                values.push_back(convert(read[i]));
+            }
+            return values;
+        }
+
+        template<typename TGodot, typename TRust, typename TConvert>
+        static const Variant &variant_from_slice(rust::Slice<const TRust> values, TConvert convert) {
+            PoolVector<TGodot> pool;
+            pool.resize(static_cast<int>(values.size()));
+            {
+                auto write = pool.write();
+                for (size_t i = 0; i < values.size(); ++i) {
+                    write[i] = convert(values[i]);
+                }
+            }
+            return *(new Variant(pool));
+        }
+
+        template<typename T>
+        static T identity(const T &value) {
+            return value;
+        }
+
         int64_t variant_as_i64(const Variant &variant) {
             return variant.operator int64_t();
         }
@@ -20,6 +151,103 @@ namespace gcs {
             return variant.operator double();
         }
 
+        Vector2 variant_as_vector2(const Variant &variant) {
+            return from_godot(variant.operator ::Vector2());
+        }
+
+        Rect2 variant_as_rect2(const Variant &variant) {
+            return from_godot(variant.operator ::Rect2());
+        }
+
+        Vector3 variant_as_vector3(const Variant &variant) {
+            return from_godot(variant.operator ::Vector3());
+        }
+
+        Transform2D variant_as_transform2d(const Variant &variant) {
+            return from_godot(variant.operator ::Transform2D());
+        }
+
+        Plane variant_as_plane(const Variant &variant) {
+            return from_godot(variant.operator ::Plane());
+        }
+
+        Quat variant_as_quat(const Variant &variant) {
+            return from_godot(variant.operator ::Quat());
+        }
+
+        AABB variant_as_aabb(const Variant &variant) {
+            return from_godot(variant.operator ::AABB());
+        }
+
+        Basis variant_as_basis(const Variant &variant) {
+            return from_godot(variant.operator ::Basis());
+        }
+
+        Transform variant_as_transform(const Variant &variant) {
+            return from_godot(variant.operator ::Transform());
+        }
+
+        Color variant_as_color(const Variant &variant) {
+            return from_godot(variant.operator ::Color());
+        }
+
+        rust::Vec<uint8_t> variant_as_pool_byte_array(const Variant &variant) {
+            return vec_from_pool<uint8_t>(variant.operator PoolByteArray(), identity<uint8_t>);
+        }
+
+        rust::Vec<int32_t> variant_as_pool_int_array(const Variant &variant) {
+            return vec_from_pool<int32_t>(variant.operator PoolIntArray(), identity<int32_t>);
+        }
+
+        rust::Vec<float> variant_as_pool_real_array(const Variant &variant) {
+            return vec_from_pool<float>(variant.operator PoolRealArray(), identity<float>);
+        }
+
+        rust::Vec<rust::string> variant_as_pool_string_array(const Variant &variant) {
+            return vec_from_pool<rust::string>(variant.operator PoolStringArray(), godot_string_to_rust_string);
+        }
+
+        rust::Vec<Vector2> variant_as_pool_vector2_array(const Variant &variant) {
+            return vec_from_pool<Vector2>(variant.operator PoolVector2Array(), [](const ::Vector2 &value) { return from_godot(value); });
+        }
+
+        rust::Vec<Vector3> variant_as_pool_vector3_array(const Variant &variant) {
+            return vec_from_pool<Vector3>(variant.operator PoolVector3Array(), [](const ::Vector3 &value) { return from_godot(value); });
+        }
+
+        rust::Vec<Color> variant_as_pool_color_array(const Variant &variant) {
+            return vec_from_pool<Color>(variant.operator PoolColorArray(), [](const ::Color &value) { return from_godot(value); });
+        }
+
+        size_t variant_array_len(const Variant &array) {
+            return array.operator Array().size();
+        }
+
+        // Arrays and dictionaries share their storage with the variant, so the returned
+        // references stay valid for as long as the variant does.
+        const Variant &variant_array_get(const Variant &array, size_t index) {
+            const Array godot_array = array.operator Array();
+            return godot_array[index];
+        }
+
+        size_t variant_dictionary_len(const Variant &dictionary) {
+            return dictionary.operator Dictionary().size();
+        }
+
+        const Variant &variant_dictionary_key(const Variant &dictionary, size_t index) {
+            auto godot_dictionary = dictionary.operator Dictionary();
+            const Variant *key = godot_dictionary.next();
+            for (size_t i = 0; i < index; ++i) {
+                key = godot_dictionary.next(key);
+            }
+            return *key;
+        }
+
+        const Variant &variant_dictionary_get(const Variant &dictionary, const Variant &key) {
+            const Dictionary godot_dictionary = dictionary.operator Dictionary();
+            return godot_dictionary[key];
+        }
+
         const Variant &empty_variant() {
             return *(new Variant());
         }
@@ -39,5 +267,93 @@ namespace gcs {
         const Variant &variant_from_f64(double value) {
             return *(new Variant(value));
         }
+
+        const Variant &variant_from_vector2(Vector2 value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_rect2(Rect2 value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_vector3(Vector3 value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_transform2d(Transform2D value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_plane(Plane value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_quat(Quat value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_aabb(AABB value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_basis(Basis value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_transform(Transform value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_color(Color value) {
+            return *(new Variant(to_godot(value)));
+        }
+
+        const Variant &variant_from_pool_byte_array(rust::Slice<const uint8_t> value) {
+            return variant_from_slice<uint8_t>(value, identity<uint8_t>);
+        }
+
+        const Variant &variant_from_pool_int_array(rust::Slice<const int32_t> value) {
+            return variant_from_slice<int>(value, identity<int32_t>);
+        }
+
+        const Variant &variant_from_pool_real_array(rust::Slice<const float> value) {
+            return variant_from_slice<real_t>(value, identity<float>);
+        }
+
+        const Variant &variant_from_pool_string_array(rust::Slice<const rust::string> value) {
+            return variant_from_slice<String>(value, from_rust);
+        }
+
+        const Variant &variant_from_pool_vector2_array(rust::Slice<const Vector2> value) {
+            return variant_from_slice<::Vector2>(value, [](const Vector2 &value) { return to_godot(value); });
+        }
+
+        const Variant &variant_from_pool_vector3_array(rust::Slice<const Vector3> value) {
+            return variant_from_slice<::Vector3>(value, [](const Vector3 &value) { return to_godot(value); });
+        }
+
+        const Variant &variant_from_pool_color_array(rust::Slice<const Color> value) {
+            return variant_from_slice<::Color>(value, [](const Color &value) { return to_godot(value); });
+        }
+
+        std::unique_ptr<Variant> new_array_variant() {
+            return std::unique_ptr<Variant>(new Variant(Array()));
+        }
+
+        std::unique_ptr<Variant> new_dictionary_variant() {
+            return std::unique_ptr<Variant>(new Variant(Dictionary()));
+        }
+
+        const Variant &release_variant(std::unique_ptr<Variant> value) {
+            return *value.release();
+        }
+
+        void variant_array_push(Variant &array, const Variant &value) {
+            array.operator Array().push_back(value);
+        }
+
+        void variant_dictionary_set(Variant &dictionary, const Variant &key, const Variant &value) {
+            dictionary.operator Dictionary()[key] = value;
+        }
     }
 }
\ No newline at end of file
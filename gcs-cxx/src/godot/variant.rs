@@ -1,9 +1,90 @@
-use crate::godot::variant::ffi::Variant;
+use crate::godot::variant::ffi::{
+    CXXAabb, CXXBasis, CXXColor, CXXPlane, CXXQuat, CXXRect2, CXXTransform, CXXTransform2D,
+    CXXVector2, CXXVector3, Variant,
+};
 use cxx::{type_id, ExternType};
+use gcs::math::{Aabb, Basis, Color, Plane, Quat, Rect2, Transform, Transform2D, Vector2, Vector3};
 use gcs::variant::VariantType;
 
 #[cxx::bridge(namespace = gcs::ffi)]
 pub mod ffi {
+    #[cxx_name = "Vector2"]
+    #[derive(Copy, Clone)]
+    pub struct CXXVector2 {
+        pub x: f32,
+        pub y: f32,
+    }
+
+    #[cxx_name = "Rect2"]
+    #[derive(Copy, Clone)]
+    pub struct CXXRect2 {
+        pub position: CXXVector2,
+        pub size: CXXVector2,
+    }
+
+    #[cxx_name = "Vector3"]
+    #[derive(Copy, Clone)]
+    pub struct CXXVector3 {
+        pub x: f32,
+        pub y: f32,
+        pub z: f32,
+    }
+
+    #[cxx_name = "Transform2D"]
+    #[derive(Copy, Clone)]
+    pub struct CXXTransform2D {
+        pub x: CXXVector2,
+        pub y: CXXVector2,
+        pub origin: CXXVector2,
+    }
+
+    #[cxx_name = "Plane"]
+    #[derive(Copy, Clone)]
+    pub struct CXXPlane {
+        pub normal: CXXVector3,
+        pub d: f32,
+    }
+
+    #[cxx_name = "Quat"]
+    #[derive(Copy, Clone)]
+    pub struct CXXQuat {
+        pub x: f32,
+        pub y: f32,
+        pub z: f32,
+        pub w: f32,
+    }
+
+    #[cxx_name = "AABB"]
+    #[derive(Copy, Clone)]
+    pub struct CXXAabb {
+        pub position: CXXVector3,
+        pub size: CXXVector3,
+    }
+
+    #[cxx_name = "Basis"]
+    #[derive(Copy, Clone)]
+    pub struct CXXBasis {
+        pub x: CXXVector3,
+        pub y: CXXVector3,
+        pub z: CXXVector3,
+    }
+
+    #[cxx_name = "Transform"]
+    #[derive(Copy, Clone)]
+    pub struct CXXTransform {
+        pub basis: CXXBasis,
+        pub origin: CXXVector3,
+    }
+
+    #[cxx_name = "Color"]
+    #[derive(Copy, Clone)]
+    pub struct CXXColor {
+        pub r: f32,
+        pub g: f32,
+        pub b: f32,
+        pub a: f32,
+    }
+
     unsafe extern "C++" {
         include!("gcs-cxx/include/godot/variant.h");
         include!("rust/cxx.h");
@@ -17,7 +98,32 @@ pub mod ffi {
         pub fn variant_as_string(variant: &Variant) -> String;
         pub fn variant_as_bool(variant: &Variant) -> bool;
         pub fn variant_as_f64(variant: &Variant) -> f64;
+        pub fn variant_as_vector2(variant: &Variant) -> CXXVector2;
+        pub fn variant_as_rect2(variant: &Variant) -> CXXRect2;
+        pub fn variant_as_vector3(variant: &Variant) -> CXXVector3;
+        pub fn variant_as_transform2d(variant: &Variant) -> CXXTransform2D;
+        pub fn variant_as_plane(variant: &Variant) -> CXXPlane;
+        pub fn variant_as_quat(variant: &Variant) -> CXXQuat;
+        pub fn variant_as_aabb(variant: &Variant) -> CXXAabb;
+        pub fn variant_as_basis(variant: &Variant) -> CXXBasis;
+        pub fn variant_as_transform(variant: &Variant) -> CXXTransform;
+        pub fn variant_as_color(variant: &Variant) -> CXXColor;
+        pub fn variant_as_pool_byte_array(variant: &Variant) -> Vec<u8>;
+        pub fn variant_as_pool_int_array(variant: &Variant) -> Vec<i32>;
+        pub fn variant_as_pool_real_array(variant: &Variant) -> Vec<f32>;
+        pub fn variant_as_pool_string_array(variant: &Variant) -> Vec<String>;
+        pub fn variant_as_pool_vector2_array(variant: &Variant) -> Vec<CXXVector2>;
+        pub fn variant_as_pool_vector3_array(variant: &Variant) -> Vec<CXXVector3>;
+        pub fn variant_as_pool_color_array(variant: &Variant) -> Vec<CXXColor>;
+
+        pub fn variant_array_len(array: &Variant) -> usize;
+        pub fn variant_array_get(array: &Variant, index: usize) -> &Variant;
+        pub fn variant_dictionary_len(dictionary: &Variant) -> usize;
+        pub fn variant_dictionary_key(dictionary: &Variant, index: usize) -> &Variant;
+        pub fn variant_dictionary_get<'a>(dictionary: &'a Variant, key: &Variant) -> &'a Variant;
     }
+
+    impl UniquePtr<Variant> {}
 }
 
 #[derive(Hash, Eq, PartialEq, Clone, Default)]
@@ -51,3 +157,197 @@ impl From<&ffi::Variant> for f64 {
         ffi::variant_as_f64(variant)
     }
 }
+
+impl From<Vector2> for CXXVector2 {
+    fn from(value: Vector2) -> Self {
+        CXXVector2 {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl From<CXXVector2> for Vector2 {
+    fn from(value: CXXVector2) -> Self {
+        Vector2 {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+impl From<Rect2> for CXXRect2 {
+    fn from(value: Rect2) -> Self {
+        CXXRect2 {
+            position: value.position.into(),
+            size: value.size.into(),
+        }
+    }
+}
+
+impl From<CXXRect2> for Rect2 {
+    fn from(value: CXXRect2) -> Self {
+        Rect2 {
+            position: value.position.into(),
+            size: value.size.into(),
+        }
+    }
+}
+
+impl From<Vector3> for CXXVector3 {
+    fn from(value: Vector3) -> Self {
+        CXXVector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<CXXVector3> for Vector3 {
+    fn from(value: CXXVector3) -> Self {
+        Vector3 {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+        }
+    }
+}
+
+impl From<Transform2D> for CXXTransform2D {
+    fn from(value: Transform2D) -> Self {
+        CXXTransform2D {
+            x: value.x.into(),
+            y: value.y.into(),
+            origin: value.origin.into(),
+        }
+    }
+}
+
+impl From<CXXTransform2D> for Transform2D {
+    fn from(value: CXXTransform2D) -> Self {
+        Transform2D {
+            x: value.x.into(),
+            y: value.y.into(),
+            origin: value.origin.into(),
+        }
+    }
+}
+
+impl From<Plane> for CXXPlane {
+    fn from(value: Plane) -> Self {
+        CXXPlane {
+            normal: value.normal.into(),
+            d: value.d,
+        }
+    }
+}
+
+impl From<CXXPlane> for Plane {
+    fn from(value: CXXPlane) -> Self {
+        Plane {
+            normal: value.normal.into(),
+            d: value.d,
+        }
+    }
+}
+
+impl From<Quat> for CXXQuat {
+    fn from(value: Quat) -> Self {
+        CXXQuat {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+            w: value.w,
+        }
+    }
+}
+
+impl From<CXXQuat> for Quat {
+    fn from(value: CXXQuat) -> Self {
+        Quat {
+            x: value.x,
+            y: value.y,
+            z: value.z,
+            w: value.w,
+        }
+    }
+}
+
+impl From<Aabb> for CXXAabb {
+    fn from(value: Aabb) -> Self {
+        CXXAabb {
+            position: value.position.into(),
+            size: value.size.into(),
+        }
+    }
+}
+
+impl From<CXXAabb> for Aabb {
+    fn from(value: CXXAabb) -> Self {
+        Aabb {
+            position: value.position.into(),
+            size: value.size.into(),
+        }
+    }
+}
+
+impl From<Basis> for CXXBasis {
+    fn from(value: Basis) -> Self {
+        CXXBasis {
+            x: value.x.into(),
+            y: value.y.into(),
+            z: value.z.into(),
+        }
+    }
+}
+
+impl From<CXXBasis> for Basis {
+    fn from(value: CXXBasis) -> Self {
+        Basis {
+            x: value.x.into(),
+            y: value.y.into(),
+            z: value.z.into(),
+        }
+    }
+}
+
+impl From<Transform> for CXXTransform {
+    fn from(value: Transform) -> Self {
+        CXXTransform {
+            basis: value.basis.into(),
+            origin: value.origin.into(),
+        }
+    }
+}
+
+impl From<CXXTransform> for Transform {
+    fn from(value: CXXTransform) -> Self {
+        Transform {
+            basis: value.basis.into(),
+            origin: value.origin.into(),
+        }
+    }
+}
+
+impl From<Color> for CXXColor {
+    fn from(value: Color) -> Self {
+        CXXColor {
+            r: value.r,
+            g: value.g,
+            b: value.b,
+            a: value.a,
+        }
+    }
+}
+
+impl From<CXXColor> for Color {
+    fn from(value: CXXColor) -> Self {
+        Color {
+            r: value.r,
+            g: value.g,
+            b: value.b,
+            a: value.a,
+        }
+    }
+}
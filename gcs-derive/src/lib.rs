@@ -0,0 +1,119 @@
+//! `#[derive(Component)]` for plain structs with named fields.
+//!
+//! ```ignore
+//! #[derive(Component)]
+//! #[component(name = "Velocity")]
+//! struct Velocity {
+//!     #[component(rename = "linear")]
+//!     value: Vector2,
+//!     damping: f64,
+//! }
+//! ```
+//!
+//! The component is named after the struct unless `name` is given, its fields after the struct
+//! fields unless `rename` is given. The type of every field must implement
+//! `gcs::component::typed_component::ComponentField`, which decides its `VariantType`.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr};
+
+#[proc_macro_derive(Component, attributes(component))]
+pub fn derive_component(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "Component can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "Component can only be derived for structs",
+            ))
+        }
+    };
+
+    let ident = &input.ident;
+    let name = read_attribute(&input.attrs, "name")?
+        .unwrap_or_else(|| LitStr::new(&ident.to_string(), Span::call_site()));
+
+    let mut idents = Vec::new();
+    let mut types = Vec::new();
+    let mut field_names = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.clone().unwrap();
+        let field_name = read_attribute(&field.attrs, "rename")?
+            .unwrap_or_else(|| LitStr::new(&field_ident.to_string(), Span::call_site()));
+        idents.push(field_ident);
+        types.push(field.ty.clone());
+        field_names.push(field_name);
+    }
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::gcs::component::typed_component::Component for #ident #type_generics #where_clause {
+            fn get_name() -> &'static str {
+                #name
+            }
+
+            fn get_fields() -> ::std::vec::Vec<(&'static str, ::gcs::variant::VariantType)> {
+                ::std::vec![#((
+                    #field_names,
+                    <#types as ::gcs::component::typed_component::ComponentField>::VARIANT_TYPE,
+                )),*]
+            }
+
+            fn from_data<TComponentData: ::gcs::component::component_data::ComponentData>(
+                data: &TComponentData,
+            ) -> ::std::result::Result<Self, ::gcs::component::value_conversion::ConversionError> {
+                ::std::result::Result::Ok(Self {
+                    #(#idents: <#types as ::gcs::component::typed_component::ComponentField>::from_value(
+                        data.get_field(#field_names),
+                    )?,)*
+                })
+            }
+
+            fn write_data<TComponentData: ::gcs::component::component_data::ComponentData>(
+                &self,
+                data: &mut TComponentData,
+            ) {
+                #(data.set_field(
+                    #field_names,
+                    &::gcs::component::typed_component::ComponentField::to_value(&self.#idents),
+                );)*
+            }
+        }
+    })
+}
+
+/// Value of `#[component(<key> = "...")]`, if present. Any other key is an error.
+fn read_attribute(attributes: &[Attribute], key: &str) -> Result<Option<LitStr>, Error> {
+    let mut value = None;
+    for attribute in attributes {
+        if !attribute.path().is_ident("component") {
+            continue;
+        }
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident(key) {
+                value = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error(format!("expected `{}`", key)))
+            }
+        })?;
+    }
+    Ok(value)
+}
@@ -0,0 +1,110 @@
+use gcs::backend::component_data::DefaultComponentData;
+use gcs::backend::component_definition::DefaultComponentDefinition;
+use gcs::backend::component_value::DefaultComponentValue;
+use gcs::backend::entity_id::DefaultEntityId;
+use gcs::backend::DefaultECSWorld;
+use gcs::component::component_data::ComponentData;
+use gcs::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+use gcs::component::typed_component::Component;
+use gcs::component::value_conversion::ConversionError;
+use gcs::entity::EntityId;
+use gcs::math::Vector2;
+use gcs::variant::VariantType;
+use gcs_derive::Component;
+
+#[derive(Component, Debug, PartialEq)]
+struct Health {
+    current: i64,
+    regenerating: bool,
+}
+
+#[derive(Component, Debug, PartialEq)]
+#[component(name = "Velocity")]
+struct Movement {
+    #[component(rename = "linear")]
+    value: Vector2,
+    damping: f64,
+    tags: Vec<String>,
+}
+
+#[test]
+fn definition_uses_field_names_and_types_of_the_struct() {
+    assert_eq!("Health", Health::get_name());
+    assert_eq!("Velocity", Movement::get_name());
+
+    let definition = Movement::create_definition::<DefaultComponentDefinition>();
+    let fields = definition
+        .get_fields()
+        .iter()
+        .map(|field| (field.get_name(), field.get_type()))
+        .collect::<Vec<(String, VariantType)>>();
+    assert_eq!(
+        vec![
+            ("linear".to_string(), VariantType::Vector2),
+            ("damping".to_string(), VariantType::Real),
+            ("tags".to_string(), VariantType::PoolStringArray),
+        ],
+        fields
+    );
+}
+
+#[test]
+fn components_round_trip_through_the_world() {
+    let mut world = DefaultECSWorld::default();
+    world
+        .register_component(
+            Movement::get_name().to_string(),
+            Movement::create_definition(),
+        )
+        .unwrap();
+    let entity = *world.create_entity();
+    let movement = Movement {
+        value: Vector2::new(1.0, -2.0),
+        damping: 0.5,
+        tags: vec!["player".to_string()],
+    };
+
+    world
+        .set_component_data(
+            &entity,
+            Movement::get_name().to_string(),
+            &movement.to_data(entity),
+        )
+        .unwrap();
+
+    let data = world
+        .get_component_of_entity(&entity, Movement::get_name().to_string())
+        .unwrap();
+    assert_eq!(
+        DefaultComponentValue::Real(0.5),
+        *data.get_field("damping")
+    );
+    assert_eq!(Ok(movement), Movement::from_data(&data));
+}
+
+#[test]
+fn from_data_reports_fields_of_the_wrong_type() {
+    let mut data = DefaultComponentData::<DefaultEntityId>::new(DefaultEntityId::create());
+    data.set_field(
+        "current",
+        &DefaultComponentValue::String("full".to_string()),
+    );
+
+    assert_eq!(
+        Err(ConversionError::InvalidValue {
+            from: VariantType::String,
+            to: VariantType::Int,
+            value: "full".to_string(),
+        }),
+        Health::from_data(&data)
+    );
+
+    data.set_field("current", &DefaultComponentValue::Int(3));
+    assert_eq!(
+        Ok(Health {
+            current: 3,
+            regenerating: false,
+        }),
+        Health::from_data(&data)
+    );
+}
@@ -0,0 +1,23 @@
+//! Godot-free implementations of the gcs traits, for tools, servers and tests.
+//!
+//! ```
+//! use gcs::backend::DefaultECSWorld;
+//!
+//! let mut world = DefaultECSWorld::default();
+//! let entity = world.create_entity();
+//! assert!(world.get_entities().any(|e| *e == *entity));
+//! ```
+
+use crate::world::ecs_world::ECSWorld;
+
+pub mod component_data;
+pub mod component_definition;
+pub mod component_info;
+pub mod component_value;
+pub mod entity_id;
+
+pub type DefaultECSWorld = ECSWorld<
+    component_definition::DefaultComponentDefinition,
+    component_data::DefaultComponentData,
+    component_info::DefaultComponentInfo,
+>;
@@ -0,0 +1,66 @@
+use crate::backend::component_value::DefaultComponentValue;
+use crate::backend::entity_id::DefaultEntityId;
+use crate::component::component_data::ComponentData;
+use crate::component::field_layout::{FieldLayout, FieldValues};
+use crate::entity::EntityId;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+#[derive(Clone, Debug)]
+pub struct DefaultComponentData<TEntityId: EntityId + Debug = DefaultEntityId> {
+    pub entity: TEntityId,
+    pub fields: FieldValues<DefaultComponentValue<TEntityId>>,
+}
+
+impl<TEntityId: EntityId + Debug> Default for DefaultComponentData<TEntityId> {
+    fn default() -> Self {
+        Self {
+            entity: TEntityId::default(),
+            fields: FieldValues::default(),
+        }
+    }
+}
+
+impl<TEntityId: EntityId + Debug> ComponentData for DefaultComponentData<TEntityId> {
+    type EntityIdType = TEntityId;
+    type ComponentValueType = DefaultComponentValue<TEntityId>;
+
+    fn new(entity: TEntityId) -> Self {
+        Self::with_layout(entity, Rc::default())
+    }
+
+    fn with_layout(entity: TEntityId, layout: Rc<FieldLayout>) -> Self {
+        DefaultComponentData {
+            entity,
+            fields: FieldValues::new(layout),
+        }
+    }
+
+    fn get_entity(&self) -> TEntityId {
+        self.entity
+    }
+
+    fn get_layout(&self) -> &FieldLayout {
+        self.fields.get_layout()
+    }
+
+    fn get_field(&self, field: &str) -> &Self::ComponentValueType {
+        self.fields
+            .get(field)
+            .unwrap_or(&DefaultComponentValue::Nil)
+    }
+
+    fn set_field(&mut self, field: &str, value: &Self::ComponentValueType) {
+        self.fields.set(field, value.clone());
+    }
+
+    fn get_field_at(&self, index: usize) -> &Self::ComponentValueType {
+        self.fields
+            .get_at(index)
+            .unwrap_or(&DefaultComponentValue::Nil)
+    }
+
+    fn set_field_at(&mut self, index: usize, value: &Self::ComponentValueType) {
+        self.fields.set_at(index, value.clone());
+    }
+}
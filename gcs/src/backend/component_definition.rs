@@ -0,0 +1,42 @@
+use crate::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+use crate::variant::VariantType;
+
+#[derive(Default, Clone, Hash, Debug, PartialEq, Eq)]
+pub struct DefaultComponentFieldDefinition {
+    pub name: String,
+    pub field_type: VariantType,
+}
+
+impl ComponentFieldDefinition for DefaultComponentFieldDefinition {
+    fn create(name: String, field_type: VariantType) -> Self
+    where
+        Self: Sized,
+    {
+        Self { name, field_type }
+    }
+
+    fn get_type(&self) -> VariantType {
+        self.field_type
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+#[derive(Default, Hash, Clone, PartialEq, Debug)]
+pub struct DefaultComponentDefinition {
+    pub fields: Vec<DefaultComponentFieldDefinition>,
+}
+
+impl ComponentDefinition for DefaultComponentDefinition {
+    type FieldDefinition = DefaultComponentFieldDefinition;
+
+    fn get_fields(&self) -> Vec<Self::FieldDefinition> {
+        self.fields.clone()
+    }
+
+    fn add_field(&mut self, field_definition: Self::FieldDefinition) {
+        self.fields.push(field_definition);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::component::component_info::ComponentInfo;
+
+#[derive(Default, Hash, Eq, PartialEq, Copy, Clone, Debug)]
+pub struct DefaultComponentInfo {
+    pub hash: u64,
+    pub index: u32,
+    pub world_id: u64,
+}
+
+impl ComponentInfo for DefaultComponentInfo {
+    fn get_hash(&self) -> u64 {
+        self.hash
+    }
+
+    fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    fn get_world_id(&self) -> u64 {
+        self.world_id
+    }
+
+    fn create(hash: u64, index: u32, world_id: u64) -> Self
+    where
+        Self: Sized,
+    {
+        Self {
+            hash,
+            index,
+            world_id,
+        }
+    }
+}
@@ -0,0 +1,360 @@
+use crate::backend::entity_id::DefaultEntityId;
+use crate::component::component_value::{
+    array_to_string, dictionary_to_string, pool_array_to_string, ComponentValue,
+};
+use crate::component::value_conversion::Scalar;
+use crate::component::value_dictionary::ValueDictionary;
+use crate::entity::EntityId;
+use crate::math::{
+    Aabb, Basis, Color, Plane, Quat, Rect2, Transform, Transform2D, Vector2, Vector3,
+};
+use crate::variant::VariantType;
+use std::fmt::Debug;
+
+/// Plain Rust value of a component field, one variant per supported `VariantType`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum DefaultComponentValue<TEntityId: EntityId = DefaultEntityId> {
+    #[default]
+    Nil,
+    Int(i64),
+    String(String),
+    Bool(bool),
+    Real(f64),
+    Vector2(Vector2),
+    Rect2(Rect2),
+    Vector3(Vector3),
+    Transform2D(Transform2D),
+    Plane(Plane),
+    Quat(Quat),
+    Aabb(Aabb),
+    Basis(Basis),
+    Transform(Transform),
+    Color(Color),
+    Array(Vec<DefaultComponentValue<TEntityId>>),
+    Dictionary(ValueDictionary<DefaultComponentValue<TEntityId>>),
+    PoolByteArray(Vec<u8>),
+    PoolIntArray(Vec<i32>),
+    PoolRealArray(Vec<f32>),
+    PoolStringArray(Vec<String>),
+    PoolVector2Array(Vec<Vector2>),
+    PoolVector3Array(Vec<Vector3>),
+    PoolColorArray(Vec<Color>),
+    Entity(TEntityId),
+}
+
+impl<TEntityId: EntityId + Debug> ComponentValue for DefaultComponentValue<TEntityId> {
+    type EntityIdType = TEntityId;
+
+    fn get_type(&self) -> VariantType {
+        match self {
+            DefaultComponentValue::Nil => VariantType::Nil,
+            DefaultComponentValue::Int(_) => VariantType::Int,
+            DefaultComponentValue::String(_) => VariantType::String,
+            DefaultComponentValue::Bool(_) => VariantType::Bool,
+            DefaultComponentValue::Real(_) => VariantType::Real,
+            DefaultComponentValue::Vector2(_) => VariantType::Vector2,
+            DefaultComponentValue::Rect2(_) => VariantType::Rect2,
+            DefaultComponentValue::Vector3(_) => VariantType::Vector3,
+            DefaultComponentValue::Transform2D(_) => VariantType::Transform2D,
+            DefaultComponentValue::Plane(_) => VariantType::Plan,
+            DefaultComponentValue::Quat(_) => VariantType::Quat,
+            DefaultComponentValue::Aabb(_) => VariantType::Aaab,
+            DefaultComponentValue::Basis(_) => VariantType::Basis,
+            DefaultComponentValue::Transform(_) => VariantType::Transform,
+            DefaultComponentValue::Color(_) => VariantType::Color,
+            DefaultComponentValue::Array(_) => VariantType::Array,
+            DefaultComponentValue::Dictionary(_) => VariantType::Dictionary,
+            DefaultComponentValue::PoolByteArray(_) => VariantType::PoolByteArray,
+            DefaultComponentValue::PoolIntArray(_) => VariantType::PoolIntArray,
+            DefaultComponentValue::PoolRealArray(_) => VariantType::PoolRealArray,
+            DefaultComponentValue::PoolStringArray(_) => VariantType::PoolStringArray,
+            DefaultComponentValue::PoolVector2Array(_) => VariantType::PoolVector2Array,
+            DefaultComponentValue::PoolVector3Array(_) => VariantType::PoolVector3Array,
+            DefaultComponentValue::PoolColorArray(_) => VariantType::PoolColorArray,
+            DefaultComponentValue::Entity(_) => VariantType::Entity,
+        }
+    }
+
+    fn as_scalar(&self) -> Scalar<'_> {
+        match self {
+            DefaultComponentValue::Nil => Scalar::Nil,
+            DefaultComponentValue::Bool(value) => Scalar::Bool(*value),
+            DefaultComponentValue::Int(value) => Scalar::Int(*value),
+            DefaultComponentValue::Real(value) => Scalar::Real(*value),
+            DefaultComponentValue::String(value) => Scalar::String(value),
+            _ => Scalar::Other(self.get_type()),
+        }
+    }
+
+    fn set_nil(&mut self) {
+        *self = DefaultComponentValue::Nil;
+    }
+
+    fn get_nil(&self) {}
+
+    fn set_int(&mut self, value: i64) {
+        *self = DefaultComponentValue::Int(value)
+    }
+
+    fn set_string(&mut self, value: String) {
+        *self = DefaultComponentValue::String(value)
+    }
+
+    fn get_string(&self) -> String {
+        match self {
+            DefaultComponentValue::Nil => "".to_string(),
+            DefaultComponentValue::Int(value) => value.to_string(),
+            DefaultComponentValue::String(value) => value.clone(),
+            DefaultComponentValue::Bool(value) => value.to_string(),
+            DefaultComponentValue::Real(value) => value.to_string(),
+            DefaultComponentValue::Vector2(value) => value.to_string(),
+            DefaultComponentValue::Rect2(value) => value.to_string(),
+            DefaultComponentValue::Vector3(value) => value.to_string(),
+            DefaultComponentValue::Transform2D(value) => value.to_string(),
+            DefaultComponentValue::Plane(value) => value.to_string(),
+            DefaultComponentValue::Quat(value) => value.to_string(),
+            DefaultComponentValue::Aabb(value) => value.to_string(),
+            DefaultComponentValue::Basis(value) => value.to_string(),
+            DefaultComponentValue::Transform(value) => value.to_string(),
+            DefaultComponentValue::Color(value) => value.to_string(),
+            DefaultComponentValue::Array(value) => array_to_string(value),
+            DefaultComponentValue::Dictionary(value) => dictionary_to_string(value),
+            DefaultComponentValue::PoolByteArray(value) => pool_array_to_string(value),
+            DefaultComponentValue::PoolIntArray(value) => pool_array_to_string(value),
+            DefaultComponentValue::PoolRealArray(value) => pool_array_to_string(value),
+            DefaultComponentValue::PoolStringArray(value) => pool_array_to_string(value),
+            DefaultComponentValue::PoolVector2Array(value) => pool_array_to_string(value),
+            DefaultComponentValue::PoolVector3Array(value) => pool_array_to_string(value),
+            DefaultComponentValue::PoolColorArray(value) => pool_array_to_string(value),
+            DefaultComponentValue::Entity(value) => value.as_string(),
+        }
+    }
+
+    fn set_bool(&mut self, value: bool) {
+        *self = DefaultComponentValue::Bool(value)
+    }
+
+    fn set_real(&mut self, value: f64) {
+        *self = DefaultComponentValue::Real(value)
+    }
+
+    fn set_vector2(&mut self, value: Vector2) {
+        *self = DefaultComponentValue::Vector2(value)
+    }
+
+    fn get_vector2(&self) -> Vector2 {
+        match self {
+            DefaultComponentValue::Vector2(value) => *value,
+            _ => Vector2::default(),
+        }
+    }
+
+    fn set_rect2(&mut self, value: Rect2) {
+        *self = DefaultComponentValue::Rect2(value)
+    }
+
+    fn get_rect2(&self) -> Rect2 {
+        match self {
+            DefaultComponentValue::Rect2(value) => *value,
+            _ => Rect2::default(),
+        }
+    }
+
+    fn set_vector3(&mut self, value: Vector3) {
+        *self = DefaultComponentValue::Vector3(value)
+    }
+
+    fn get_vector3(&self) -> Vector3 {
+        match self {
+            DefaultComponentValue::Vector3(value) => *value,
+            _ => Vector3::default(),
+        }
+    }
+
+    fn set_transform2d(&mut self, value: Transform2D) {
+        *self = DefaultComponentValue::Transform2D(value)
+    }
+
+    fn get_transform2d(&self) -> Transform2D {
+        match self {
+            DefaultComponentValue::Transform2D(value) => *value,
+            _ => Transform2D::default(),
+        }
+    }
+
+    fn set_plane(&mut self, value: Plane) {
+        *self = DefaultComponentValue::Plane(value)
+    }
+
+    fn get_plane(&self) -> Plane {
+        match self {
+            DefaultComponentValue::Plane(value) => *value,
+            _ => Plane::default(),
+        }
+    }
+
+    fn set_quat(&mut self, value: Quat) {
+        *self = DefaultComponentValue::Quat(value)
+    }
+
+    fn get_quat(&self) -> Quat {
+        match self {
+            DefaultComponentValue::Quat(value) => *value,
+            _ => Quat::default(),
+        }
+    }
+
+    fn set_aabb(&mut self, value: Aabb) {
+        *self = DefaultComponentValue::Aabb(value)
+    }
+
+    fn get_aabb(&self) -> Aabb {
+        match self {
+            DefaultComponentValue::Aabb(value) => *value,
+            _ => Aabb::default(),
+        }
+    }
+
+    fn set_basis(&mut self, value: Basis) {
+        *self = DefaultComponentValue::Basis(value)
+    }
+
+    fn get_basis(&self) -> Basis {
+        match self {
+            DefaultComponentValue::Basis(value) => *value,
+            _ => Basis::default(),
+        }
+    }
+
+    fn set_transform(&mut self, value: Transform) {
+        *self = DefaultComponentValue::Transform(value)
+    }
+
+    fn get_transform(&self) -> Transform {
+        match self {
+            DefaultComponentValue::Transform(value) => *value,
+            _ => Transform::default(),
+        }
+    }
+
+    fn set_color(&mut self, value: Color) {
+        *self = DefaultComponentValue::Color(value)
+    }
+
+    fn get_color(&self) -> Color {
+        match self {
+            DefaultComponentValue::Color(value) => *value,
+            _ => Color::default(),
+        }
+    }
+
+    fn set_array(&mut self, value: Vec<DefaultComponentValue<TEntityId>>) {
+        *self = DefaultComponentValue::Array(value)
+    }
+
+    fn get_array(&self) -> Vec<DefaultComponentValue<TEntityId>> {
+        match self {
+            DefaultComponentValue::Array(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_dictionary(&mut self, value: ValueDictionary<DefaultComponentValue<TEntityId>>) {
+        *self = DefaultComponentValue::Dictionary(value)
+    }
+
+    fn get_dictionary(&self) -> ValueDictionary<DefaultComponentValue<TEntityId>> {
+        match self {
+            DefaultComponentValue::Dictionary(value) => value.clone(),
+            _ => ValueDictionary::new(),
+        }
+    }
+
+    fn set_pool_byte_array(&mut self, value: Vec<u8>) {
+        *self = DefaultComponentValue::PoolByteArray(value)
+    }
+
+    fn get_pool_byte_array(&self) -> Vec<u8> {
+        match self {
+            DefaultComponentValue::PoolByteArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_int_array(&mut self, value: Vec<i32>) {
+        *self = DefaultComponentValue::PoolIntArray(value)
+    }
+
+    fn get_pool_int_array(&self) -> Vec<i32> {
+        match self {
+            DefaultComponentValue::PoolIntArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_real_array(&mut self, value: Vec<f32>) {
+        *self = DefaultComponentValue::PoolRealArray(value)
+    }
+
+    fn get_pool_real_array(&self) -> Vec<f32> {
+        match self {
+            DefaultComponentValue::PoolRealArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_string_array(&mut self, value: Vec<String>) {
+        *self = DefaultComponentValue::PoolStringArray(value)
+    }
+
+    fn get_pool_string_array(&self) -> Vec<String> {
+        match self {
+            DefaultComponentValue::PoolStringArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_vector2_array(&mut self, value: Vec<Vector2>) {
+        *self = DefaultComponentValue::PoolVector2Array(value)
+    }
+
+    fn get_pool_vector2_array(&self) -> Vec<Vector2> {
+        match self {
+            DefaultComponentValue::PoolVector2Array(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_vector3_array(&mut self, value: Vec<Vector3>) {
+        *self = DefaultComponentValue::PoolVector3Array(value)
+    }
+
+    fn get_pool_vector3_array(&self) -> Vec<Vector3> {
+        match self {
+            DefaultComponentValue::PoolVector3Array(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_pool_color_array(&mut self, value: Vec<Color>) {
+        *self = DefaultComponentValue::PoolColorArray(value)
+    }
+
+    fn get_pool_color_array(&self) -> Vec<Color> {
+        match self {
+            DefaultComponentValue::PoolColorArray(value) => value.clone(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn set_entity(&mut self, value: TEntityId) {
+        *self = DefaultComponentValue::Entity(value)
+    }
+
+    fn get_entity(&self) -> Option<TEntityId> {
+        match self {
+            DefaultComponentValue::Entity(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
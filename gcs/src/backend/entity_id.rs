@@ -0,0 +1,50 @@
+use crate::entity::EntityId;
+use uuid::Uuid;
+
+/// Entity id backed by a UUID, the same representation the Godot bindings use.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct DefaultEntityId(pub Uuid);
+
+impl EntityId for DefaultEntityId {
+    fn create() -> Self
+    where
+        Self: Sized,
+    {
+        DefaultEntityId(Uuid::new_v4())
+    }
+
+    fn from_u128(value: u128) -> Self
+    where
+        Self: Sized,
+    {
+        DefaultEntityId(Uuid::from_u128(value))
+    }
+
+    fn as_string(&self) -> String {
+        self.0.to_hyphenated().to_string()
+    }
+
+    fn parse_str(input: &str) -> Result<Self, String>
+    where
+        Self: Sized,
+    {
+        match Uuid::parse_str(input) {
+            Ok(uuid) => Ok(DefaultEntityId(uuid)),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::entity_id::DefaultEntityId;
+    use crate::entity::EntityId;
+
+    #[test]
+    fn parse_str_reads_what_as_string_writes() {
+        let entity = DefaultEntityId::from_u128(0x1234);
+        assert_eq!("00000000-0000-0000-0000-000000001234", entity.as_string());
+        assert_eq!(Ok(entity), DefaultEntityId::parse_str(&entity.as_string()));
+        assert!(DefaultEntityId::parse_str("not an id").is_err());
+    }
+}
@@ -1,4 +1,9 @@
 pub mod component_data;
 pub mod component_definition;
+pub mod component_hash;
 pub mod component_info;
 pub mod component_value;
+pub mod field_layout;
+pub mod typed_component;
+pub mod value_conversion;
+pub mod value_dictionary;
@@ -1,12 +1,23 @@
 use crate::component::component_value::ComponentValue;
+use crate::component::field_layout::FieldLayout;
 use crate::entity::EntityId;
+use std::rc::Rc;
 
 pub trait ComponentData: Default + Clone {
     type EntityIdType: EntityId;
-    type ComponentValueType: ComponentValue;
+    type ComponentValueType: ComponentValue<EntityIdType = Self::EntityIdType>;
 
+    /// Data with a layout of its own, which grows with every new field that is set.
     fn new(entity: Self::EntityIdType) -> Self;
+    /// Data that stores its fields by their index in `layout`.
+    fn with_layout(entity: Self::EntityIdType, layout: Rc<FieldLayout>) -> Self;
     fn get_entity(&self) -> Self::EntityIdType;
-    fn get_field(&self, field: String) -> &Self::ComponentValueType;
-    fn set_field(&mut self, field: String, value: &Self::ComponentValueType);
+    fn get_layout(&self) -> &FieldLayout;
+    /// Nil if the field is not set.
+    fn get_field(&self, field: &str) -> &Self::ComponentValueType;
+    fn set_field(&mut self, field: &str, value: &Self::ComponentValueType);
+    /// Nil if the field is not set.
+    fn get_field_at(&self, index: usize) -> &Self::ComponentValueType;
+    /// Panics if `index` is not part of the layout.
+    fn set_field_at(&mut self, index: usize, value: &Self::ComponentValueType);
 }
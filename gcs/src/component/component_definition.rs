@@ -8,6 +8,9 @@ pub trait ComponentDefinition: Default + Hash + Clone {
 }
 
 pub trait ComponentFieldDefinition: Default + Hash + Clone + Eq {
+    fn create(name: String, field_type: VariantType) -> Self
+    where
+        Self: Sized;
     fn get_type(&self) -> VariantType;
     fn get_name(&self) -> String;
 }
@@ -0,0 +1,123 @@
+use std::hash::Hasher;
+
+use crate::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+
+/// 64 bit FNV-1a, which produces the same output on every platform and Rust version.
+pub(crate) struct Fnv1aHasher(u64);
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+impl Fnv1aHasher {
+    pub(crate) fn write_str(&mut self, value: &str) {
+        self.write_u64_le(value.len() as u64);
+        self.write(value.as_bytes());
+    }
+
+    pub(crate) fn write_u32_le(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    pub(crate) fn write_u64_le(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+}
+
+/// Hash used for `ComponentInfo`. It is 64 bit FNV-1a over the following encoding:
+///
+/// - the component name
+/// - the number of fields as a little endian u64
+/// - for every field in definition order, its name and its `VariantType` id as a little endian u32
+///
+/// Strings are encoded as their byte length as a little endian u64 followed by their UTF-8 bytes.
+pub fn hash_component<TComponentDefinition: ComponentDefinition>(
+    name: &str,
+    component_definition: &TComponentDefinition,
+) -> u64 {
+    let mut hasher = Fnv1aHasher::default();
+    hasher.write_str(name);
+    let fields = component_definition.get_fields();
+    hasher.write_u64_le(fields.len() as u64);
+    for field in fields {
+        hasher.write_str(&field.get_name());
+        hasher.write_u32_le(field.get_type() as u32);
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+    use crate::component::component_hash::hash_component;
+    use crate::variant::VariantType;
+    use crate::world::ecs_world::tests::{TestComponentDefinition, TestComponentFieldDefinition};
+
+    fn create_definition(fields: &[(&str, VariantType)]) -> TestComponentDefinition {
+        let mut definition = TestComponentDefinition::default();
+        for (name, field_type) in fields {
+            definition.add_field(TestComponentFieldDefinition::create(
+                name.to_string(),
+                *field_type,
+            ));
+        }
+        definition
+    }
+
+    #[test]
+    fn hash_component_produces_known_values() {
+        assert_eq!(
+            16274136872688051481,
+            hash_component("Test", &create_definition(&[]))
+        );
+        assert_eq!(
+            14935075019001874591,
+            hash_component("Test", &create_definition(&[("Field", VariantType::Nil)]))
+        );
+        assert_eq!(
+            6060647140612084659,
+            hash_component(
+                "Position",
+                &create_definition(&[("x", VariantType::Real), ("y", VariantType::Real)])
+            )
+        );
+    }
+
+    #[test]
+    fn hash_component_depends_on_field_order_and_types() {
+        let hash = hash_component(
+            "Position",
+            &create_definition(&[("x", VariantType::Real), ("y", VariantType::Real)]),
+        );
+
+        assert_ne!(
+            hash,
+            hash_component(
+                "Position",
+                &create_definition(&[("y", VariantType::Real), ("x", VariantType::Real)])
+            )
+        );
+        assert_ne!(
+            hash,
+            hash_component(
+                "Position",
+                &create_definition(&[("x", VariantType::Int), ("y", VariantType::Real)])
+            )
+        );
+    }
+}
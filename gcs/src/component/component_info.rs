@@ -1,8 +1,14 @@
 use std::hash::Hash;
 
+/// Handle of a registered component, returned by `ECSWorld::register_component`.
 pub trait ComponentInfo: Hash + Default + Eq + Copy + Clone {
+    /// Hash of the name and definition of the component, see `hash_component`.
     fn get_hash(&self) -> u64;
-    fn create(hash: u64) -> Self
+    /// Dense index of the component in the world that created the handle.
+    fn get_index(&self) -> u32;
+    /// Id of the world that created the handle, never 0 for a handle created by a world.
+    fn get_world_id(&self) -> u64;
+    fn create(hash: u64, index: u32, world_id: u64) -> Self
     where
         Self: Sized;
 }
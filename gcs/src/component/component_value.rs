@@ -1,16 +1,113 @@
+use crate::component::value_conversion::{
+    bool_from_scalar, int_from_scalar, real_from_scalar, ConversionError, Scalar,
+};
+use crate::component::value_dictionary::ValueDictionary;
+use crate::entity::EntityId;
+use crate::math::{
+    Aabb, Basis, Color, Plane, Quat, Rect2, Transform, Transform2D, Vector2, Vector3,
+};
 use crate::variant::VariantType;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
+
+pub trait ComponentValue: Default + Clone + PartialEq + Debug {
+    type EntityIdType: EntityId;
 
-pub trait ComponentValue: Clone + PartialEq + Debug {
     fn get_type(&self) -> VariantType;
+    /// View of the value used by the conversion rules in `value_conversion`.
+    fn as_scalar(&self) -> Scalar<'_>;
     fn set_nil(&mut self);
     fn get_nil(&self) -> ();
     fn set_int(&mut self, value: i64);
-    fn get_int(&self) -> i64;
+    fn get_int(&self) -> i64 {
+        self.try_get_int().unwrap_or_default()
+    }
+    fn try_get_int(&self) -> Result<i64, ConversionError> {
+        int_from_scalar(self.as_scalar())
+    }
     fn set_string(&mut self, value: String);
     fn get_string(&self) -> String;
     fn set_bool(&mut self, value: bool);
-    fn get_bool(&self) -> bool;
+    fn get_bool(&self) -> bool {
+        self.try_get_bool().unwrap_or_default()
+    }
+    fn try_get_bool(&self) -> Result<bool, ConversionError> {
+        bool_from_scalar(self.as_scalar())
+    }
     fn set_real(&mut self, value: f64);
-    fn get_real(&self) -> f64;
+    fn get_real(&self) -> f64 {
+        self.try_get_real().unwrap_or_default()
+    }
+    fn try_get_real(&self) -> Result<f64, ConversionError> {
+        real_from_scalar(self.as_scalar())
+    }
+    fn set_vector2(&mut self, value: Vector2);
+    fn get_vector2(&self) -> Vector2;
+    fn set_rect2(&mut self, value: Rect2);
+    fn get_rect2(&self) -> Rect2;
+    fn set_vector3(&mut self, value: Vector3);
+    fn get_vector3(&self) -> Vector3;
+    fn set_transform2d(&mut self, value: Transform2D);
+    fn get_transform2d(&self) -> Transform2D;
+    fn set_plane(&mut self, value: Plane);
+    fn get_plane(&self) -> Plane;
+    fn set_quat(&mut self, value: Quat);
+    fn get_quat(&self) -> Quat;
+    fn set_aabb(&mut self, value: Aabb);
+    fn get_aabb(&self) -> Aabb;
+    fn set_basis(&mut self, value: Basis);
+    fn get_basis(&self) -> Basis;
+    fn set_transform(&mut self, value: Transform);
+    fn get_transform(&self) -> Transform;
+    fn set_color(&mut self, value: Color);
+    fn get_color(&self) -> Color;
+    fn set_array(&mut self, value: Vec<Self>);
+    fn get_array(&self) -> Vec<Self>;
+    fn set_dictionary(&mut self, value: ValueDictionary<Self>);
+    fn get_dictionary(&self) -> ValueDictionary<Self>;
+    fn set_pool_byte_array(&mut self, value: Vec<u8>);
+    fn get_pool_byte_array(&self) -> Vec<u8>;
+    fn set_pool_int_array(&mut self, value: Vec<i32>);
+    fn get_pool_int_array(&self) -> Vec<i32>;
+    fn set_pool_real_array(&mut self, value: Vec<f32>);
+    fn get_pool_real_array(&self) -> Vec<f32>;
+    fn set_pool_string_array(&mut self, value: Vec<String>);
+    fn get_pool_string_array(&self) -> Vec<String>;
+    fn set_pool_vector2_array(&mut self, value: Vec<Vector2>);
+    fn get_pool_vector2_array(&self) -> Vec<Vector2>;
+    fn set_pool_vector3_array(&mut self, value: Vec<Vector3>);
+    fn get_pool_vector3_array(&self) -> Vec<Vector3>;
+    fn set_pool_color_array(&mut self, value: Vec<Color>);
+    fn get_pool_color_array(&self) -> Vec<Color>;
+    fn set_entity(&mut self, value: Self::EntityIdType);
+    /// `None` unless the value is an entity reference.
+    fn get_entity(&self) -> Option<Self::EntityIdType>;
+}
+
+/// Formats an array value the way Godot's `str()` does, e.g. `[1, a, 2.5]`.
+pub fn array_to_string<TComponentValue: ComponentValue>(values: &[TComponentValue]) -> String {
+    let values = values
+        .iter()
+        .map(|value| value.get_string())
+        .collect::<Vec<String>>();
+    format!("[{}]", values.join(", "))
+}
+
+/// Formats a dictionary value the way Godot's `str()` does, e.g. `{a:1, b:2}`.
+pub fn dictionary_to_string<TComponentValue: ComponentValue>(
+    dictionary: &ValueDictionary<TComponentValue>,
+) -> String {
+    let entries = dictionary
+        .iter()
+        .map(|(key, value)| format!("{}:{}", key.get_string(), value.get_string()))
+        .collect::<Vec<String>>();
+    format!("{{{}}}", entries.join(", "))
+}
+
+/// Formats a packed array value the way Godot's `str()` does, e.g. `[1, 2, 3]`.
+pub fn pool_array_to_string<T: Display>(values: &[T]) -> String {
+    let values = values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>();
+    format!("[{}]", values.join(", "))
 }
@@ -0,0 +1,134 @@
+use crate::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+use crate::component::component_value::ComponentValue;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Maps the field names of a component to the indices its data stores the fields at.
+/// A world creates one layout per registered component and shares it between all data of that
+/// component, so field names are stored once instead of once per entity.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct FieldLayout {
+    names: Vec<String>,
+    indices: HashMap<String, usize>,
+}
+
+impl FieldLayout {
+    pub fn from_definition<TComponentDefinition: ComponentDefinition>(
+        definition: &TComponentDefinition,
+    ) -> Self {
+        let mut layout = Self::default();
+        for field in definition.get_fields() {
+            layout.push(field.get_name());
+        }
+        layout
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn get_index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn get_name(&self, index: usize) -> Option<&str> {
+        self.names.get(index).map(String::as_str)
+    }
+
+    pub fn get_names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Index of `name`, which is appended to the layout if it is not part of it yet.
+    pub fn push(&mut self, name: String) -> usize {
+        if let Some(index) = self.get_index(&name) {
+            return index;
+        }
+        let index = self.names.len();
+        self.indices.insert(name.clone(), index);
+        self.names.push(name);
+        index
+    }
+}
+
+/// Field values stored by the index of their field in a shared `FieldLayout`.
+/// Setting a field that is not part of the layout gives the values a layout of their own.
+#[derive(Clone, Default, Debug)]
+pub struct FieldValues<TComponentValue: ComponentValue> {
+    layout: Rc<FieldLayout>,
+    values: Vec<TComponentValue>,
+}
+
+impl<TComponentValue: ComponentValue> FieldValues<TComponentValue> {
+    pub fn new(layout: Rc<FieldLayout>) -> Self {
+        let values = vec![TComponentValue::default(); layout.len()];
+        Self { layout, values }
+    }
+
+    pub fn get_layout(&self) -> &FieldLayout {
+        &self.layout
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TComponentValue> {
+        self.get_at(self.layout.get_index(name)?)
+    }
+
+    pub fn get_at(&self, index: usize) -> Option<&TComponentValue> {
+        self.values.get(index)
+    }
+
+    pub fn set(&mut self, name: &str, value: TComponentValue) {
+        let index = match self.layout.get_index(name) {
+            Some(index) => index,
+            None => Rc::make_mut(&mut self.layout).push(name.to_string()),
+        };
+        self.set_at(index, value);
+    }
+
+    /// Panics if `index` is not part of the layout.
+    pub fn set_at(&mut self, index: usize, value: TComponentValue) {
+        assert!(
+            index < self.layout.len(),
+            "Field index {} is out of range for {} fields",
+            index,
+            self.layout.len()
+        );
+        if index >= self.values.len() {
+            self.values
+                .resize(self.layout.len(), TComponentValue::default());
+        }
+        self.values[index] = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::component_value::DefaultComponentValue;
+    use crate::component::field_layout::{FieldLayout, FieldValues};
+    use std::rc::Rc;
+
+    #[test]
+    fn values_share_the_layout_until_an_unknown_field_is_set() {
+        let mut layout = FieldLayout::default();
+        assert_eq!(0, layout.push("a".to_string()));
+        assert_eq!(1, layout.push("b".to_string()));
+        assert_eq!(0, layout.push("a".to_string()));
+        let layout = Rc::new(layout);
+
+        let mut values = FieldValues::<DefaultComponentValue>::new(layout.clone());
+        values.set("b", DefaultComponentValue::Int(2));
+        assert_eq!(Some(&DefaultComponentValue::Nil), values.get("a"));
+        assert_eq!(Some(&DefaultComponentValue::Int(2)), values.get_at(1));
+        assert_eq!(2, Rc::strong_count(&layout));
+
+        values.set("c", DefaultComponentValue::Bool(true));
+        assert_eq!(Some(2), values.get_layout().get_index("c"));
+        assert_eq!(Some(&DefaultComponentValue::Bool(true)), values.get("c"));
+        assert_eq!(None, layout.get_index("c"));
+        assert_eq!(1, Rc::strong_count(&layout));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::component::component_data::ComponentData;
+use crate::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+use crate::component::component_value::ComponentValue;
+use crate::component::value_conversion::ConversionError;
+use crate::math::{
+    Aabb, Basis, Color, Plane, Quat, Rect2, Transform, Transform2D, Vector2, Vector3,
+};
+use crate::variant::VariantType;
+
+/// A Rust struct with a statically known layout that is stored as component data.
+/// Usually implemented with `#[derive(Component)]` from `gcs-derive`.
+pub trait Component: Sized {
+    fn get_name() -> &'static str;
+    /// `(name, type)` of every field, in declaration order.
+    fn get_fields() -> Vec<(&'static str, VariantType)>;
+    fn from_data<TComponentData: ComponentData>(
+        data: &TComponentData,
+    ) -> Result<Self, ConversionError>;
+    fn write_data<TComponentData: ComponentData>(&self, data: &mut TComponentData);
+
+    fn create_definition<TComponentDefinition: ComponentDefinition>() -> TComponentDefinition {
+        let mut definition = TComponentDefinition::default();
+        for (name, field_type) in Self::get_fields() {
+            definition.add_field(TComponentDefinition::FieldDefinition::create(
+                name.to_string(),
+                field_type,
+            ));
+        }
+        definition
+    }
+
+    fn to_data<TComponentData: ComponentData>(
+        &self,
+        entity: TComponentData::EntityIdType,
+    ) -> TComponentData {
+        let mut data = TComponentData::new(entity);
+        self.write_data(&mut data);
+        data
+    }
+}
+
+/// A Rust type that can be a field of a `Component`.
+/// Reading is strict: apart from the numeric conversions of `value_conversion`, only a value of
+/// the field's own type or Nil, which gives the default, is accepted.
+pub trait ComponentField: Sized {
+    const VARIANT_TYPE: VariantType;
+
+    fn to_value<TComponentValue: ComponentValue>(&self) -> TComponentValue;
+    fn from_value<TComponentValue: ComponentValue>(
+        value: &TComponentValue,
+    ) -> Result<Self, ConversionError>;
+}
+
+fn expect_type<TComponentValue: ComponentValue>(
+    value: &TComponentValue,
+    variant_type: VariantType,
+) -> Result<(), ConversionError> {
+    match value.get_type() {
+        VariantType::Nil => Ok(()),
+        from if from == variant_type => Ok(()),
+        from => Err(ConversionError::Unsupported {
+            from,
+            to: variant_type,
+        }),
+    }
+}
+
+macro_rules! impl_component_field {
+    ($type:ty, $variant_type:ident, $set:ident, $get:ident) => {
+        impl ComponentField for $type {
+            const VARIANT_TYPE: VariantType = VariantType::$variant_type;
+
+            fn to_value<TComponentValue: ComponentValue>(&self) -> TComponentValue {
+                let mut value = TComponentValue::default();
+                value.$set(self.clone());
+                value
+            }
+
+            fn from_value<TComponentValue: ComponentValue>(
+                value: &TComponentValue,
+            ) -> Result<Self, ConversionError> {
+                expect_type(value, Self::VARIANT_TYPE)?;
+                Ok(value.$get())
+            }
+        }
+    };
+}
+
+impl ComponentField for i64 {
+    const VARIANT_TYPE: VariantType = VariantType::Int;
+
+    fn to_value<TComponentValue: ComponentValue>(&self) -> TComponentValue {
+        let mut value = TComponentValue::default();
+        value.set_int(*self);
+        value
+    }
+
+    fn from_value<TComponentValue: ComponentValue>(
+        value: &TComponentValue,
+    ) -> Result<Self, ConversionError> {
+        value.try_get_int()
+    }
+}
+
+impl ComponentField for f64 {
+    const VARIANT_TYPE: VariantType = VariantType::Real;
+
+    fn to_value<TComponentValue: ComponentValue>(&self) -> TComponentValue {
+        let mut value = TComponentValue::default();
+        value.set_real(*self);
+        value
+    }
+
+    fn from_value<TComponentValue: ComponentValue>(
+        value: &TComponentValue,
+    ) -> Result<Self, ConversionError> {
+        value.try_get_real()
+    }
+}
+
+impl ComponentField for bool {
+    const VARIANT_TYPE: VariantType = VariantType::Bool;
+
+    fn to_value<TComponentValue: ComponentValue>(&self) -> TComponentValue {
+        let mut value = TComponentValue::default();
+        value.set_bool(*self);
+        value
+    }
+
+    fn from_value<TComponentValue: ComponentValue>(
+        value: &TComponentValue,
+    ) -> Result<Self, ConversionError> {
+        value.try_get_bool()
+    }
+}
+
+impl_component_field!(String, String, set_string, get_string);
+impl_component_field!(Vector2, Vector2, set_vector2, get_vector2);
+impl_component_field!(Rect2, Rect2, set_rect2, get_rect2);
+impl_component_field!(Vector3, Vector3, set_vector3, get_vector3);
+impl_component_field!(Transform2D, Transform2D, set_transform2d, get_transform2d);
+impl_component_field!(Plane, Plan, set_plane, get_plane);
+impl_component_field!(Quat, Quat, set_quat, get_quat);
+impl_component_field!(Aabb, Aaab, set_aabb, get_aabb);
+impl_component_field!(Basis, Basis, set_basis, get_basis);
+impl_component_field!(Transform, Transform, set_transform, get_transform);
+impl_component_field!(Color, Color, set_color, get_color);
+impl_component_field!(
+    Vec<u8>,
+    PoolByteArray,
+    set_pool_byte_array,
+    get_pool_byte_array
+);
+impl_component_field!(
+    Vec<i32>,
+    PoolIntArray,
+    set_pool_int_array,
+    get_pool_int_array
+);
+impl_component_field!(
+    Vec<f32>,
+    PoolRealArray,
+    set_pool_real_array,
+    get_pool_real_array
+);
+impl_component_field!(
+    Vec<String>,
+    PoolStringArray,
+    set_pool_string_array,
+    get_pool_string_array
+);
+impl_component_field!(
+    Vec<Vector2>,
+    PoolVector2Array,
+    set_pool_vector2_array,
+    get_pool_vector2_array
+);
+impl_component_field!(
+    Vec<Vector3>,
+    PoolVector3Array,
+    set_pool_vector3_array,
+    get_pool_vector3_array
+);
+impl_component_field!(
+    Vec<Color>,
+    PoolColorArray,
+    set_pool_color_array,
+    get_pool_color_array
+);
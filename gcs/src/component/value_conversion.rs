@@ -0,0 +1,194 @@
+//! Conversion rules shared by every `ComponentValue` implementation.
+//!
+//! | from \ to | Int                       | Real            | Bool                   |
+//! |-----------|---------------------------|-----------------|------------------------|
+//! | Nil       | `0`                       | `0.0`           | `false`                |
+//! | Bool      | `0` or `1`                | `0.0` or `1.0`  | itself                 |
+//! | Int       | itself                    | nearest real    | `value != 0`           |
+//! | Real      | truncated, if it fits     | itself          | `value != 0.0`         |
+//! | String    | parsed as an integer      | parsed as real  | `"true"` or `"false"`  |
+//! | any other | error                     | error           | error                  |
+//!
+//! Every value converts to a String, and all other getters only accept their own type.
+//! The lenient getters (`get_int`, `get_real`, `get_bool`) return the default of the target type
+//! where this matrix gives an error.
+
+use crate::variant::VariantType;
+use crate::world::errors::ErrorKind;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Borrowed view of the part of a value that takes part in conversions.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Scalar<'a> {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    Real(f64),
+    String(&'a str),
+    /// Any value that only converts to itself.
+    Other(VariantType),
+}
+
+impl Scalar<'_> {
+    pub fn get_type(&self) -> VariantType {
+        match self {
+            Scalar::Nil => VariantType::Nil,
+            Scalar::Bool(_) => VariantType::Bool,
+            Scalar::Int(_) => VariantType::Int,
+            Scalar::Real(_) => VariantType::Real,
+            Scalar::String(_) => VariantType::String,
+            Scalar::Other(variant_type) => *variant_type,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub enum ConversionError {
+    Unsupported {
+        from: VariantType,
+        to: VariantType,
+    },
+    InvalidValue {
+        from: VariantType,
+        to: VariantType,
+        value: String,
+    },
+}
+
+impl ConversionError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::InvalidData
+    }
+
+    pub fn get_source_type(&self) -> VariantType {
+        match self {
+            Self::Unsupported { from, .. } | Self::InvalidValue { from, .. } => *from,
+        }
+    }
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported { from, to } => {
+                write!(f, "Cannot convert a {:?} to a {:?}", from, to)
+            }
+            Self::InvalidValue { from, to, value } => {
+                write!(f, "Cannot convert the {:?} '{}' to a {:?}", from, value, to)
+            }
+        }
+    }
+}
+
+impl Error for ConversionError {}
+
+fn unsupported(value: Scalar, to: VariantType) -> ConversionError {
+    ConversionError::Unsupported {
+        from: value.get_type(),
+        to,
+    }
+}
+
+fn invalid_value(value: &str, to: VariantType) -> ConversionError {
+    ConversionError::InvalidValue {
+        from: VariantType::String,
+        to,
+        value: value.to_string(),
+    }
+}
+
+pub fn int_from_scalar(value: Scalar) -> Result<i64, ConversionError> {
+    match value {
+        Scalar::Nil => Ok(0),
+        Scalar::Bool(value) => Ok(i64::from(value)),
+        Scalar::Int(value) => Ok(value),
+        // the upper bound is exclusive, as i64::MAX rounds up to 2^63
+        Scalar::Real(real)
+            if real.is_finite() && real >= i64::MIN as f64 && real < i64::MAX as f64 =>
+        {
+            Ok(real as i64)
+        }
+        Scalar::Real(real) => Err(ConversionError::InvalidValue {
+            from: VariantType::Real,
+            to: VariantType::Int,
+            value: real.to_string(),
+        }),
+        Scalar::String(string) => string
+            .parse::<i64>()
+            .map_err(|_| invalid_value(string, VariantType::Int)),
+        Scalar::Other(_) => Err(unsupported(value, VariantType::Int)),
+    }
+}
+
+pub fn real_from_scalar(value: Scalar) -> Result<f64, ConversionError> {
+    match value {
+        Scalar::Nil => Ok(0.0),
+        Scalar::Bool(value) => Ok(f64::from(u8::from(value))),
+        Scalar::Int(value) => Ok(value as f64),
+        Scalar::Real(value) => Ok(value),
+        Scalar::String(string) => string
+            .parse::<f64>()
+            .map_err(|_| invalid_value(string, VariantType::Real)),
+        Scalar::Other(_) => Err(unsupported(value, VariantType::Real)),
+    }
+}
+
+pub fn bool_from_scalar(value: Scalar) -> Result<bool, ConversionError> {
+    match value {
+        Scalar::Nil => Ok(false),
+        Scalar::Bool(value) => Ok(value),
+        Scalar::Int(value) => Ok(value != 0),
+        Scalar::Real(value) => Ok(value != 0.0),
+        Scalar::String(string) => string
+            .parse::<bool>()
+            .map_err(|_| invalid_value(string, VariantType::Bool)),
+        Scalar::Other(_) => Err(unsupported(value, VariantType::Bool)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::component::value_conversion::{
+        bool_from_scalar, int_from_scalar, real_from_scalar, ConversionError, Scalar,
+    };
+    use crate::variant::VariantType;
+
+    #[test]
+    fn strings_convert_only_when_they_parse() {
+        assert_eq!(int_from_scalar(Scalar::String("42")), Ok(42));
+        assert_eq!(real_from_scalar(Scalar::String("2.5")), Ok(2.5));
+        assert_eq!(bool_from_scalar(Scalar::String("true")), Ok(true));
+        assert_eq!(bool_from_scalar(Scalar::String("false")), Ok(false));
+
+        let error = bool_from_scalar(Scalar::String("")).unwrap_err();
+        assert_eq!(error.get_source_type(), VariantType::String);
+        assert_eq!(
+            int_from_scalar(Scalar::String("4x")),
+            Err(ConversionError::InvalidValue {
+                from: VariantType::String,
+                to: VariantType::Int,
+                value: "4x".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn reals_convert_to_int_only_when_they_fit() {
+        assert_eq!(int_from_scalar(Scalar::Real(-2.9)), Ok(-2));
+        assert!(int_from_scalar(Scalar::Real(f64::NAN)).is_err());
+        assert!(int_from_scalar(Scalar::Real(1e19)).is_err());
+    }
+
+    #[test]
+    fn other_types_do_not_convert() {
+        assert_eq!(
+            real_from_scalar(Scalar::Other(VariantType::Vector2)),
+            Err(ConversionError::Unsupported {
+                from: VariantType::Vector2,
+                to: VariantType::Real,
+            })
+        );
+        assert_eq!(bool_from_scalar(Scalar::Nil), Ok(false));
+    }
+}
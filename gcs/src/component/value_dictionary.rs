@@ -0,0 +1,104 @@
+/// Dictionary of component values that keeps its keys in insertion order, like Godot's `Dictionary`.
+/// Keys are compared with `PartialEq`, so lookups are linear.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ValueDictionary<TValue: PartialEq> {
+    entries: Vec<(TValue, TValue)>,
+}
+
+impl<TValue: PartialEq> Default for ValueDictionary<TValue> {
+    fn default() -> Self {
+        ValueDictionary {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<TValue: PartialEq> ValueDictionary<TValue> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, key: &TValue) -> Option<&TValue> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn contains_key(&self, key: &TValue) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Replaces the value of an existing key in place, otherwise appends the entry.
+    pub fn insert(&mut self, key: TValue, value: TValue) -> Option<TValue> {
+        match self
+            .entries
+            .iter_mut()
+            .find(|(entry_key, _)| *entry_key == key)
+        {
+            Some((_, entry_value)) => Some(std::mem::replace(entry_value, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &TValue) -> Option<TValue> {
+        let index = self
+            .entries
+            .iter()
+            .position(|(entry_key, _)| entry_key == key)?;
+        Some(self.entries.remove(index).1)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &TValue> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&TValue, &TValue)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+}
+
+impl<TValue: PartialEq> FromIterator<(TValue, TValue)> for ValueDictionary<TValue> {
+    fn from_iter<T: IntoIterator<Item = (TValue, TValue)>>(iter: T) -> Self {
+        let mut dictionary = ValueDictionary::new();
+        for (key, value) in iter {
+            dictionary.insert(key, value);
+        }
+        dictionary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::component::value_dictionary::ValueDictionary;
+
+    #[test]
+    fn keys_keep_insertion_order() {
+        let mut dictionary = ValueDictionary::new();
+        dictionary.insert(3, 30);
+        dictionary.insert(1, 10);
+        dictionary.insert(2, 20);
+        assert_eq!(dictionary.insert(1, 11), Some(10));
+
+        assert_eq!(
+            dictionary.keys().copied().collect::<Vec<_>>(),
+            vec![3, 1, 2]
+        );
+        assert_eq!(dictionary.get(&1), Some(&11));
+
+        assert_eq!(dictionary.remove(&3), Some(30));
+        assert_eq!(dictionary.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(dictionary.len(), 2);
+    }
+}
@@ -1,7 +1,12 @@
 use std::hash::Hash;
 
+pub mod entity_id_generator;
+
 pub trait EntityId: Default + PartialEq + Eq + Hash + Copy + Clone {
     fn create() -> Self
+    where
+        Self: Sized;
+    fn from_u128(value: u128) -> Self
     where
         Self: Sized;
     fn as_string(&self) -> String;
@@ -0,0 +1,214 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::entity::EntityId;
+
+pub trait EntityIdGenerator<TEntityId: EntityId> {
+    fn generate(&mut self) -> TEntityId;
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum EntityIdGeneration {
+    /// Uses `EntityId::create`.
+    #[default]
+    Random,
+    /// Random version 4 UUIDs from a SplitMix64 generator, identical for identical seeds.
+    Seeded(u64),
+    /// 1, 2, 3, ...
+    Sequential,
+    /// Version 7 UUIDs, which sort by the time they were created in.
+    TimeOrdered,
+}
+
+impl EntityIdGeneration {
+    pub fn create_generator<TEntityId: EntityId>(&self) -> Box<dyn EntityIdGenerator<TEntityId>> {
+        match self {
+            EntityIdGeneration::Random => Box::new(RandomEntityIdGenerator),
+            EntityIdGeneration::Seeded(seed) => Box::new(SeededEntityIdGenerator::new(*seed)),
+            EntityIdGeneration::Sequential => Box::new(SequentialEntityIdGenerator::default()),
+            EntityIdGeneration::TimeOrdered => Box::new(TimeOrderedEntityIdGenerator::default()),
+        }
+    }
+}
+
+const VERSION_MASK: u128 = 0xf << 76;
+const VARIANT_MASK: u128 = 0x3 << 62;
+const VARIANT_RFC_4122: u128 = 0x2 << 62;
+
+fn as_uuid(value: u128, version: u128) -> u128 {
+    (value & !VERSION_MASK & !VARIANT_MASK) | (version << 76) | VARIANT_RFC_4122
+}
+
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+}
+
+pub struct RandomEntityIdGenerator;
+
+impl<TEntityId: EntityId> EntityIdGenerator<TEntityId> for RandomEntityIdGenerator {
+    fn generate(&mut self) -> TEntityId {
+        TEntityId::create()
+    }
+}
+
+pub struct SeededEntityIdGenerator {
+    random: SplitMix64,
+}
+
+impl SeededEntityIdGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            random: SplitMix64 { state: seed },
+        }
+    }
+
+    fn next_value(&mut self) -> u128 {
+        let value = ((self.random.next() as u128) << 64) | self.random.next() as u128;
+        as_uuid(value, 4)
+    }
+}
+
+impl<TEntityId: EntityId> EntityIdGenerator<TEntityId> for SeededEntityIdGenerator {
+    fn generate(&mut self) -> TEntityId {
+        TEntityId::from_u128(self.next_value())
+    }
+}
+
+pub struct SequentialEntityIdGenerator {
+    next: u128,
+}
+
+impl Default for SequentialEntityIdGenerator {
+    fn default() -> Self {
+        Self { next: 1 }
+    }
+}
+
+impl SequentialEntityIdGenerator {
+    fn next_value(&mut self) -> u128 {
+        let value = self.next;
+        self.next += 1;
+        value
+    }
+}
+
+impl<TEntityId: EntityId> EntityIdGenerator<TEntityId> for SequentialEntityIdGenerator {
+    fn generate(&mut self) -> TEntityId {
+        TEntityId::from_u128(self.next_value())
+    }
+}
+
+pub struct TimeOrderedEntityIdGenerator {
+    last_timestamp: u64,
+    counter: u64,
+    random: SplitMix64,
+}
+
+impl Default for TimeOrderedEntityIdGenerator {
+    fn default() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        Self {
+            last_timestamp: 0,
+            counter: 0,
+            random: SplitMix64 { state: seed },
+        }
+    }
+}
+
+impl TimeOrderedEntityIdGenerator {
+    fn next_value(&mut self) -> u128 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        // The counter keeps ids created within the same millisecond ordered.
+        if now > self.last_timestamp {
+            self.last_timestamp = now;
+            self.counter = 0;
+        } else {
+            self.counter += 1;
+            if self.counter > 0xfff {
+                self.last_timestamp += 1;
+                self.counter = 0;
+            }
+        }
+
+        let timestamp = (self.last_timestamp as u128 & 0xffff_ffff_ffff) << 80;
+        let counter = (self.counter as u128) << 64;
+        let random = self.random.next() as u128;
+        as_uuid(timestamp | counter | random, 7)
+    }
+}
+
+impl<TEntityId: EntityId> EntityIdGenerator<TEntityId> for TimeOrderedEntityIdGenerator {
+    fn generate(&mut self) -> TEntityId {
+        TEntityId::from_u128(self.next_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entity::entity_id_generator::{
+        SeededEntityIdGenerator, SequentialEntityIdGenerator, TimeOrderedEntityIdGenerator,
+        VARIANT_MASK, VARIANT_RFC_4122, VERSION_MASK,
+    };
+
+    #[test]
+    fn seeded_generator_repeats_the_same_ids_for_the_same_seed() {
+        let mut first = SeededEntityIdGenerator::new(42);
+        let mut second = SeededEntityIdGenerator::new(42);
+        let mut other = SeededEntityIdGenerator::new(43);
+
+        let ids: Vec<u128> = (0..10).map(|_| first.next_value()).collect();
+        let repeated: Vec<u128> = (0..10).map(|_| second.next_value()).collect();
+        let other_ids: Vec<u128> = (0..10).map(|_| other.next_value()).collect();
+
+        assert_eq!(ids, repeated);
+        assert_ne!(ids, other_ids);
+    }
+
+    #[test]
+    fn seeded_generator_creates_version_4_uuids() {
+        let mut generator = SeededEntityIdGenerator::new(0);
+        let id = generator.next_value();
+
+        assert_eq!(4 << 76, id & VERSION_MASK);
+        assert_eq!(VARIANT_RFC_4122, id & VARIANT_MASK);
+    }
+
+    #[test]
+    fn sequential_generator_counts_up_from_one() {
+        let mut generator = SequentialEntityIdGenerator::default();
+
+        assert_eq!(1, generator.next_value());
+        assert_eq!(2, generator.next_value());
+        assert_eq!(3, generator.next_value());
+    }
+
+    #[test]
+    fn time_ordered_generator_creates_ordered_version_7_uuids() {
+        let mut generator = TimeOrderedEntityIdGenerator::default();
+        let ids: Vec<u128> = (0..5000).map(|_| generator.next_value()).collect();
+
+        for id in &ids {
+            assert_eq!(7 << 76, id & VERSION_MASK);
+            assert_eq!(VARIANT_RFC_4122, id & VARIANT_MASK);
+        }
+        for pair in ids.windows(2) {
+            assert!(pair[0] < pair[1], "Ids should be ordered by creation");
+        }
+    }
+}
@@ -1,4 +1,7 @@
+pub mod backend;
 pub mod component;
 pub mod entity;
+pub mod math;
+pub mod replication;
 pub mod variant;
 pub mod world;
@@ -0,0 +1,605 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::slice::Iter;
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Vector3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Rect2 {
+    pub position: Vector2,
+    pub size: Vector2,
+}
+
+/// 2D transform stored as its x axis, y axis and origin, like Godot's `Transform2D`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Transform2D {
+    pub x: Vector2,
+    pub y: Vector2,
+    pub origin: Vector2,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Plane {
+    pub normal: Vector3,
+    pub d: f32,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Aabb {
+    pub position: Vector3,
+    pub size: Vector3,
+}
+
+/// 3x3 matrix stored as its x, y and z axes (columns), like the properties of Godot's `Basis`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Basis {
+    pub x: Vector3,
+    pub y: Vector3,
+    pub z: Vector3,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct Transform {
+    pub basis: Basis,
+    pub origin: Vector3,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Vector2 {
+    pub const ZERO: Vector2 = Vector2::new(0.0, 0.0);
+    pub const ONE: Vector2 = Vector2::new(1.0, 1.0);
+
+    pub const fn new(x: f32, y: f32) -> Self {
+        Vector2 { x, y }
+    }
+
+    pub fn dot(&self, other: Vector2) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn length(&self) -> f32 {
+        self.dot(*self).sqrt()
+    }
+}
+
+impl Vector3 {
+    pub const ZERO: Vector3 = Vector3::new(0.0, 0.0, 0.0);
+    pub const ONE: Vector3 = Vector3::new(1.0, 1.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Vector3 { x, y, z }
+    }
+
+    pub fn dot(&self, other: Vector3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: Vector3) -> Vector3 {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn length(&self) -> f32 {
+        self.dot(*self).sqrt()
+    }
+}
+
+impl Rect2 {
+    pub const fn new(position: Vector2, size: Vector2) -> Self {
+        Rect2 { position, size }
+    }
+
+    pub fn get_end(&self) -> Vector2 {
+        self.position + self.size
+    }
+
+    pub fn has_point(&self, point: Vector2) -> bool {
+        let end = self.get_end();
+        point.x >= self.position.x
+            && point.y >= self.position.y
+            && point.x < end.x
+            && point.y < end.y
+    }
+}
+
+impl Transform2D {
+    pub const IDENTITY: Transform2D = Transform2D::new(
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+        Vector2::ZERO,
+    );
+
+    pub const fn new(x: Vector2, y: Vector2, origin: Vector2) -> Self {
+        Transform2D { x, y, origin }
+    }
+
+    pub fn xform(&self, point: Vector2) -> Vector2 {
+        self.x * point.x + self.y * point.y + self.origin
+    }
+}
+
+impl Plane {
+    pub const fn new(normal: Vector3, d: f32) -> Self {
+        Plane { normal, d }
+    }
+
+    pub fn distance_to(&self, point: Vector3) -> f32 {
+        self.normal.dot(point) - self.d
+    }
+}
+
+impl Quat {
+    pub const IDENTITY: Quat = Quat::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Quat { x, y, z, w }
+    }
+}
+
+impl Aabb {
+    pub const fn new(position: Vector3, size: Vector3) -> Self {
+        Aabb { position, size }
+    }
+
+    pub fn get_end(&self) -> Vector3 {
+        self.position + self.size
+    }
+}
+
+impl Basis {
+    pub const IDENTITY: Basis = Basis::new(
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    );
+
+    pub const fn new(x: Vector3, y: Vector3, z: Vector3) -> Self {
+        Basis { x, y, z }
+    }
+
+    pub fn xform(&self, vector: Vector3) -> Vector3 {
+        self.x * vector.x + self.y * vector.y + self.z * vector.z
+    }
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform::new(Basis::IDENTITY, Vector3::ZERO);
+
+    pub const fn new(basis: Basis, origin: Vector3) -> Self {
+        Transform { basis, origin }
+    }
+
+    pub fn xform(&self, point: Vector3) -> Vector3 {
+        self.basis.xform(point) + self.origin
+    }
+}
+
+impl Color {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color { r, g, b, a }
+    }
+
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Color::new(r, g, b, 1.0)
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Transform2D::IDENTITY
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Quat::IDENTITY
+    }
+}
+
+impl Default for Basis {
+    fn default() -> Self {
+        Basis::IDENTITY
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::rgb(0.0, 0.0, 0.0)
+    }
+}
+
+impl Add for Vector2 {
+    type Output = Vector2;
+
+    fn add(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector2 {
+    type Output = Vector2;
+
+    fn sub(self, other: Vector2) -> Vector2 {
+        Vector2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f32> for Vector2 {
+    type Output = Vector2;
+
+    fn mul(self, scale: f32) -> Vector2 {
+        Vector2::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Vector2 {
+    type Output = Vector2;
+
+    fn neg(self) -> Vector2 {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<f32> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, scale: f32) -> Vector3 {
+        Vector3::new(self.x * scale, self.y * scale, self.z * scale)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Flattens a math value into its reals, in declaration order, so it can be written to a replay.
+pub(crate) trait Reals: Sized {
+    const LEN: usize;
+
+    fn write_reals(&self, reals: &mut Vec<f32>);
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self;
+
+    fn to_reals(&self) -> Vec<f32> {
+        let mut reals = Vec::with_capacity(Self::LEN);
+        self.write_reals(&mut reals);
+        reals
+    }
+
+    fn from_reals(reals: &[f32]) -> Option<Self> {
+        if reals.len() == Self::LEN {
+            Some(Self::read_reals(&mut reals.iter()))
+        } else {
+            None
+        }
+    }
+}
+
+fn next_real(reals: &mut Iter<f32>) -> f32 {
+    reals.next().copied().unwrap_or_default()
+}
+
+impl Reals for Vector2 {
+    const LEN: usize = 2;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        reals.extend_from_slice(&[self.x, self.y]);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Vector2::new(next_real(reals), next_real(reals))
+    }
+}
+
+impl Reals for Vector3 {
+    const LEN: usize = 3;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        reals.extend_from_slice(&[self.x, self.y, self.z]);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Vector3::new(next_real(reals), next_real(reals), next_real(reals))
+    }
+}
+
+impl Reals for Rect2 {
+    const LEN: usize = Vector2::LEN + Vector2::LEN;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        self.position.write_reals(reals);
+        self.size.write_reals(reals);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Rect2::new(Vector2::read_reals(reals), Vector2::read_reals(reals))
+    }
+}
+
+impl Reals for Transform2D {
+    const LEN: usize = Vector2::LEN + Vector2::LEN + Vector2::LEN;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        self.x.write_reals(reals);
+        self.y.write_reals(reals);
+        self.origin.write_reals(reals);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Transform2D::new(
+            Vector2::read_reals(reals),
+            Vector2::read_reals(reals),
+            Vector2::read_reals(reals),
+        )
+    }
+}
+
+impl Reals for Plane {
+    const LEN: usize = Vector3::LEN + 1;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        self.normal.write_reals(reals);
+        reals.push(self.d);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Plane::new(Vector3::read_reals(reals), next_real(reals))
+    }
+}
+
+impl Reals for Quat {
+    const LEN: usize = 4;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        reals.extend_from_slice(&[self.x, self.y, self.z, self.w]);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Quat::new(
+            next_real(reals),
+            next_real(reals),
+            next_real(reals),
+            next_real(reals),
+        )
+    }
+}
+
+impl Reals for Aabb {
+    const LEN: usize = Vector3::LEN + Vector3::LEN;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        self.position.write_reals(reals);
+        self.size.write_reals(reals);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Aabb::new(Vector3::read_reals(reals), Vector3::read_reals(reals))
+    }
+}
+
+impl Reals for Basis {
+    const LEN: usize = Vector3::LEN + Vector3::LEN + Vector3::LEN;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        self.x.write_reals(reals);
+        self.y.write_reals(reals);
+        self.z.write_reals(reals);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Basis::new(
+            Vector3::read_reals(reals),
+            Vector3::read_reals(reals),
+            Vector3::read_reals(reals),
+        )
+    }
+}
+
+impl Reals for Transform {
+    const LEN: usize = Basis::LEN + Vector3::LEN;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        self.basis.write_reals(reals);
+        self.origin.write_reals(reals);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Transform::new(Basis::read_reals(reals), Vector3::read_reals(reals))
+    }
+}
+
+impl Reals for Color {
+    const LEN: usize = 4;
+
+    fn write_reals(&self, reals: &mut Vec<f32>) {
+        reals.extend_from_slice(&[self.r, self.g, self.b, self.a]);
+    }
+
+    fn read_reals(reals: &mut Iter<f32>) -> Self {
+        Color::new(
+            next_real(reals),
+            next_real(reals),
+            next_real(reals),
+            next_real(reals),
+        )
+    }
+}
+
+impl Display for Vector2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for Vector3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl Display for Rect2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.position, self.size)
+    }
+}
+
+impl Display for Transform2D {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}, {}", self.x, self.y, self.origin)
+    }
+}
+
+impl Display for Plane {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.normal, self.d)
+    }
+}
+
+impl Display for Quat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}, {}, {}", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl Display for Aabb {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.position, self.size)
+    }
+}
+
+impl Display for Basis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}, {}", self.x, self.y, self.z)
+    }
+}
+
+impl Display for Transform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.basis, self.origin)
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{},{}", self.r, self.g, self.b, self.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::{Basis, Color, Quat, Reals, Rect2, Transform, Transform2D, Vector2, Vector3};
+
+    #[test]
+    fn defaults_match_godot() {
+        assert_eq!(Transform2D::default(), Transform2D::IDENTITY);
+        assert_eq!(Quat::default(), Quat::new(0.0, 0.0, 0.0, 1.0));
+        assert_eq!(Basis::default(), Basis::IDENTITY);
+        assert_eq!(Transform::default().basis, Basis::IDENTITY);
+        assert_eq!(Color::default(), Color::new(0.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn vector_operations() {
+        let a = Vector2::new(1.0, 2.0);
+        let b = Vector2::new(3.0, 4.0);
+        assert_eq!(a + b, Vector2::new(4.0, 6.0));
+        assert_eq!(b - a, Vector2::new(2.0, 2.0));
+        assert_eq!(a * 2.0, Vector2::new(2.0, 4.0));
+        assert_eq!(-a, Vector2::new(-1.0, -2.0));
+        assert_eq!(b.length(), 5.0);
+
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        assert_eq!(x.cross(y), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(x.dot(y), 0.0);
+    }
+
+    #[test]
+    fn transforms_move_points() {
+        let transform = Transform2D::new(
+            Vector2::new(0.0, 1.0),
+            Vector2::new(-1.0, 0.0),
+            Vector2::new(10.0, 0.0),
+        );
+        assert_eq!(
+            transform.xform(Vector2::new(1.0, 0.0)),
+            Vector2::new(10.0, 1.0)
+        );
+
+        let transform = Transform::new(Basis::IDENTITY, Vector3::new(0.0, 0.0, -5.0));
+        assert_eq!(transform.xform(Vector3::ONE), Vector3::new(1.0, 1.0, -4.0));
+    }
+
+    #[test]
+    fn rect_has_point() {
+        let rect = Rect2::new(Vector2::ZERO, Vector2::new(2.0, 2.0));
+        assert!(rect.has_point(Vector2::new(1.0, 1.0)));
+        assert!(!rect.has_point(Vector2::new(2.0, 1.0)));
+    }
+
+    #[test]
+    fn reals_round_trip() {
+        let transform = Transform::new(
+            Basis::new(
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(4.0, 5.0, 6.0),
+                Vector3::new(7.0, 8.0, 9.0),
+            ),
+            Vector3::new(10.0, 11.0, 12.0),
+        );
+        let reals = transform.to_reals();
+        assert_eq!(reals.len(), Transform::LEN);
+        assert_eq!(reals[9..], [10.0, 11.0, 12.0]);
+        assert_eq!(Transform::from_reals(&reals), Some(transform));
+        assert_eq!(Transform::from_reals(&reals[1..]), None);
+    }
+}
@@ -0,0 +1,36 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(PartialEq, Debug)]
+pub enum AddObserverError {
+    AlreadyRegistered,
+}
+
+impl Display for AddObserverError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyRegistered => {
+                write!(f, "Observer is already registered")
+            }
+        }
+    }
+}
+
+impl Error for AddObserverError {}
+
+#[derive(PartialEq, Debug)]
+pub enum ObserverError {
+    ObserverNotFound,
+}
+
+impl Display for ObserverError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ObserverNotFound => {
+                write!(f, "Observer was not found")
+            }
+        }
+    }
+}
+
+impl Error for ObserverError {}
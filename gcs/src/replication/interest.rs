@@ -0,0 +1,633 @@
+use std::collections::HashSet;
+
+use crate::component::component_data::ComponentData;
+use crate::component::component_definition::ComponentDefinition;
+use crate::component::component_info::ComponentInfo;
+use crate::component::component_value::ComponentValue;
+use crate::entity::EntityId;
+use crate::replication::errors::{AddObserverError, ObserverError};
+use crate::world::ecs_world::ECSWorld;
+
+pub trait InterestFilter<
+    TComponentDefinition: ComponentDefinition,
+    TComponentData: ComponentData,
+    TComponentInfo: ComponentInfo,
+>
+{
+    fn is_relevant(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> bool;
+
+    /// Keeps the relevant entities of `entities`. Filters that look up the same data for every
+    /// entity can override it to look the data up once.
+    fn retain_relevant(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entities: &mut Vec<TComponentData::EntityIdType>,
+    ) {
+        entities.retain(|entity| self.is_relevant(world, entity));
+    }
+}
+
+type Filters<TComponentDefinition, TComponentData, TComponentInfo> =
+    Vec<Box<dyn InterestFilter<TComponentDefinition, TComponentData, TComponentInfo>>>;
+
+/// Accepts entities whose position is within `radius` of the position of `focus`.
+/// The position is read from the `Real` values of `fields` in `component`, one field per axis.
+pub struct DistanceFilter<TEntityId: EntityId> {
+    pub component: String,
+    pub fields: Vec<String>,
+    pub focus: TEntityId,
+    pub radius: f64,
+}
+
+impl<TEntityId: EntityId> DistanceFilter<TEntityId> {
+    fn get_position<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData<EntityIdType = TEntityId>,
+        TComponentInfo: ComponentInfo,
+    >(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entity_id: &TEntityId,
+    ) -> Option<Vec<f64>> {
+        let data = world
+            .get_component_of_entity(entity_id, self.component.clone())
+            .ok()?;
+        Some(
+            self.fields
+                .iter()
+                .map(|field| data.get_field(field).get_real())
+                .collect(),
+        )
+    }
+
+    fn is_within<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData<EntityIdType = TEntityId>,
+        TComponentInfo: ComponentInfo,
+    >(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        focus: &[f64],
+        entity_id: &TEntityId,
+    ) -> bool {
+        let position = match self.get_position(world, entity_id) {
+            None => return false,
+            Some(position) => position,
+        };
+
+        let distance_squared: f64 = focus
+            .iter()
+            .zip(position.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum();
+        distance_squared <= self.radius * self.radius
+    }
+}
+
+impl<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > InterestFilter<TComponentDefinition, TComponentData, TComponentInfo>
+    for DistanceFilter<TComponentData::EntityIdType>
+{
+    fn is_relevant(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> bool {
+        match self.get_position(world, &self.focus) {
+            None => false,
+            Some(focus) => self.is_within(world, &focus, entity_id),
+        }
+    }
+
+    fn retain_relevant(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entities: &mut Vec<TComponentData::EntityIdType>,
+    ) {
+        match self.get_position(world, &self.focus) {
+            None => entities.clear(),
+            Some(focus) => entities.retain(|entity| self.is_within(world, &focus, entity)),
+        }
+    }
+}
+
+/// Accepts entities whose `field` in `component` is equal to `team`.
+pub struct TeamFilter<TComponentValue: ComponentValue> {
+    pub component: String,
+    pub field: String,
+    pub team: TComponentValue,
+}
+
+impl<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > InterestFilter<TComponentDefinition, TComponentData, TComponentInfo>
+    for TeamFilter<TComponentData::ComponentValueType>
+{
+    fn is_relevant(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> bool {
+        match world.get_component_of_entity(entity_id, self.component.clone()) {
+            Ok(data) => *data.get_field(&self.field) == self.team,
+            Err(_) => false,
+        }
+    }
+}
+
+/// Accepts the entities in `entities`.
+pub struct AllowListFilter<TEntityId: EntityId> {
+    pub entities: HashSet<TEntityId>,
+}
+
+impl<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > InterestFilter<TComponentDefinition, TComponentData, TComponentInfo>
+    for AllowListFilter<TComponentData::EntityIdType>
+{
+    fn is_relevant(
+        &self,
+        _world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> bool {
+        self.entities.contains(entity_id)
+    }
+}
+
+/// Accepts entities that are accepted by at least one of `filters`.
+pub struct AnyOfFilter<
+    TComponentDefinition: ComponentDefinition,
+    TComponentData: ComponentData,
+    TComponentInfo: ComponentInfo,
+> {
+    pub filters: Filters<TComponentDefinition, TComponentData, TComponentInfo>,
+}
+
+impl<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > InterestFilter<TComponentDefinition, TComponentData, TComponentInfo>
+    for AnyOfFilter<TComponentDefinition, TComponentData, TComponentInfo>
+{
+    fn is_relevant(
+        &self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> bool {
+        self.filters
+            .iter()
+            .any(|filter| filter.is_relevant(world, entity_id))
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum InterestMessage<TObserverId, TEntityId> {
+    Spawn {
+        observer: TObserverId,
+        entity: TEntityId,
+    },
+    Despawn {
+        observer: TObserverId,
+        entity: TEntityId,
+    },
+}
+
+struct Observer<
+    TObserverId,
+    TComponentDefinition: ComponentDefinition,
+    TComponentData: ComponentData,
+    TComponentInfo: ComponentInfo,
+> {
+    id: TObserverId,
+    filters: Filters<TComponentDefinition, TComponentData, TComponentInfo>,
+    interest_set: Vec<TComponentData::EntityIdType>,
+}
+
+/// Tracks which entities are relevant for each observer.
+/// An entity is relevant for an observer when all of the observer's filters accept it.
+pub struct InterestManager<
+    TObserverId: Eq + Clone,
+    TComponentDefinition: ComponentDefinition,
+    TComponentData: ComponentData,
+    TComponentInfo: ComponentInfo,
+> {
+    observers: Vec<Observer<TObserverId, TComponentDefinition, TComponentData, TComponentInfo>>,
+}
+
+impl<
+        TObserverId: Eq + Clone,
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > Default
+    for InterestManager<TObserverId, TComponentDefinition, TComponentData, TComponentInfo>
+{
+    fn default() -> Self {
+        Self {
+            observers: Vec::new(),
+        }
+    }
+}
+
+impl<
+        TObserverId: Eq + Clone,
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > InterestManager<TObserverId, TComponentDefinition, TComponentData, TComponentInfo>
+{
+    fn get_observer_mut(
+        &mut self,
+        observer: &TObserverId,
+    ) -> Result<
+        &mut Observer<TObserverId, TComponentDefinition, TComponentData, TComponentInfo>,
+        ObserverError,
+    > {
+        self.observers
+            .iter_mut()
+            .find(|o| o.id == *observer)
+            .ok_or(ObserverError::ObserverNotFound)
+    }
+
+    pub fn add_observer(
+        &mut self,
+        observer: TObserverId,
+        filters: Filters<TComponentDefinition, TComponentData, TComponentInfo>,
+    ) -> Result<(), AddObserverError> {
+        if self.observers.iter().any(|o| o.id == observer) {
+            Err(AddObserverError::AlreadyRegistered)
+        } else {
+            self.observers.push(Observer {
+                id: observer,
+                filters,
+                interest_set: Vec::new(),
+            });
+            Ok(())
+        }
+    }
+
+    pub fn remove_observer(
+        &mut self,
+        observer: &TObserverId,
+    ) -> Result<Vec<InterestMessage<TObserverId, TComponentData::EntityIdType>>, ObserverError>
+    {
+        let index = self
+            .observers
+            .iter()
+            .position(|o| o.id == *observer)
+            .ok_or(ObserverError::ObserverNotFound)?;
+        let removed = self.observers.remove(index);
+        Ok(removed
+            .interest_set
+            .into_iter()
+            .map(|entity| InterestMessage::Despawn {
+                observer: removed.id.clone(),
+                entity,
+            })
+            .collect())
+    }
+
+    pub fn set_filters(
+        &mut self,
+        observer: &TObserverId,
+        filters: Filters<TComponentDefinition, TComponentData, TComponentInfo>,
+    ) -> Result<(), ObserverError> {
+        self.get_observer_mut(observer)?.filters = filters;
+        Ok(())
+    }
+
+    pub fn get_interest_set(
+        &self,
+        observer: &TObserverId,
+    ) -> Result<&[TComponentData::EntityIdType], ObserverError> {
+        self.observers
+            .iter()
+            .find(|o| o.id == *observer)
+            .map(|o| o.interest_set.as_slice())
+            .ok_or(ObserverError::ObserverNotFound)
+    }
+
+    /// Re-evaluates the interest set of every observer against the current state of `world`.
+    /// Returns a `Despawn` message for each entity that left an interest set and a `Spawn`
+    /// message for each entity that entered one.
+    pub fn update(
+        &mut self,
+        world: &ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>,
+    ) -> Vec<InterestMessage<TObserverId, TComponentData::EntityIdType>> {
+        let mut messages = Vec::new();
+
+        for observer in &mut self.observers {
+            let mut interest_set: Vec<TComponentData::EntityIdType> =
+                world.get_entities().copied().collect();
+            for filter in &observer.filters {
+                filter.retain_relevant(world, &mut interest_set);
+            }
+
+            let current: HashSet<_> = interest_set.iter().collect();
+            let previous: HashSet<_> = observer.interest_set.iter().collect();
+
+            for entity in observer.interest_set.iter() {
+                if !current.contains(entity) {
+                    messages.push(InterestMessage::Despawn {
+                        observer: observer.id.clone(),
+                        entity: *entity,
+                    });
+                }
+            }
+            for entity in interest_set.iter() {
+                if !previous.contains(entity) {
+                    messages.push(InterestMessage::Spawn {
+                        observer: observer.id.clone(),
+                        entity: *entity,
+                    });
+                }
+            }
+
+            observer.interest_set = interest_set;
+        }
+
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::component::component_data::ComponentData;
+    use crate::component::component_definition::ComponentDefinition;
+    use crate::replication::errors::{AddObserverError, ObserverError};
+    use crate::replication::interest::{
+        AllowListFilter, AnyOfFilter, DistanceFilter, InterestManager, InterestMessage, TeamFilter,
+    };
+    use crate::variant::VariantType;
+    use crate::world::ecs_world::tests::{
+        TestComponentData, TestComponentDefinition, TestComponentFieldDefinition,
+        TestComponentInfo, TestComponentValue, TestEntityId,
+    };
+    use crate::world::ecs_world::ECSWorld;
+
+    type TestWorld = ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo>;
+    type TestInterestManager =
+        InterestManager<u32, TestComponentDefinition, TestComponentData, TestComponentInfo>;
+
+    fn create_world() -> TestWorld {
+        let mut world = TestWorld::default();
+
+        let mut position = TestComponentDefinition::default();
+        position.add_field(TestComponentFieldDefinition {
+            name: "x".to_string(),
+            field_type: VariantType::Real,
+        });
+        position.add_field(TestComponentFieldDefinition {
+            name: "y".to_string(),
+            field_type: VariantType::Real,
+        });
+        world
+            .register_component("Position".to_string(), position)
+            .unwrap();
+
+        let mut team = TestComponentDefinition::default();
+        team.add_field(TestComponentFieldDefinition {
+            name: "team".to_string(),
+            field_type: VariantType::Int,
+        });
+        world.register_component("Team".to_string(), team).unwrap();
+
+        world
+    }
+
+    fn spawn(world: &mut TestWorld, id: u8, x: f64, y: f64, team: i64) -> TestEntityId {
+        let entity_id = TestEntityId { id };
+        world.register_entity(&entity_id).unwrap();
+        set_position(world, entity_id, x, y);
+
+        let mut data = TestComponentData::new(entity_id);
+        data.set_field("team", &TestComponentValue::Int(team));
+        world
+            .set_component_data(&entity_id, "Team".to_string(), &data)
+            .unwrap();
+        entity_id
+    }
+
+    fn set_position(world: &mut TestWorld, entity_id: TestEntityId, x: f64, y: f64) {
+        let mut data = TestComponentData::new(entity_id);
+        data.set_field("x", &TestComponentValue::Real(x));
+        data.set_field("y", &TestComponentValue::Real(y));
+        world
+            .set_component_data(&entity_id, "Position".to_string(), &data)
+            .unwrap();
+    }
+
+    fn distance_filter(focus: TestEntityId, radius: f64) -> DistanceFilter<TestEntityId> {
+        DistanceFilter {
+            component: "Position".to_string(),
+            fields: vec!["x".to_string(), "y".to_string()],
+            focus,
+            radius,
+        }
+    }
+
+    #[test]
+    fn update_emits_spawn_for_entities_within_distance() {
+        let mut world = create_world();
+        let player = spawn(&mut world, 0, 0.0, 0.0, 0);
+        let near = spawn(&mut world, 1, 3.0, 4.0, 0);
+        spawn(&mut world, 2, 30.0, 40.0, 0);
+
+        let mut manager = TestInterestManager::default();
+        manager
+            .add_observer(1, vec![Box::new(distance_filter(player, 5.0))])
+            .unwrap();
+
+        let messages = manager.update(&world);
+        assert_eq!(
+            vec![
+                InterestMessage::Spawn {
+                    observer: 1,
+                    entity: player
+                },
+                InterestMessage::Spawn {
+                    observer: 1,
+                    entity: near
+                },
+            ],
+            messages
+        );
+        assert_eq!(vec![player, near], manager.get_interest_set(&1).unwrap());
+    }
+
+    #[test]
+    fn update_emits_despawn_when_an_entity_leaves_the_interest_set() {
+        let mut world = create_world();
+        let player = spawn(&mut world, 0, 0.0, 0.0, 0);
+        let enemy = spawn(&mut world, 1, 1.0, 1.0, 1);
+
+        let mut manager = TestInterestManager::default();
+        manager
+            .add_observer(1, vec![Box::new(distance_filter(player, 5.0))])
+            .unwrap();
+        manager.update(&world);
+
+        set_position(&mut world, enemy, 10.0, 10.0);
+        let messages = manager.update(&world);
+        assert_eq!(
+            vec![InterestMessage::Despawn {
+                observer: 1,
+                entity: enemy
+            }],
+            messages
+        );
+
+        assert!(
+            manager.update(&world).is_empty(),
+            "Unchanged interest sets should not emit messages"
+        );
+    }
+
+    #[test]
+    fn distance_filter_accepts_no_entity_if_the_focus_has_no_position() {
+        let mut world = create_world();
+        let player = TestEntityId { id: 0 };
+        world.register_entity(&player).unwrap();
+        spawn(&mut world, 1, 0.0, 0.0, 0);
+
+        let mut manager = TestInterestManager::default();
+        manager
+            .add_observer(1, vec![Box::new(distance_filter(player, 5.0))])
+            .unwrap();
+
+        assert!(manager.update(&world).is_empty());
+        assert!(manager.get_interest_set(&1).unwrap().is_empty());
+    }
+
+    #[test]
+    fn all_filters_of_an_observer_have_to_accept_an_entity() {
+        let mut world = create_world();
+        let player = spawn(&mut world, 0, 0.0, 0.0, 0);
+        let ally = spawn(&mut world, 1, 1.0, 0.0, 0);
+        spawn(&mut world, 2, 1.0, 1.0, 1);
+        spawn(&mut world, 3, 50.0, 0.0, 0);
+
+        let mut manager = TestInterestManager::default();
+        manager
+            .add_observer(
+                1,
+                vec![
+                    Box::new(distance_filter(player, 5.0)),
+                    Box::new(TeamFilter {
+                        component: "Team".to_string(),
+                        field: "team".to_string(),
+                        team: TestComponentValue::Int(0),
+                    }),
+                ],
+            )
+            .unwrap();
+        manager.update(&world);
+
+        assert_eq!(vec![player, ally], manager.get_interest_set(&1).unwrap());
+    }
+
+    #[test]
+    fn any_of_filter_accepts_entities_accepted_by_one_of_its_filters() {
+        let mut world = create_world();
+        let player = spawn(&mut world, 0, 0.0, 0.0, 0);
+        let far_away = spawn(&mut world, 1, 50.0, 0.0, 1);
+        spawn(&mut world, 2, 60.0, 0.0, 1);
+
+        let mut manager = TestInterestManager::default();
+        manager
+            .add_observer(
+                1,
+                vec![Box::new(AnyOfFilter {
+                    filters: vec![
+                        Box::new(distance_filter(player, 5.0)),
+                        Box::new(AllowListFilter {
+                            entities: HashSet::from([far_away]),
+                        }),
+                    ],
+                })],
+            )
+            .unwrap();
+        manager.update(&world);
+
+        assert_eq!(
+            vec![player, far_away],
+            manager.get_interest_set(&1).unwrap()
+        );
+    }
+
+    #[test]
+    fn interest_sets_are_tracked_per_observer() {
+        let mut world = create_world();
+        let first = spawn(&mut world, 0, 0.0, 0.0, 0);
+        let second = spawn(&mut world, 1, 0.0, 0.0, 1);
+
+        let mut manager = TestInterestManager::default();
+        manager
+            .add_observer(
+                1,
+                vec![Box::new(AllowListFilter {
+                    entities: HashSet::from([first]),
+                })],
+            )
+            .unwrap();
+        manager
+            .add_observer(
+                2,
+                vec![Box::new(AllowListFilter {
+                    entities: HashSet::from([second]),
+                })],
+            )
+            .unwrap();
+        manager.update(&world);
+
+        assert_eq!(vec![first], manager.get_interest_set(&1).unwrap());
+        assert_eq!(vec![second], manager.get_interest_set(&2).unwrap());
+    }
+
+    #[test]
+    fn remove_observer_despawns_its_interest_set() {
+        let mut world = create_world();
+        let entity = spawn(&mut world, 0, 0.0, 0.0, 0);
+
+        let mut manager = TestInterestManager::default();
+        manager.add_observer(1, Vec::new()).unwrap();
+        manager.update(&world);
+
+        assert_eq!(
+            Ok(vec![InterestMessage::Despawn {
+                observer: 1,
+                entity
+            }]),
+            manager.remove_observer(&1)
+        );
+        assert_eq!(
+            Err(ObserverError::ObserverNotFound),
+            manager.get_interest_set(&1)
+        );
+    }
+
+    #[test]
+    fn add_observer_does_not_allow_adding_an_observer_twice() {
+        let mut manager = TestInterestManager::default();
+        manager.add_observer(1, Vec::new()).unwrap();
+
+        assert_eq!(
+            Err(AddObserverError::AlreadyRegistered),
+            manager.add_observer(1, Vec::new())
+        );
+    }
+}
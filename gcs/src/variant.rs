@@ -1,5 +1,6 @@
-#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug, Default)]
 pub enum VariantType {
+    #[default]
     Nil,
     // atomic types
     Bool,
@@ -36,10 +37,45 @@ pub enum VariantType {
     PoolColorArray,
 
     VariantMax,
+
+    // gcs types, stored as their id in Godot
+    Entity,
 }
 
-impl Default for VariantType {
-    fn default() -> Self {
-        VariantType::Nil
+impl TryFrom<u32> for VariantType {
+    type Error = String;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(VariantType::Nil),
+            1 => Ok(VariantType::Bool),
+            2 => Ok(VariantType::Int),
+            3 => Ok(VariantType::Real),
+            4 => Ok(VariantType::String),
+            5 => Ok(VariantType::Vector2),
+            6 => Ok(VariantType::Rect2),
+            7 => Ok(VariantType::Vector3),
+            8 => Ok(VariantType::Transform2D),
+            9 => Ok(VariantType::Plan),
+            10 => Ok(VariantType::Quat),
+            11 => Ok(VariantType::Aaab),
+            12 => Ok(VariantType::Basis),
+            13 => Ok(VariantType::Transform),
+            14 => Ok(VariantType::Color),
+            15 => Ok(VariantType::NodePath),
+            16 => Ok(VariantType::_RID),
+            17 => Ok(VariantType::Object),
+            18 => Ok(VariantType::Dictionary),
+            19 => Ok(VariantType::Array),
+            20 => Ok(VariantType::PoolByteArray),
+            21 => Ok(VariantType::PoolIntArray),
+            22 => Ok(VariantType::PoolRealArray),
+            23 => Ok(VariantType::PoolStringArray),
+            24 => Ok(VariantType::PoolVector2Array),
+            25 => Ok(VariantType::PoolVector3Array),
+            26 => Ok(VariantType::PoolColorArray),
+            28 => Ok(VariantType::Entity),
+            _ => Err(format!("{} is not a valid variant type", value)),
+        }
     }
 }
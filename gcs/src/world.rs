@@ -1,2 +1,10 @@
+pub mod component_columns;
+pub mod component_key;
+pub(crate) mod component_storage;
 pub mod ecs_world;
+pub(crate) mod entity_list;
+pub(crate) mod entity_pool;
+pub mod entity_reference;
 pub mod errors;
+pub(crate) mod journal;
+pub mod replay;
@@ -0,0 +1,309 @@
+use crate::component::component_data::ComponentData;
+use crate::component::component_value::ComponentValue;
+use crate::component::field_layout::FieldLayout;
+use crate::variant::VariantType;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// One field of every entity of a component, typed by the `VariantType` of the field.
+/// Fields that are not Bool, Int or Real are stored as values.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Column<TComponentValue: ComponentValue> {
+    Bool(Vec<bool>),
+    Int(Vec<i64>),
+    Real(Vec<f64>),
+    Value(Vec<TComponentValue>),
+}
+
+impl<TComponentValue: ComponentValue> Column<TComponentValue> {
+    fn for_type(field_type: VariantType) -> Self {
+        match field_type {
+            VariantType::Bool => Column::Bool(Vec::new()),
+            VariantType::Int => Column::Int(Vec::new()),
+            VariantType::Real => Column::Real(Vec::new()),
+            _ => Column::Value(Vec::new()),
+        }
+    }
+
+    fn push(&mut self, value: &TComponentValue) {
+        match self {
+            Column::Bool(column) => column.push(value.get_bool()),
+            Column::Int(column) => column.push(value.get_int()),
+            Column::Real(column) => column.push(value.get_real()),
+            Column::Value(column) => column.push(value.clone()),
+        }
+    }
+
+    fn set(&mut self, row: usize, value: &TComponentValue) {
+        match self {
+            Column::Bool(column) => column[row] = value.get_bool(),
+            Column::Int(column) => column[row] = value.get_int(),
+            Column::Real(column) => column[row] = value.get_real(),
+            Column::Value(column) => column[row] = value.clone(),
+        }
+    }
+
+    fn swap_remove(&mut self, row: usize) {
+        match self {
+            Column::Bool(column) => {
+                column.swap_remove(row);
+            }
+            Column::Int(column) => {
+                column.swap_remove(row);
+            }
+            Column::Real(column) => {
+                column.swap_remove(row);
+            }
+            Column::Value(column) => {
+                column.swap_remove(row);
+            }
+        }
+    }
+
+    /// Value of `row` if it differs from `value` as seen through the type of the column.
+    /// Value columns can not be written through slices and never differ.
+    fn changed_value(&self, row: usize, value: &TComponentValue) -> Option<TComponentValue> {
+        let mut changed = TComponentValue::default();
+        match self {
+            Column::Bool(column) if column[row] != value.get_bool() => {
+                changed.set_bool(column[row])
+            }
+            Column::Int(column) if column[row] != value.get_int() => changed.set_int(column[row]),
+            Column::Real(column) if column[row].to_bits() != value.get_real().to_bits() => {
+                changed.set_real(column[row])
+            }
+            _ => return None,
+        }
+        Some(changed)
+    }
+}
+
+/// Struct-of-arrays copy of the data of a component, kept in sync with the data of its entities
+/// by `ECSWorld` for components that have columns enabled. Row `n` of every column belongs to
+/// entity `n` of `get_entities`, column `n` to field `n` of the layout of the component.
+/// Rows of disabled entities or components are kept, `get_enabled` tells them apart.
+#[derive(Clone, Debug)]
+pub struct ComponentColumns<TComponentData: ComponentData> {
+    layout: Rc<FieldLayout>,
+    entities: Vec<TComponentData::EntityIdType>,
+    rows: HashMap<TComponentData::EntityIdType, usize>,
+    enabled: Vec<bool>,
+    columns: Vec<Column<TComponentData::ComponentValueType>>,
+}
+
+impl<TComponentData: ComponentData> ComponentColumns<TComponentData> {
+    /// `field_types` holds the type of every field of `layout`, by index.
+    pub(crate) fn new(layout: Rc<FieldLayout>, field_types: &[VariantType]) -> Self {
+        Self {
+            layout,
+            entities: Vec::new(),
+            rows: HashMap::new(),
+            enabled: Vec::new(),
+            columns: field_types.iter().map(|t| Column::for_type(*t)).collect(),
+        }
+    }
+
+    pub fn get_layout(&self) -> &FieldLayout {
+        &self.layout
+    }
+
+    pub fn get_entities(&self) -> &[TComponentData::EntityIdType] {
+        &self.entities
+    }
+
+    /// Whether the entity and the component of every row are enabled.
+    pub fn get_enabled(&self) -> &[bool] {
+        &self.enabled
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn get_column(&self, field: &str) -> Option<&Column<TComponentData::ComponentValueType>> {
+        self.columns.get(self.layout.get_index(field)?)
+    }
+
+    pub fn get_bools(&self, field: &str) -> Option<&[bool]> {
+        match self.get_column(field)? {
+            Column::Bool(column) => Some(column),
+            _ => None,
+        }
+    }
+
+    pub fn get_ints(&self, field: &str) -> Option<&[i64]> {
+        match self.get_column(field)? {
+            Column::Int(column) => Some(column),
+            _ => None,
+        }
+    }
+
+    pub fn get_reals(&self, field: &str) -> Option<&[f64]> {
+        match self.get_column(field)? {
+            Column::Real(column) => Some(column),
+            _ => None,
+        }
+    }
+
+    pub fn get_bools_mut(&mut self, field: &str) -> Option<&mut [bool]> {
+        match self.get_column_mut(field)? {
+            Column::Bool(column) => Some(column),
+            _ => None,
+        }
+    }
+
+    pub fn get_ints_mut(&mut self, field: &str) -> Option<&mut [i64]> {
+        match self.get_column_mut(field)? {
+            Column::Int(column) => Some(column),
+            _ => None,
+        }
+    }
+
+    pub fn get_reals_mut(&mut self, field: &str) -> Option<&mut [f64]> {
+        match self.get_column_mut(field)? {
+            Column::Real(column) => Some(column),
+            _ => None,
+        }
+    }
+
+    fn get_column_mut(
+        &mut self,
+        field: &str,
+    ) -> Option<&mut Column<TComponentData::ComponentValueType>> {
+        self.columns.get_mut(self.layout.get_index(field)?)
+    }
+
+    /// Adds the row of the entity of `data` or overwrites it if it exists.
+    pub(crate) fn set(&mut self, data: &TComponentData, enabled: bool) {
+        let entity = data.get_entity();
+        match self.rows.get(&entity) {
+            Some(row) => {
+                self.enabled[*row] = enabled;
+                for (index, column) in self.columns.iter_mut().enumerate() {
+                    column.set(*row, data.get_field_at(index));
+                }
+            }
+            None => {
+                self.rows.insert(entity, self.entities.len());
+                self.entities.push(entity);
+                self.enabled.push(enabled);
+                for (index, column) in self.columns.iter_mut().enumerate() {
+                    column.push(data.get_field_at(index));
+                }
+            }
+        }
+    }
+
+    pub(crate) fn remove(&mut self, entity: &TComponentData::EntityIdType) {
+        let row = match self.rows.remove(entity) {
+            None => return,
+            Some(row) => row,
+        };
+        self.entities.swap_remove(row);
+        self.enabled.swap_remove(row);
+        for column in &mut self.columns {
+            column.swap_remove(row);
+        }
+        if let Some(moved) = self.entities.get(row) {
+            self.rows.insert(*moved, row);
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, entity: &TComponentData::EntityIdType, enabled: bool) {
+        if let Some(row) = self.rows.get(entity) {
+            self.enabled[*row] = enabled;
+        }
+    }
+
+    /// `data` with the values of its row, if they differ from the values `data` already has.
+    pub(crate) fn apply_row(&self, data: &TComponentData) -> Option<TComponentData> {
+        let row = *self.rows.get(&data.get_entity())?;
+        let mut changed_data: Option<TComponentData> = None;
+        for (index, column) in self.columns.iter().enumerate() {
+            if let Some(value) = column.changed_value(row, data.get_field_at(index)) {
+                changed_data
+                    .get_or_insert_with(|| data.clone())
+                    .set_field_at(index, &value);
+            }
+        }
+        changed_data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::component_data::DefaultComponentData;
+    use crate::backend::component_value::DefaultComponentValue;
+    use crate::component::component_data::ComponentData;
+    use crate::component::field_layout::FieldLayout;
+    use crate::variant::VariantType;
+    use crate::world::component_columns::{Column, ComponentColumns};
+    use crate::world::ecs_world::tests::TestEntityId;
+    use std::rc::Rc;
+
+    type TestComponentData = DefaultComponentData<TestEntityId>;
+    type TestComponentValue = DefaultComponentValue<TestEntityId>;
+
+    fn create_data(
+        layout: &Rc<FieldLayout>,
+        id: u8,
+        speed: TestComponentValue,
+    ) -> TestComponentData {
+        let mut data = TestComponentData::with_layout(TestEntityId { id }, layout.clone());
+        data.set_field("speed", &speed);
+        data.set_field("name", &TestComponentValue::String(id.to_string()));
+        data
+    }
+
+    #[test]
+    fn rows_are_stored_in_typed_columns_and_removed_by_swapping() {
+        let mut layout = FieldLayout::default();
+        layout.push("speed".to_string());
+        layout.push("name".to_string());
+        let layout = Rc::new(layout);
+        let mut columns = ComponentColumns::<TestComponentData>::new(
+            layout.clone(),
+            &[VariantType::Real, VariantType::String],
+        );
+
+        columns.set(
+            &create_data(&layout, 1, TestComponentValue::Real(1.0)),
+            true,
+        );
+        columns.set(&create_data(&layout, 2, TestComponentValue::Int(2)), true);
+        columns.set(&create_data(&layout, 3, TestComponentValue::Nil), false);
+        columns.set(
+            &create_data(&layout, 1, TestComponentValue::Real(1.5)),
+            true,
+        );
+        assert_eq!(Some(&[1.5, 2.0, 0.0][..]), columns.get_reals("speed"));
+        assert_eq!(None, columns.get_ints("speed"));
+
+        columns.remove(&TestEntityId { id: 1 });
+        assert_eq!(
+            &[TestEntityId { id: 3 }, TestEntityId { id: 2 }],
+            columns.get_entities()
+        );
+        assert_eq!(Some(&[0.0, 2.0][..]), columns.get_reals("speed"));
+        assert_eq!(&[false, true], columns.get_enabled());
+        assert_eq!(
+            Some(&Column::Value(vec![
+                TestComponentValue::String("3".to_string()),
+                TestComponentValue::String("2".to_string()),
+            ])),
+            columns.get_column("name")
+        );
+
+        columns.get_reals_mut("speed").unwrap()[1] = 4.0;
+        let unchanged = create_data(&layout, 3, TestComponentValue::Nil);
+        assert!(columns.apply_row(&unchanged).is_none());
+        let changed = columns
+            .apply_row(&create_data(&layout, 2, TestComponentValue::Int(2)))
+            .unwrap();
+        assert_eq!(TestComponentValue::Real(4.0), *changed.get_field("speed"));
+    }
+}
@@ -0,0 +1,51 @@
+use crate::component::component_info::ComponentInfo;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+/// Identifies a registered component, either by its name or by the `ComponentInfo` handle that
+/// `ECSWorld::register_component` returned. Handles skip the name lookup, but are only valid
+/// in the world that created them.
+#[derive(Clone, PartialEq, Debug)]
+pub enum ComponentKey<'a, TComponentInfo: ComponentInfo> {
+    Name(Cow<'a, str>),
+    Info(TComponentInfo),
+}
+
+impl<TComponentInfo: ComponentInfo> From<String> for ComponentKey<'_, TComponentInfo> {
+    fn from(name: String) -> Self {
+        ComponentKey::Name(Cow::Owned(name))
+    }
+}
+
+impl<'a, TComponentInfo: ComponentInfo> From<&'a String> for ComponentKey<'a, TComponentInfo> {
+    fn from(name: &'a String) -> Self {
+        ComponentKey::Name(Cow::Borrowed(name))
+    }
+}
+
+impl<'a, TComponentInfo: ComponentInfo> From<&'a str> for ComponentKey<'a, TComponentInfo> {
+    fn from(name: &'a str) -> Self {
+        ComponentKey::Name(Cow::Borrowed(name))
+    }
+}
+
+impl<TComponentInfo: ComponentInfo> From<TComponentInfo> for ComponentKey<'_, TComponentInfo> {
+    fn from(info: TComponentInfo) -> Self {
+        ComponentKey::Info(info)
+    }
+}
+
+impl<TComponentInfo: ComponentInfo> From<&TComponentInfo> for ComponentKey<'_, TComponentInfo> {
+    fn from(info: &TComponentInfo) -> Self {
+        ComponentKey::Info(*info)
+    }
+}
+
+impl<TComponentInfo: ComponentInfo> Display for ComponentKey<'_, TComponentInfo> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ComponentKey::Name(name) => write!(f, "{}", name),
+            ComponentKey::Info(info) => write!(f, "#{:016x}", info.get_hash()),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::component::component_data::ComponentData;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// The data of one component of every entity that has it, in no particular order.
+/// Removing the data of an entity moves the last data into its row, so it is constant time.
+pub(crate) struct ComponentStorage<TComponentData: ComponentData> {
+    data: Vec<Rc<RefCell<TComponentData>>>,
+    entities: Vec<TComponentData::EntityIdType>,
+    rows: HashMap<TComponentData::EntityIdType, usize>,
+}
+
+impl<TComponentData: ComponentData> Default for ComponentStorage<TComponentData> {
+    fn default() -> Self {
+        Self {
+            data: Vec::new(),
+            entities: Vec::new(),
+            rows: HashMap::default(),
+        }
+    }
+}
+
+impl<TComponentData: ComponentData> ComponentStorage<TComponentData> {
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Rc<RefCell<TComponentData>>> + '_ {
+        self.data.iter()
+    }
+
+    /// Replaces the data the entity had before, if any.
+    pub(crate) fn insert(
+        &mut self,
+        entity: TComponentData::EntityIdType,
+        data: Rc<RefCell<TComponentData>>,
+    ) {
+        match self.rows.get(&entity) {
+            Some(row) => self.data[*row] = data,
+            None => {
+                self.rows.insert(entity, self.entities.len());
+                self.entities.push(entity);
+                self.data.push(data);
+            }
+        }
+    }
+
+    pub(crate) fn remove(
+        &mut self,
+        entity: &TComponentData::EntityIdType,
+    ) -> Option<Rc<RefCell<TComponentData>>> {
+        let row = self.rows.remove(entity)?;
+        self.entities.swap_remove(row);
+        let data = self.data.swap_remove(row);
+        if let Some(moved) = self.entities.get(row) {
+            self.rows.insert(*moved, row);
+        }
+        Some(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::component_data::DefaultComponentData;
+    use crate::component::component_data::ComponentData;
+    use crate::world::component_storage::ComponentStorage;
+    use crate::world::ecs_world::tests::TestEntityId;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type TestComponentData = DefaultComponentData<TestEntityId>;
+
+    fn get_entities(storage: &ComponentStorage<TestComponentData>) -> Vec<u8> {
+        storage
+            .iter()
+            .map(|data| {
+                let data: &RefCell<TestComponentData> = data;
+                data.borrow().get_entity().id
+            })
+            .collect()
+    }
+
+    #[test]
+    fn removing_data_moves_the_last_data_into_its_row() {
+        let mut storage = ComponentStorage::<TestComponentData>::default();
+        for id in 0..4 {
+            let entity = TestEntityId { id };
+            let data = TestComponentData::new(entity);
+            storage.insert(entity, Rc::new(RefCell::new(data)));
+        }
+
+        assert!(storage.remove(&TestEntityId { id: 1 }).is_some());
+        assert!(storage.remove(&TestEntityId { id: 1 }).is_none());
+        assert_eq!(vec![0, 3, 2], get_entities(&storage));
+
+        assert!(storage.remove(&TestEntityId { id: 3 }).is_some());
+        assert!(storage.remove(&TestEntityId { id: 2 }).is_some());
+        assert_eq!(vec![0], get_entities(&storage));
+    }
+}
@@ -1,40 +1,109 @@
 use std::borrow::Borrow;
 use std::cell::{Ref, RefCell};
-use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hasher;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crate::component::component_data::ComponentData;
 use crate::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+use crate::component::component_hash::{hash_component, Fnv1aHasher};
 use crate::component::component_info::ComponentInfo;
+use crate::component::component_value::ComponentValue;
+use crate::component::field_layout::FieldLayout;
+use crate::entity::entity_id_generator::{EntityIdGeneration, EntityIdGenerator};
 use crate::entity::EntityId;
-use crate::world::errors::RegisterEntityError::AlreadyRegistered;
-use crate::world::errors::SetComponentDataError::{ComponentNotFound, DataInUse, EntityNotFound};
+use crate::variant::VariantType;
+use crate::world::component_columns::ComponentColumns;
+use crate::world::component_key::ComponentKey;
+use crate::world::component_storage::ComponentStorage;
+use crate::world::entity_list::EntityList;
+use crate::world::entity_pool::{EntityPool, PooledComponents};
+use crate::world::entity_reference::EntityReferencePolicy;
 use crate::world::errors::{
-    GetComponentDataError, GetComponentOfEntityError, RegisterEntityError, SetComponentDataError,
+    AddComponentError, BatchError, ColumnsError, DespawnEntityError, GetComponentDataError,
+    GetComponentOfEntityError, JournalError, RegisterComponentError, RegisterEntityError,
+    ReleaseEntityError, RemoveComponentError, SetComponentDataError, SetEnabledError,
+    SpawnEntityError, WorldError,
 };
+use crate::world::journal::{Journal, JournalEntry};
+use crate::world::replay::{encode_value, ReplayEvent, ReplayLog};
+
+static NEXT_WORLD_ID: AtomicU64 = AtomicU64::new(1);
+
+struct RegisteredComponent<TComponentDefinition: ComponentDefinition, TComponentInfo: ComponentInfo>
+{
+    name: String,
+    info: TComponentInfo,
+    definition: TComponentDefinition,
+    layout: Rc<FieldLayout>,
+}
 
-#[derive(Default)]
 pub struct ECSWorld<
     TComponentDefinition: ComponentDefinition,
     TComponentData: ComponentData,
     TComponentInfo: ComponentInfo,
 > {
-    component_definitions: HashMap<TComponentInfo, TComponentDefinition>,
-    component_names: HashMap<String, TComponentInfo>,
-    entities: Vec<TComponentData::EntityIdType>,
-    components: HashMap<String, Vec<Rc<RefCell<TComponentData>>>>,
+    id: u64,
+    /// Indexed by the dense component index, which is also stored in the `ComponentInfo`.
+    registered_components: Vec<RegisteredComponent<TComponentDefinition, TComponentInfo>>,
+    component_indices: HashMap<String, usize>,
+    entities: EntityList<TComponentData::EntityIdType>,
+    components: Vec<ComponentStorage<TComponentData>>,
     components_of_entity:
-        HashMap<TComponentData::EntityIdType, HashMap<String, Rc<RefCell<TComponentData>>>>,
+        HashMap<TComponentData::EntityIdType, HashMap<usize, Rc<RefCell<TComponentData>>>>,
+    journal: Option<Journal<TComponentData>>,
+    transaction_logs: Vec<Vec<JournalEntry<TComponentData>>>,
+    frame: u64,
+    recording: Option<ReplayLog<TComponentDefinition, TComponentData>>,
+    entity_id_generator: Box<dyn EntityIdGenerator<TComponentData::EntityIdType>>,
+    entity_reference_policy: EntityReferencePolicy,
+    entity_reference_policies: HashMap<usize, EntityReferencePolicy>,
+    component_columns: HashMap<usize, ComponentColumns<TComponentData>>,
+    disabled_entities: HashSet<TComponentData::EntityIdType>,
+    disabled_components: HashSet<(TComponentData::EntityIdType, usize)>,
+    entity_pool: EntityPool<TComponentData>,
+}
+
+impl<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > Default for ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>
+{
+    fn default() -> Self {
+        Self {
+            id: NEXT_WORLD_ID.fetch_add(1, Ordering::Relaxed),
+            registered_components: Vec::default(),
+            component_indices: HashMap::default(),
+            entities: EntityList::default(),
+            components: Vec::default(),
+            components_of_entity: HashMap::default(),
+            journal: None,
+            transaction_logs: Vec::default(),
+            frame: 0,
+            recording: None,
+            entity_id_generator: EntityIdGeneration::default().create_generator(),
+            entity_reference_policy: EntityReferencePolicy::default(),
+            entity_reference_policies: HashMap::default(),
+            component_columns: HashMap::default(),
+            disabled_entities: HashSet::default(),
+            disabled_components: HashSet::default(),
+            entity_pool: EntityPool::default(),
+        }
+    }
 }
 
 pub fn create_ecs_world<
     TComponentDefinition: ComponentDefinition,
     TComponentData: ComponentData,
     TComponentInfo: ComponentInfo,
->() -> ECSWorld<TComponentDefinition, TComponentData, TComponentInfo> {
-    ECSWorld::default()
+>(
+    id_generation: EntityIdGeneration,
+) -> ECSWorld<TComponentDefinition, TComponentData, TComponentInfo> {
+    let mut world = ECSWorld::default();
+    world.set_entity_id_generation(id_generation);
+    world
 }
 
 impl<
@@ -47,431 +116,1573 @@ impl<
         &mut self,
         name: String,
         component_definition: TComponentDefinition,
-    ) -> Result<TComponentInfo, String> {
-        let mut hasher = DefaultHasher::default();
-        component_definition.hash(&mut hasher);
-        name.hash(&mut hasher);
-
-        if let std::collections::hash_map::Entry::Vacant(entry) =
-            self.component_names.entry(name.clone())
-        {
-            let info = TComponentInfo::create(hasher.finish());
-            self.component_definitions
-                .entry(info)
-                .or_insert_with(|| component_definition.clone());
-            entry.insert(info);
-            self.components.insert(name.clone(), Vec::new());
-            Result::Ok(info)
-        } else {
-            Result::Err(format!(
-                "Component with name \'{}\' already registered",
-                name
-            ))
+    ) -> Result<TComponentInfo, RegisterComponentError> {
+        if self.component_indices.contains_key(&name) {
+            return Err(RegisterComponentError::AlreadyRegistered { component: name });
         }
+        let index = self.registered_components.len();
+        let info = TComponentInfo::create(
+            hash_component(&name, &component_definition),
+            index as u32,
+            self.id,
+        );
+        self.registered_components.push(RegisteredComponent {
+            name: name.clone(),
+            info,
+            layout: Rc::new(FieldLayout::from_definition(&component_definition)),
+            definition: component_definition.clone(),
+        });
+        self.component_indices.insert(name.clone(), index);
+        self.components.push(ComponentStorage::default());
+        self.record_replay_event(ReplayEvent::RegisterComponent {
+            name,
+            definition: component_definition,
+        });
+        Ok(info)
     }
 
-    pub fn get_component_data(
+    /// Data of `component` of all entities where both the entity and the component are enabled.
+    pub fn get_component_data<'a>(
         &self,
-        name: String,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
     ) -> Result<Vec<TComponentData>, GetComponentDataError> {
-        let component_data = self.components.get(&name);
-        match component_data {
-            None => Err(GetComponentDataError::ComponentNotFound),
-            Some(data) => {
-                let data = data
-                    .iter()
-                    .map(|c| {
-                        let c: &RefCell<TComponentData> = c.borrow();
-                        let c: Ref<TComponentData> = c.borrow();
-                        c.clone()
-                    })
-                    .collect();
-                Ok(data)
-            }
+        self.collect_component_data(component.into(), false)
+    }
+
+    pub fn get_component_data_including_disabled<'a>(
+        &self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Result<Vec<TComponentData>, GetComponentDataError> {
+        self.collect_component_data(component.into(), true)
+    }
+
+    fn collect_component_data(
+        &self,
+        component: ComponentKey<TComponentInfo>,
+        include_disabled: bool,
+    ) -> Result<Vec<TComponentData>, GetComponentDataError> {
+        match self.get_component_index(&component) {
+            None => Err(GetComponentDataError::ComponentNotFound {
+                component: component.to_string(),
+            }),
+            Some(index) => Ok(self.components[index]
+                .iter()
+                .filter_map(|c| {
+                    let c: &RefCell<TComponentData> = c.borrow();
+                    let c: Ref<TComponentData> = c.borrow();
+                    (include_disabled || self.is_data_enabled(&c.get_entity(), index))
+                        .then(|| c.clone())
+                })
+                .collect()),
         }
     }
 
-    pub fn has_component(&self, name: String) -> bool {
-        self.component_names.contains_key(&name)
+    pub fn has_component<'a>(
+        &self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> bool {
+        self.get_component_index(&component.into()).is_some()
+    }
+
+    /// Names of all registered components, sorted alphabetically.
+    pub fn get_component_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.component_indices.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn get_component_info<'a>(
+        &self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Option<TComponentInfo> {
+        self.get_component_index(&component.into())
+            .map(|index| self.registered_components[index].info)
+    }
+
+    /// `None` if `info` was not created by this world.
+    pub fn get_component_name(&self, info: &TComponentInfo) -> Option<String> {
+        self.get_component_index(&ComponentKey::Info(*info))
+            .map(|index| self.registered_components[index].name.clone())
+    }
+
+    pub fn get_component_definition<'a>(
+        &self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Option<&TComponentDefinition> {
+        self.get_component_index(&component.into())
+            .map(|index| &self.registered_components[index].definition)
+    }
+
+    pub fn get_component_definition_by_info(
+        &self,
+        info: &TComponentInfo,
+    ) -> Option<&TComponentDefinition> {
+        self.get_component_definition(info)
+    }
+
+    /// The entities of the world in the order they were added.
+    pub fn get_entities(&self) -> impl Iterator<Item = &TComponentData::EntityIdType> + '_ {
+        self.entities.iter()
+    }
+
+    pub fn set_entity_id_generation(&mut self, id_generation: EntityIdGeneration) {
+        self.entity_id_generator = id_generation.create_generator();
+    }
+
+    pub fn set_entity_id_generator(
+        &mut self,
+        generator: Box<dyn EntityIdGenerator<TComponentData::EntityIdType>>,
+    ) {
+        self.entity_id_generator = generator;
     }
 
+    /// Creates an entity with the next id of the id generator that is neither in use nor held by
+    /// the entity pool. Ids that are taken, e.g. because the generator was reset, are skipped.
     pub fn create_entity(&mut self) -> Box<TComponentData::EntityIdType> {
-        let id = EntityId::create();
+        let id = self.generate_entity_id();
         self.entities.push(id);
-        Box::new(*self.entities.last().unwrap())
+        self.record(JournalEntry::EntityAdded(id));
+        self.record_replay_event(ReplayEvent::CreateEntity(id));
+        Box::new(id)
     }
 
     pub fn register_entity(
         &mut self,
         id: &TComponentData::EntityIdType,
     ) -> Result<(), RegisterEntityError> {
-        if self.entities.contains(id) {
-            Err(AlreadyRegistered)
+        if self.entities.contains(id) || self.entity_pool.contains(id) {
+            Err(RegisterEntityError::AlreadyRegistered {
+                entity: id.as_string(),
+            })
         } else {
             self.entities.push(*id);
+            self.record(JournalEntry::EntityAdded(*id));
+            self.record_replay_event(ReplayEvent::RegisterEntity(*id));
             Ok(())
         }
     }
 
-    fn add_component_to_entity(
+    pub fn despawn_entity(
         &mut self,
         entity_id: &TComponentData::EntityIdType,
-        component: String,
-    ) -> Result<(), String> {
-        if !self.has_component(component.clone()) {
-            Err("Component is not registered".to_string())
-        } else if self.is_component_added_to_entity(&entity_id, component.clone()) {
-            Err("Component was already added for that entity".to_string())
+    ) -> Result<(), DespawnEntityError> {
+        if !self.entities.contains(entity_id) {
+            Err(DespawnEntityError::EntityNotFound {
+                entity: entity_id.as_string(),
+            })
         } else {
-            let components = self.components.get_mut(&component).unwrap();
-            let value = RefCell::new(TComponentData::new(*entity_id));
-            let data = Rc::new(value);
-            components.push(data.clone());
+            if let Some(journal) = &mut self.journal {
+                journal.begin_group("despawn_entity".to_string());
+            }
+            self.despawn_entities(vec![*entity_id]);
+            if let Some(journal) = &mut self.journal {
+                journal.end_group().unwrap();
+            }
+            self.record_replay_event(ReplayEvent::DespawnEntity(*entity_id));
+            Ok(())
+        }
+    }
 
-            let entity_components = self
-                .components_of_entity
-                .entry(*entity_id)
-                .or_insert_with(HashMap::new);
+    /// Spawns an entity with exactly `components`, all with default values. Reuses a released
+    /// entity with the same components, including their data, before creating a new one.
+    pub fn spawn_entity<'a, TComponentKey: Into<ComponentKey<'a, TComponentInfo>>>(
+        &mut self,
+        components: impl IntoIterator<Item = TComponentKey>,
+    ) -> Result<TComponentData::EntityIdType, SpawnEntityError> {
+        self.spawn_entity_with_id(components, None)
+    }
 
-            entity_components.insert(component, data);
-            Ok(())
+    /// Like `spawn_entity`, but reuses or creates `entity` if it is set.
+    pub(crate) fn spawn_entity_with_id<
+        'a,
+        TComponentKey: Into<ComponentKey<'a, TComponentInfo>>,
+    >(
+        &mut self,
+        components: impl IntoIterator<Item = TComponentKey>,
+        entity_id: Option<TComponentData::EntityIdType>,
+    ) -> Result<TComponentData::EntityIdType, SpawnEntityError> {
+        let mut archetype = Vec::new();
+        for component in components {
+            let component = component.into();
+            let index = self.get_component_index(&component).ok_or_else(|| {
+                SpawnEntityError::ComponentNotFound {
+                    component: component.to_string(),
+                }
+            })?;
+            archetype.push(index);
+        }
+        archetype.sort();
+        archetype.dedup();
+
+        let pooled = match entity_id {
+            Some(entity_id) => self
+                .entity_pool
+                .take_entity(&entity_id)
+                .map(|components| (entity_id, components)),
+            None => self.entity_pool.take(&archetype),
+        };
+        let entity_id = match pooled {
+            Some((entity_id, components)) => {
+                self.entities.push(entity_id);
+                self.unpark_entity(entity_id, components);
+                self.record(JournalEntry::EntityRecycled(entity_id));
+                entity_id
+            }
+            None => {
+                let entity_id = entity_id.unwrap_or_else(|| self.generate_entity_id());
+                if self.entities.contains(&entity_id) || self.entity_pool.contains(&entity_id) {
+                    return Err(SpawnEntityError::EntityAlreadyExists {
+                        entity: entity_id.as_string(),
+                    });
+                }
+                if let Some(journal) = &mut self.journal {
+                    journal.begin_group("spawn_entity".to_string());
+                }
+                self.entities.push(entity_id);
+                self.record(JournalEntry::EntityAdded(entity_id));
+                for index in &archetype {
+                    let layout = self.registered_components[*index].layout.clone();
+                    let data = TComponentData::with_layout(entity_id, layout);
+                    self.insert_component_data(&entity_id, *index, data.clone());
+                    self.record(JournalEntry::ComponentDataSet {
+                        entity: entity_id,
+                        component: *index,
+                        previous: None,
+                        data,
+                    });
+                }
+                if let Some(journal) = &mut self.journal {
+                    journal.end_group().unwrap();
+                }
+                entity_id
+            }
+        };
+        self.record_replay_event(ReplayEvent::SpawnEntity {
+            entity: entity_id,
+            components: archetype
+                .iter()
+                .map(|index| self.registered_components[*index].name.clone())
+                .collect(),
+        });
+        Ok(entity_id)
+    }
+
+    /// Resets the components of the entity to their default values and parks it in the entity
+    /// pool for `spawn_entity` to reuse. The generation of the entity advances, so references
+    /// that were taken before can be told apart from the recycled entity. Components that
+    /// reference the entity are handled by the entity reference policies, like on despawn.
+    pub fn release_entity(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> Result<(), ReleaseEntityError> {
+        if !self.entities.contains(entity_id) {
+            return Err(ReleaseEntityError::EntityNotFound {
+                entity: entity_id.as_string(),
+            });
+        }
+        if let Some(journal) = &mut self.journal {
+            journal.begin_group("release_entity".to_string());
+        }
+        let previous = self.entities.get_previous(entity_id);
+        let enabled = self.is_entity_enabled(entity_id);
+        let components = self.park_entity(entity_id);
+        self.advance_generation(entity_id, 1);
+        self.record(JournalEntry::EntityReleased {
+            entity: *entity_id,
+            previous,
+            enabled,
+            components,
+        });
+        let referencing = self.release_entity_references(entity_id);
+        self.despawn_entities(referencing);
+        if let Some(journal) = &mut self.journal {
+            journal.end_group().unwrap();
+        }
+        self.record_replay_event(ReplayEvent::ReleaseEntity(*entity_id));
+        Ok(())
+    }
+
+    /// Generation of a spawned entity, `None` if it does not exist or is parked in the pool.
+    pub fn get_entity_generation(&self, entity_id: &TComponentData::EntityIdType) -> Option<u32> {
+        self.entities
+            .contains(entity_id)
+            .then(|| self.entity_pool.get_generation(entity_id))
+    }
+
+    /// False if the entity was released since `generation` was taken.
+    pub fn is_entity_current(
+        &self,
+        entity_id: &TComponentData::EntityIdType,
+        generation: u32,
+    ) -> bool {
+        self.get_entity_generation(entity_id) == Some(generation)
+    }
+
+    /// Number of released entities waiting to be reused.
+    pub fn get_pooled_entity_count(&self) -> usize {
+        self.entity_pool.len()
+    }
+
+    /// Sets the policy for references to despawned entities of all components that do not
+    /// have a policy of their own.
+    pub fn set_entity_reference_policy(&mut self, policy: EntityReferencePolicy) {
+        self.entity_reference_policy = policy;
+        self.record_replay_event(ReplayEvent::SetEntityReferencePolicy {
+            component: None,
+            policy,
+        });
+    }
+
+    pub fn set_component_entity_reference_policy<'a>(
+        &mut self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+        policy: EntityReferencePolicy,
+    ) -> Result<(), GetComponentDataError> {
+        let component = component.into();
+        let index = self.get_component_index(&component).ok_or_else(|| {
+            GetComponentDataError::ComponentNotFound {
+                component: component.to_string(),
+            }
+        })?;
+        self.entity_reference_policies.insert(index, policy);
+        self.record_replay_event(ReplayEvent::SetEntityReferencePolicy {
+            component: Some(self.registered_components[index].name.clone()),
+            policy,
+        });
+        Ok(())
+    }
+
+    pub fn get_entity_reference_policy<'a>(
+        &self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> EntityReferencePolicy {
+        self.get_component_index(&component.into())
+            .and_then(|index| self.entity_reference_policies.get(&index))
+            .copied()
+            .unwrap_or(self.entity_reference_policy)
+    }
+
+    /// Points every reference to an entity in `mapping` at the entity it is mapped to,
+    /// e.g. after the entities of a saved world were registered again under new ids.
+    pub fn remap_entity_references(
+        &mut self,
+        mapping: &HashMap<TComponentData::EntityIdType, TComponentData::EntityIdType>,
+    ) -> Result<(), SetComponentDataError> {
+        let mut remapped = Vec::new();
+        for (component, component_data) in self.components.iter().enumerate() {
+            let fields = self.get_entity_fields(component);
+            if fields.is_empty() {
+                continue;
+            }
+            for data in component_data.iter() {
+                let data: &RefCell<TComponentData> = data.borrow();
+                let mut data = data.borrow().clone();
+                let mut changed = false;
+                for field in &fields {
+                    let mut value = data.get_field_at(*field).clone();
+                    if let Some(entity) = value.get_entity().and_then(|e| mapping.get(&e)) {
+                        value.set_entity(*entity);
+                        data.set_field_at(*field, &value);
+                        changed = true;
+                    }
+                }
+                if changed {
+                    remapped.push((self.registered_components[component].info, data));
+                }
+            }
+        }
+        for (component, data) in remapped {
+            self.set_component_data(&data.get_entity(), component, &data)?;
         }
+        Ok(())
     }
 
-    pub fn set_component_data(
+    fn add_component_to_entity<'a>(
         &mut self,
         entity_id: &TComponentData::EntityIdType,
-        component: String,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Result<(), AddComponentError> {
+        let component = component.into();
+        match self.get_component_index(&component) {
+            None => Err(AddComponentError::ComponentNotFound {
+                component: component.to_string(),
+            }),
+            Some(index) if self.has_component_index(entity_id, index) => {
+                Err(AddComponentError::AlreadyAdded {
+                    entity: entity_id.as_string(),
+                    component: component.to_string(),
+                })
+            }
+            Some(index) => {
+                let layout = self.registered_components[index].layout.clone();
+                self.insert_component_data(
+                    entity_id,
+                    index,
+                    TComponentData::with_layout(*entity_id, layout),
+                );
+                Ok(())
+            }
+        }
+    }
+
+    pub fn remove_component_from_entity<'a>(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Result<(), RemoveComponentError> {
+        let component = component.into();
+        if !self.entities.contains(entity_id) {
+            return Err(RemoveComponentError::EntityNotFound {
+                entity: entity_id.as_string(),
+            });
+        }
+        let removed = self.get_component_index(&component).and_then(|index| {
+            let enabled = !self.disabled_components.contains(&(*entity_id, index));
+            Some((index, self.take_component_data(entity_id, index)?, enabled))
+        });
+        match removed {
+            None => Err(RemoveComponentError::ComponentNotInEntity {
+                entity: entity_id.as_string(),
+                component: component.to_string(),
+            }),
+            Some((index, data, enabled)) => {
+                self.record(JournalEntry::ComponentRemoved {
+                    entity: *entity_id,
+                    component: index,
+                    data,
+                    enabled,
+                });
+                self.record_replay_event(ReplayEvent::RemoveComponentFromEntity {
+                    entity: *entity_id,
+                    component: self.registered_components[index].name.clone(),
+                });
+                Ok(())
+            }
+        }
+    }
+
+    pub fn set_component_data<'a>(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
         data: &TComponentData,
     ) -> Result<(), SetComponentDataError> {
+        let component = component.into();
         if !self.entities.contains(entity_id) {
-            Err(EntityNotFound)
-        } else if !self.components.contains_key(&component) {
-            Err(ComponentNotFound)
-        } else {
-            if self
-                .components_of_entity
-                .get(entity_id)
-                .and_then(|c| c.get(&component))
-                .is_none()
-            {
-                self.add_component_to_entity(entity_id, component.clone())
-                    .unwrap();
+            return Err(SetComponentDataError::EntityNotFound {
+                entity: entity_id.as_string(),
+            });
+        }
+        let index = match self.get_component_index(&component) {
+            None => {
+                return Err(SetComponentDataError::ComponentNotFound {
+                    component: component.to_string(),
+                })
+            }
+            Some(index) => index,
+        };
+        let previous = match self
+            .components_of_entity
+            .get(entity_id)
+            .and_then(|c| c.get(&index))
+        {
+            None => {
+                let info = self.registered_components[index].info;
+                self.add_component_to_entity(entity_id, info).unwrap();
+                None
             }
+            Some(stored_data) => match stored_data.try_borrow() {
+                Ok(stored_data) => Some(stored_data.clone()),
+                Err(_) => {
+                    return Err(SetComponentDataError::DataInUse {
+                        entity: entity_id.as_string(),
+                        component: component.to_string(),
+                    })
+                }
+            },
+        };
 
-            let stored_data = self
-                .components_of_entity
-                .get_mut(entity_id)
-                .and_then(|c| c.get_mut(&component))
-                .unwrap();
+        let stored_data = self
+            .components_of_entity
+            .get(entity_id)
+            .and_then(|c| c.get(&index))
+            .unwrap();
 
-            let mut stored_data = match { stored_data.try_borrow_mut() } {
-                Ok(data) => data,
-                Err(_) => return Err(DataInUse), // TODO: Can this be tested?
-            };
+        let mut stored_data = match stored_data.try_borrow_mut() {
+            Ok(data) => data,
+            // TODO: Can this be tested?
+            Err(_) => {
+                return Err(SetComponentDataError::DataInUse {
+                    entity: entity_id.as_string(),
+                    component: component.to_string(),
+                })
+            }
+        };
 
-            let component_information = self.component_names.get(&component).unwrap();
+        let mut fields = Vec::new();
+        let layout = &self.registered_components[index].layout;
+        for (field_index, field) in layout.get_names().iter().enumerate() {
+            let new_data = data.get_field(field);
+            stored_data.set_field_at(field_index, new_data);
+            fields.push((field.clone(), new_data.clone()));
+        }
 
-            let component_fields = self
-                .component_definitions
-                .get(component_information)
-                .unwrap();
+        let data = stored_data.clone();
+        drop(stored_data);
+        self.update_columns_of_data(index, &data);
+        self.record(JournalEntry::ComponentDataSet {
+            entity: *entity_id,
+            component: index,
+            previous,
+            data,
+        });
+        self.record_replay_event(ReplayEvent::SetComponentData {
+            entity: *entity_id,
+            component: self.registered_components[index].name.clone(),
+            fields,
+        });
+        Ok(())
+    }
 
-            for component_field in &component_fields.get_fields() {
-                let new_data = data.get_field(component_field.get_name());
-                stored_data.set_field(component_field.get_name(), &new_data.clone());
+    /// Spawns `count` entities with only `component`, like `spawn_entity`, and gives each of
+    /// them a copy of `data`. The journal records the batch as one group. If one entity fails,
+    /// the entities spawned before it are removed again.
+    pub fn spawn_batch<'a>(
+        &mut self,
+        count: usize,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+        data: &TComponentData,
+    ) -> Result<Vec<TComponentData::EntityIdType>, WorldError> {
+        let component = component.into();
+        let index = self.get_component_index(&component).ok_or_else(|| {
+            SpawnEntityError::ComponentNotFound {
+                component: component.to_string(),
             }
-            Ok(())
+        })?;
+        let name = self.registered_components[index].name.clone();
+        self.run_transaction("spawn_batch", |world| {
+            let mut entities = Vec::with_capacity(count);
+            for _ in 0..count {
+                let entity = world.spawn_entity([name.as_str()])?;
+                world.set_component_data(&entity, name.as_str(), data)?;
+                entities.push(entity);
+            }
+            Ok(entities)
+        })
+    }
+
+    /// Sets the data of `component` of every entity in `entities` to the data at the same
+    /// position in `data`. An error of one entity does not stop the batch, the result of every
+    /// entity is returned in order. The journal records the batch as one group.
+    pub fn set_component_data_batch<'a>(
+        &mut self,
+        entities: &[TComponentData::EntityIdType],
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+        data: &[TComponentData],
+    ) -> Result<Vec<Result<(), SetComponentDataError>>, BatchError> {
+        if entities.len() != data.len() {
+            return Err(BatchError::LengthMismatch {
+                entities: entities.len(),
+                data: data.len(),
+            });
         }
+        let component = component.into();
+        let info = match self.get_component_index(&component) {
+            Some(index) => self.registered_components[index].info,
+            None => {
+                return Ok(entities
+                    .iter()
+                    .map(|_| {
+                        Err(SetComponentDataError::ComponentNotFound {
+                            component: component.to_string(),
+                        })
+                    })
+                    .collect())
+            }
+        };
+        if let Some(journal) = &mut self.journal {
+            journal.begin_group("set_component_data_batch".to_string());
+        }
+        let results = entities
+            .iter()
+            .zip(data)
+            .map(|(entity, data)| self.set_component_data(entity, info, data))
+            .collect();
+        if let Some(journal) = &mut self.journal {
+            journal.end_group().unwrap();
+        }
+        Ok(results)
+    }
+
+    /// Keeps a columnar copy of the data of `component` from now on, see `ComponentColumns`.
+    pub fn enable_columns<'a>(
+        &mut self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Result<(), ColumnsError> {
+        let component = component.into();
+        let index = self.get_component_index(&component).ok_or_else(|| {
+            ColumnsError::ComponentNotFound {
+                component: component.to_string(),
+            }
+        })?;
+        if self.component_columns.contains_key(&index) {
+            return Ok(());
+        }
+        let registered_component = &self.registered_components[index];
+        let fields = registered_component.definition.get_fields();
+        let field_types: Vec<VariantType> = registered_component
+            .layout
+            .get_names()
+            .iter()
+            .map(|name| {
+                fields
+                    .iter()
+                    .find(|field| field.get_name() == *name)
+                    .map(|field| field.get_type())
+                    .unwrap_or(VariantType::Nil)
+            })
+            .collect();
+        let mut columns = ComponentColumns::<TComponentData>::new(
+            registered_component.layout.clone(),
+            &field_types,
+        );
+        for data in self.components[index].iter() {
+            let data: &RefCell<TComponentData> = data.borrow();
+            let data = data.borrow();
+            let enabled = self.is_data_enabled(&data.get_entity(), index);
+            columns.set(&data, enabled);
+        }
+        self.component_columns.insert(index, columns);
+        Ok(())
+    }
+
+    pub fn disable_columns<'a>(
+        &mut self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Result<(), ColumnsError> {
+        let component = component.into();
+        let index = self.get_component_index(&component).ok_or_else(|| {
+            ColumnsError::ComponentNotFound {
+                component: component.to_string(),
+            }
+        })?;
+        self.component_columns.remove(&index);
+        Ok(())
     }
 
-    pub fn is_component_added_to_entity(
+    pub fn get_columns<'a>(
         &self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> Result<&ComponentColumns<TComponentData>, ColumnsError> {
+        let component = component.into();
+        let index = self.get_component_index(&component).ok_or_else(|| {
+            ColumnsError::ComponentNotFound {
+                component: component.to_string(),
+            }
+        })?;
+        self.component_columns
+            .get(&index)
+            .ok_or_else(|| ColumnsError::ColumnsDisabled {
+                component: component.to_string(),
+            })
+    }
+
+    /// Runs `update` on the columns of `component`, then writes every value that was changed
+    /// through the column slices back into the data of its entity, like `set_component_data`.
+    pub fn update_columns<'a, T, F>(
+        &mut self,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+        update: F,
+    ) -> Result<T, ColumnsError>
+    where
+        F: FnOnce(&mut ComponentColumns<TComponentData>) -> T,
+    {
+        let component = component.into();
+        let index = self.get_component_index(&component).ok_or_else(|| {
+            ColumnsError::ComponentNotFound {
+                component: component.to_string(),
+            }
+        })?;
+        let mut columns =
+            self.component_columns
+                .remove(&index)
+                .ok_or_else(|| ColumnsError::ColumnsDisabled {
+                    component: component.to_string(),
+                })?;
+        let result = update(&mut columns);
+
+        let mut changed = Vec::new();
+        let mut in_use = None;
+        for entity in columns.get_entities() {
+            let data: &RefCell<TComponentData> = self.components_of_entity[entity][&index].borrow();
+            match data.try_borrow() {
+                Ok(data) => changed.extend(columns.apply_row(&data)),
+                Err(_) => {
+                    in_use = Some(*entity);
+                    break;
+                }
+            }
+        }
+        self.component_columns.insert(index, columns);
+        if let Some(entity) = in_use {
+            return Err(ColumnsError::DataInUse {
+                entity: entity.as_string(),
+                component: component.to_string(),
+            });
+        }
+        let info = self.registered_components[index].info;
+        if let Some(journal) = &mut self.journal {
+            journal.begin_group("update_columns".to_string());
+        }
+        let written = changed.iter().try_for_each(|data| {
+            self.set_component_data(&data.get_entity(), info, data)
+                .map_err(|error| match error {
+                    SetComponentDataError::DataInUse { entity, component } => {
+                        ColumnsError::DataInUse { entity, component }
+                    }
+                    SetComponentDataError::EntityNotFound { .. }
+                    | SetComponentDataError::ComponentNotFound { .. } => {
+                        unreachable!("Columns only hold data of registered entities")
+                    }
+                })
+        });
+        if let Some(journal) = &mut self.journal {
+            journal.end_group().unwrap();
+        }
+        written.map(|_| result)
+    }
+
+    /// Disabled entities keep their components, but are skipped by `get_component_data`.
+    pub fn set_entity_enabled(
+        &mut self,
         entity_id: &TComponentData::EntityIdType,
-        component: String,
+        enabled: bool,
+    ) -> Result<(), SetEnabledError> {
+        if !self.entities.contains(entity_id) {
+            return Err(SetEnabledError::EntityNotFound {
+                entity: entity_id.as_string(),
+            });
+        }
+        self.set_enabled(entity_id, None, enabled);
+        Ok(())
+    }
+
+    /// False if the entity does not exist.
+    pub fn is_entity_enabled(&self, entity_id: &TComponentData::EntityIdType) -> bool {
+        self.entities.contains(entity_id) && !self.disabled_entities.contains(entity_id)
+    }
+
+    /// A disabled component stays added to its entity, but its data is skipped by
+    /// `get_component_data`.
+    pub fn set_component_enabled<'a>(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+        enabled: bool,
+    ) -> Result<(), SetEnabledError> {
+        let component = component.into();
+        if !self.entities.contains(entity_id) {
+            return Err(SetEnabledError::EntityNotFound {
+                entity: entity_id.as_string(),
+            });
+        }
+        match self
+            .get_component_index(&component)
+            .filter(|index| self.has_component_index(entity_id, *index))
+        {
+            None => Err(SetEnabledError::ComponentNotInEntity {
+                entity: entity_id.as_string(),
+                component: component.to_string(),
+            }),
+            Some(index) => {
+                self.set_enabled(entity_id, Some(index), enabled);
+                Ok(())
+            }
+        }
+    }
+
+    /// False if the entity does not have the component. Does not take the enabled state of the
+    /// entity into account.
+    pub fn is_component_enabled<'a>(
+        &self,
+        entity_id: &TComponentData::EntityIdType,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
     ) -> bool {
-        self.components_of_entity
-            .get(entity_id)
-            .map_or_else(|| false, |c| c.contains_key(&component))
+        self.get_component_index(&component.into())
+            .is_some_and(|index| {
+                self.has_component_index(entity_id, index)
+                    && !self.disabled_components.contains(&(*entity_id, index))
+            })
+    }
+
+    pub fn is_component_added_to_entity<'a>(
+        &self,
+        entity_id: &TComponentData::EntityIdType,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
+    ) -> bool {
+        self.get_component_index(&component.into())
+            .is_some_and(|index| self.has_component_index(entity_id, index))
     }
 
     pub fn get_components_of_entity(
         &self,
         entity_id: &TComponentData::EntityIdType,
     ) -> Result<Vec<String>, GetComponentOfEntityError> {
-        if self.components_of_entity.contains_key(entity_id) {
-            Ok(self
-                .components_of_entity
-                .get(entity_id)
-                .unwrap()
+        if let Some(components) = self.components_of_entity.get(entity_id) {
+            Ok(components
                 .keys()
-                .cloned()
+                .map(|index| self.registered_components[*index].name.clone())
                 .collect())
         } else if self.entities.contains(entity_id) {
             Ok(Vec::new())
         } else {
-            Err(GetComponentOfEntityError::EntityNotFound)
+            Err(GetComponentOfEntityError::EntityNotFound {
+                entity: entity_id.as_string(),
+            })
         }
     }
-    pub fn get_component_of_entity(
+
+    pub fn get_component_of_entity<'a>(
         &self,
         entity_id: &TComponentData::EntityIdType,
-        component: String,
+        component: impl Into<ComponentKey<'a, TComponentInfo>>,
     ) -> Result<TComponentData, GetComponentOfEntityError> {
-        match self.components_of_entity.get(entity_id) {
-            None => {
-                if self.entities.contains(entity_id) {
-                    Err(GetComponentOfEntityError::ComponentNotInEntity)
-                } else {
-                    Err(GetComponentOfEntityError::EntityNotFound)
-                }
+        let component = component.into();
+        let data = self.get_component_index(&component).and_then(|index| {
+            self.components_of_entity
+                .get(entity_id)
+                .and_then(|c| c.get(&index))
+        });
+        match data {
+            Some(data) => {
+                let data: &RefCell<TComponentData> = data.borrow();
+                let data = data.borrow();
+                Ok(data.clone())
             }
-            Some(components) => {
-                let data = components.get(&component);
-                match data {
-                    None => Err(GetComponentOfEntityError::ComponentNotInEntity),
-                    Some(data) => {
-                        let data: &RefCell<TComponentData> = data.borrow();
-                        let data = data.borrow();
-                        Ok(data.clone())
-                    }
-                }
+            None if self.entities.contains(entity_id) => {
+                Err(GetComponentOfEntityError::ComponentNotInEntity {
+                    entity: entity_id.as_string(),
+                    component: component.to_string(),
+                })
             }
+            None => Err(GetComponentOfEntityError::EntityNotFound {
+                entity: entity_id.as_string(),
+            }),
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::component::component_data::ComponentData;
-    use crate::component::component_definition::ComponentDefinition;
-    use crate::component::component_definition::ComponentFieldDefinition;
-    use crate::component::component_info::ComponentInfo;
-    use crate::component::component_value::ComponentValue;
-    use crate::entity::EntityId;
-    use crate::variant::VariantType;
-    use crate::world::ecs_world;
-    use crate::world::ecs_world::ECSWorld;
-    use crate::world::errors::SetComponentDataError::{ComponentNotFound, EntityNotFound};
-    use crate::world::errors::{GetComponentDataError, GetComponentOfEntityError};
-    use std::borrow::Borrow;
-    use std::collections::HashMap;
+    pub fn enable_journal(&mut self) {
+        if self.journal.is_none() {
+            self.journal = Some(Journal::default());
+        }
+        self.record_replay_event(ReplayEvent::EnableJournal);
+    }
 
-    #[derive(Default, Clone, Hash, Debug, PartialEq, Eq)]
-    pub struct TestComponentFieldDefinition {
-        pub name: String,
-        pub field_type: VariantType,
+    pub fn disable_journal(&mut self) {
+        self.journal = None;
+        self.record_replay_event(ReplayEvent::DisableJournal);
     }
 
-    impl ComponentFieldDefinition for TestComponentFieldDefinition {
-        fn get_type(&self) -> VariantType {
-            self.field_type
+    pub fn is_journal_enabled(&self) -> bool {
+        self.journal.is_some()
+    }
+
+    pub fn begin_journal_group(&mut self, name: String) -> Result<(), JournalError> {
+        let journal = self.journal.as_mut().ok_or(JournalError::JournalDisabled)?;
+        journal.begin_group(name.clone());
+        self.record_replay_event(ReplayEvent::BeginJournalGroup(name));
+        Ok(())
+    }
+
+    pub fn end_journal_group(&mut self) -> Result<(), JournalError> {
+        let journal = self.journal.as_mut().ok_or(JournalError::JournalDisabled)?;
+        journal.end_group()?;
+        self.record_replay_event(ReplayEvent::EndJournalGroup);
+        Ok(())
+    }
+
+    pub fn undo(&mut self) -> Result<(), JournalError> {
+        let journal = self.journal.as_mut().ok_or(JournalError::JournalDisabled)?;
+        let group = journal.take_undo_group()?;
+        for entry in group.entries.iter().rev() {
+            self.revert(entry);
         }
+        self.journal.as_mut().unwrap().push_redo_group(group);
+        self.record_replay_event(ReplayEvent::Undo);
+        Ok(())
+    }
 
-        fn get_name(&self) -> String {
-            self.name.clone()
+    pub fn redo(&mut self) -> Result<(), JournalError> {
+        let journal = self.journal.as_mut().ok_or(JournalError::JournalDisabled)?;
+        let group = journal.take_redo_group()?;
+        for entry in group.entries.iter() {
+            self.reapply(entry);
         }
+        self.journal.as_mut().unwrap().push_undo_group(group);
+        self.record_replay_event(ReplayEvent::Redo);
+        Ok(())
     }
 
-    #[derive(Default, Hash, Clone, PartialEq, Debug)]
-    pub struct TestComponentDefinition {
-        pub fields: Vec<
-            <ecs_world::tests::TestComponentDefinition as ComponentDefinition>::FieldDefinition,
-        >,
+    pub fn get_undo_name(&self) -> Option<String> {
+        self.journal.as_ref().and_then(|j| j.get_undo_name())
     }
 
-    impl ComponentDefinition for TestComponentDefinition {
-        type FieldDefinition = TestComponentFieldDefinition;
+    pub fn get_redo_name(&self) -> Option<String> {
+        self.journal.as_ref().and_then(|j| j.get_redo_name())
+    }
 
-        fn get_fields(&self) -> Vec<Self::FieldDefinition> {
-            self.fields.clone()
-        }
+    /// Runs `transaction` and keeps its changes only if it returns `Ok`.
+    /// When it returns `Err` every journaled change made inside of it is rolled back and the
+    /// error is returned unchanged. Changes that are not journaled, like registering components
+    /// or setting reference policies, are kept. Transactions can be nested.
+    pub fn transaction<T, E, F>(&mut self, transaction: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        self.run_transaction("transaction", transaction)
+    }
+
+    /// Runs `transaction` like `transaction`, but names its journal group `name`.
+    fn run_transaction<T, E, F>(&mut self, name: &str, transaction: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let replay_mark = self.recording.as_ref().map(|r| r.get_mark());
+        self.transaction_logs.push(Vec::new());
+        let result = transaction(self);
+        let log = self.transaction_logs.pop().unwrap();
+
+        match result {
+            Ok(value) => {
+                if let Some(parent_log) = self.transaction_logs.last_mut() {
+                    parent_log.extend(log);
+                } else if let Some(journal) = &mut self.journal {
+                    journal.begin_group(name.to_string());
+                    for entry in log {
+                        journal.record(entry);
+                    }
+                    journal.end_group().unwrap();
 
-        fn add_field(&mut self, field_definition: Self::FieldDefinition) {
-            self.fields.push(field_definition);
+                    if let (Some(recording), Some(mark)) = (&mut self.recording, replay_mark) {
+                        recording.insert(mark, ReplayEvent::BeginJournalGroup(name.to_string()));
+                        recording.record(ReplayEvent::EndJournalGroup);
+                    }
+                }
+                Ok(value)
+            }
+            Err(err) => {
+                for entry in log.iter().rev() {
+                    self.revert(entry);
+                }
+                if let (Some(recording), Some(mark)) = (&mut self.recording, replay_mark) {
+                    recording.discard_reverted(mark);
+                }
+                Err(err)
+            }
         }
     }
 
-    #[derive(Default, Hash, Eq, PartialEq, Copy, Clone, Debug)]
-    pub struct TestEntityId {
-        id: u8,
+    /// Starts recording every external mutation of this world, see `ReplayLog`.
+    /// `seed` is stored in the log so the RNG of the game can be seeded the same way on replay.
+    pub fn start_recording(&mut self, seed: u64) {
+        self.recording = Some(ReplayLog::new(seed, self.frame));
     }
 
-    impl EntityId for TestEntityId {
-        fn create() -> Self
-        where
-            Self: Sized,
-        {
-            TestEntityId { id: 0 }
+    pub fn stop_recording(&mut self) -> Option<ReplayLog<TComponentDefinition, TComponentData>> {
+        let mut recording = self.recording.take()?;
+        recording.close();
+        Some(recording)
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    pub fn get_frame(&self) -> u64 {
+        self.frame
+    }
+
+    pub(crate) fn set_frame(&mut self, frame: u64) {
+        self.frame = frame;
+    }
+
+    /// Ends the current frame. While recording, the checksum of the world is stored for it.
+    pub fn advance_frame(&mut self) {
+        let checksum = self.recording.as_ref().map(|_| self.checksum());
+        if let (Some(recording), Some(checksum)) = (&mut self.recording, checksum) {
+            recording.end_frame(checksum);
         }
+        self.frame += 1;
+    }
 
-        fn as_string(&self) -> String {
-            "".to_string()
+    /// Checksum over the entities, their components and the field values of the world.
+    /// It does not depend on the order in which the components were added to an entity.
+    pub fn checksum(&self) -> u64 {
+        let mut hasher = Fnv1aHasher::default();
+        for entity in self.entities.iter() {
+            hasher.write_str(&entity.as_string());
+            if self.disabled_entities.contains(entity) {
+                hasher.write_str("disabled");
+            }
+            let components = match self.components_of_entity.get(entity) {
+                None => continue,
+                Some(components) => components,
+            };
+            let mut component_indices: Vec<&usize> = components.keys().collect();
+            component_indices.sort_by_key(|index| &self.registered_components[**index].name);
+            for index in component_indices {
+                let component = &self.registered_components[*index];
+                hasher.write_str(&component.name);
+                if self.disabled_components.contains(&(*entity, *index)) {
+                    hasher.write_str("disabled");
+                }
+                let data: &RefCell<TComponentData> = components.get(index).unwrap().borrow();
+                let data = data.borrow();
+                for (field_index, field) in component.layout.get_names().iter().enumerate() {
+                    hasher.write_str(field);
+                    hasher.write_str(&encode_value(data.get_field_at(field_index)));
+                }
+            }
         }
+        hasher.finish()
+    }
 
-        fn parse_str(_input: &str) -> Result<Self, String>
-        where
-            Self: Sized,
-        {
-            Result::Err("Not Implemented".to_string())
+    /// Index of the component `component` identifies in this world. Handles are only accepted
+    /// if this world created them.
+    fn get_component_index(&self, component: &ComponentKey<TComponentInfo>) -> Option<usize> {
+        match component {
+            ComponentKey::Name(name) => self.component_indices.get(name.as_ref()).copied(),
+            ComponentKey::Info(info) => {
+                let index = info.get_index() as usize;
+                match self.registered_components.get(index) {
+                    Some(registered)
+                        if info.get_world_id() == self.id && registered.info == *info =>
+                    {
+                        Some(index)
+                    }
+                    _ => None,
+                }
+            }
         }
     }
 
-    #[derive(Clone, PartialEq, Debug)]
-    pub enum TestComponentValue {
-        Nil,
-        Int(i64),
-        String(String),
-        Bool(bool),
-        Real(f64),
+    fn has_component_index(&self, entity_id: &TComponentData::EntityIdType, index: usize) -> bool {
+        self.components_of_entity
+            .get(entity_id)
+            .is_some_and(|c| c.contains_key(&index))
+    }
+
+    /// Layout indices of the entity fields of `component`.
+    fn get_entity_fields(&self, component: usize) -> Vec<usize> {
+        let component = &self.registered_components[component];
+        component
+            .definition
+            .get_fields()
+            .iter()
+            .filter(|field| field.get_type() == VariantType::Entity)
+            .filter_map(|field| component.layout.get_index(&field.get_name()))
+            .collect()
     }
 
-    impl ComponentValue for TestComponentValue {
-        fn get_type(&self) -> VariantType {
-            match self {
-                TestComponentValue::Nil => VariantType::Nil,
-                TestComponentValue::Int(_) => VariantType::Int,
-                TestComponentValue::String(_) => VariantType::String,
-                TestComponentValue::Bool(_) => VariantType::Bool,
-                TestComponentValue::Real(_) => VariantType::Real,
+    /// Despawns `entities` and every entity that the entity reference policies despawn with them.
+    fn despawn_entities(&mut self, mut entities: Vec<TComponentData::EntityIdType>) {
+        while let Some(entity) = entities.pop() {
+            if !self.entities.contains(&entity) {
+                continue;
             }
+            let previous = self.entities.get_previous(&entity);
+            let enabled = self.is_entity_enabled(&entity);
+            let components = self.remove_entity(&entity);
+            self.record(JournalEntry::EntityDespawned {
+                entity,
+                previous,
+                enabled,
+                components,
+            });
+            entities.extend(self.release_entity_references(&entity));
         }
+    }
 
-        fn set_nil(&mut self) {
-            *self = TestComponentValue::Nil;
+    /// Applies the entity reference policies to all components that reference `entity`.
+    /// Returns the entities that have to be despawned because of them.
+    fn release_entity_references(
+        &mut self,
+        entity: &TComponentData::EntityIdType,
+    ) -> Vec<TComponentData::EntityIdType> {
+        let mut references = Vec::new();
+        for (component, component_data) in self.components.iter().enumerate() {
+            let fields = self.get_entity_fields(component);
+            if fields.is_empty() {
+                continue;
+            }
+            for data in component_data.iter() {
+                let data: &RefCell<TComponentData> = data.borrow();
+                let data = data.borrow();
+                let referencing_fields: Vec<usize> = fields
+                    .iter()
+                    .filter(|field| data.get_field_at(**field).get_entity() == Some(*entity))
+                    .copied()
+                    .collect();
+                if !referencing_fields.is_empty() {
+                    references.push((component, data.clone(), referencing_fields));
+                }
+            }
+        }
+
+        let mut despawned = Vec::new();
+        for (component, previous, fields) in references {
+            let referencing_entity = previous.get_entity();
+            let policy = self
+                .entity_reference_policies
+                .get(&component)
+                .copied()
+                .unwrap_or(self.entity_reference_policy);
+            match policy {
+                EntityReferencePolicy::SetNil => {
+                    let mut nil = TComponentData::ComponentValueType::default();
+                    nil.set_nil();
+                    let mut data = previous.clone();
+                    for field in fields {
+                        data.set_field_at(field, &nil);
+                    }
+                    self.replace_component_data(&referencing_entity, component, data.clone());
+                    self.record(JournalEntry::ComponentDataSet {
+                        entity: referencing_entity,
+                        component,
+                        previous: Some(previous),
+                        data,
+                    });
+                }
+                EntityReferencePolicy::RemoveComponent => {
+                    let enabled = !self
+                        .disabled_components
+                        .contains(&(referencing_entity, component));
+                    if let Some(data) = self.take_component_data(&referencing_entity, component) {
+                        self.record(JournalEntry::ComponentRemoved {
+                            entity: referencing_entity,
+                            component,
+                            data,
+                            enabled,
+                        });
+                    }
+                }
+                EntityReferencePolicy::DespawnEntity => {
+                    if !despawned.contains(&referencing_entity) {
+                        despawned.push(referencing_entity);
+                    }
+                }
+            }
         }
+        despawned
+    }
 
-        fn get_nil(&self) {}
+    fn record_replay_event(&mut self, event: ReplayEvent<TComponentDefinition, TComponentData>) {
+        if let Some(recording) = &mut self.recording {
+            recording.record(event);
+        }
+    }
 
-        fn set_int(&mut self, value: i64) {
-            *self = TestComponentValue::Int(value)
+    fn record(&mut self, entry: JournalEntry<TComponentData>) {
+        if let Some(log) = self.transaction_logs.last_mut() {
+            log.push(entry);
+        } else if let Some(journal) = &mut self.journal {
+            journal.record(entry);
         }
+    }
 
-        fn get_int(&self) -> i64 {
-            match self {
-                TestComponentValue::Nil => 0,
-                TestComponentValue::Int(value) => *value,
-                TestComponentValue::String(value) => {
-                    let result = str::parse::<i64>(value);
-                    result.unwrap_or(0)
+    fn revert(&mut self, entry: &JournalEntry<TComponentData>) {
+        match entry {
+            JournalEntry::EntityAdded(entity) => {
+                self.remove_entity(entity);
+            }
+            JournalEntry::ComponentDataSet {
+                entity,
+                component,
+                previous,
+                ..
+            } => match previous {
+                None => {
+                    self.take_component_data(entity, *component);
                 }
-                TestComponentValue::Bool(value) => {
-                    if *value {
-                        1
-                    } else {
-                        0
-                    }
+                Some(previous) => {
+                    self.replace_component_data(entity, *component, previous.clone());
+                }
+            },
+            JournalEntry::ComponentRemoved {
+                entity,
+                component,
+                data,
+                enabled,
+            } => {
+                self.apply_enabled(entity, Some(*component), *enabled);
+                self.insert_component_data(entity, *component, data.clone());
+            }
+            JournalEntry::EntityDespawned {
+                entity,
+                previous,
+                enabled,
+                components,
+            } => {
+                self.entities.insert_after(previous.as_ref(), *entity);
+                self.apply_enabled(entity, None, *enabled);
+                for (component, data, enabled) in components {
+                    self.apply_enabled(entity, Some(*component), *enabled);
+                    self.insert_component_data(entity, *component, data.clone());
+                }
+            }
+            JournalEntry::EntityReleased {
+                entity,
+                previous,
+                enabled,
+                components,
+            } => {
+                let pooled = self.entity_pool.take_entity(entity).unwrap();
+                for ((_, stored_data), (component, data, enabled)) in pooled.iter().zip(components)
+                {
+                    let stored_data: &RefCell<TComponentData> = stored_data.borrow();
+                    *stored_data.borrow_mut() = data.clone();
+                    self.apply_enabled(entity, Some(*component), *enabled);
+                }
+                self.apply_enabled(entity, None, *enabled);
+                self.entities.insert_after(previous.as_ref(), *entity);
+                self.unpark_entity(*entity, pooled);
+                self.advance_generation(entity, -1);
+            }
+            JournalEntry::EntityRecycled(entity) => {
+                self.park_entity(entity);
+            }
+            JournalEntry::EnabledSet {
+                entity,
+                component,
+                enabled,
+            } => {
+                self.apply_enabled(entity, *component, !enabled);
+            }
+        }
+    }
+
+    fn reapply(&mut self, entry: &JournalEntry<TComponentData>) {
+        match entry {
+            JournalEntry::EntityAdded(entity) => {
+                self.entities.push(*entity);
+            }
+            JournalEntry::ComponentDataSet {
+                entity,
+                component,
+                data,
+                ..
+            } => {
+                self.replace_component_data(entity, *component, data.clone());
+            }
+            JournalEntry::ComponentRemoved {
+                entity, component, ..
+            } => {
+                self.take_component_data(entity, *component);
+            }
+            JournalEntry::EntityDespawned { entity, .. } => {
+                self.remove_entity(entity);
+            }
+            JournalEntry::EntityReleased { entity, .. } => {
+                self.park_entity(entity);
+                self.advance_generation(entity, 1);
+            }
+            JournalEntry::EntityRecycled(entity) => {
+                let pooled = self.entity_pool.take_entity(entity).unwrap();
+                self.entities.push(*entity);
+                self.unpark_entity(*entity, pooled);
+            }
+            JournalEntry::EnabledSet {
+                entity,
+                component,
+                enabled,
+            } => {
+                self.apply_enabled(entity, *component, *enabled);
+            }
+        }
+    }
+
+    fn update_columns_of_data(&mut self, component: usize, data: &TComponentData) {
+        let enabled = self.is_data_enabled(&data.get_entity(), component);
+        if let Some(columns) = self.component_columns.get_mut(&component) {
+            columns.set(data, enabled);
+        }
+    }
+
+    fn set_enabled(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: Option<usize>,
+        enabled: bool,
+    ) {
+        if self.apply_enabled(entity_id, component, enabled) {
+            self.record(JournalEntry::EnabledSet {
+                entity: *entity_id,
+                component,
+                enabled,
+            });
+        }
+        self.record_replay_event(ReplayEvent::SetEnabled {
+            entity: *entity_id,
+            component: component.map(|index| self.registered_components[index].name.clone()),
+            enabled,
+        });
+    }
+
+    fn is_data_enabled(&self, entity_id: &TComponentData::EntityIdType, component: usize) -> bool {
+        !self.disabled_entities.contains(entity_id)
+            && !self.disabled_components.contains(&(*entity_id, component))
+    }
+
+    /// Enables or disables the entity, or its `component` if set.
+    /// Returns false if it already was in that state.
+    fn apply_enabled(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: Option<usize>,
+        enabled: bool,
+    ) -> bool {
+        let changed = match (component, enabled) {
+            (None, true) => self.disabled_entities.remove(entity_id),
+            (None, false) => self.disabled_entities.insert(*entity_id),
+            (Some(index), true) => self.disabled_components.remove(&(*entity_id, index)),
+            (Some(index), false) => self.disabled_components.insert((*entity_id, index)),
+        };
+        if changed {
+            let entity_enabled = !self.disabled_entities.contains(entity_id);
+            for (index, columns) in self.component_columns.iter_mut() {
+                if component.is_none_or(|component| component == *index) {
+                    let component_enabled =
+                        !self.disabled_components.contains(&(*entity_id, *index));
+                    columns.set_enabled(entity_id, entity_enabled && component_enabled);
                 }
-                TestComponentValue::Real(value) => *value as i64,
             }
         }
+        changed
+    }
 
-        fn set_string(&mut self, value: String) {
-            *self = TestComponentValue::String(value)
-        }
+    fn insert_component_data(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: usize,
+        data: TComponentData,
+    ) {
+        self.update_columns_of_data(component, &data);
+        let data = Rc::new(RefCell::new(data));
+        self.components[component].insert(*entity_id, data.clone());
+        self.components_of_entity
+            .entry(*entity_id)
+            .or_default()
+            .insert(component, data);
+    }
 
-        fn get_string(&self) -> String {
-            match self {
-                TestComponentValue::Nil => "".to_string(),
-                TestComponentValue::Int(value) => value.to_string(),
-                TestComponentValue::String(value) => value.clone(),
-                TestComponentValue::Bool(value) => value.to_string(),
-                TestComponentValue::Real(value) => value.to_string(),
+    fn replace_component_data(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: usize,
+        data: TComponentData,
+    ) {
+        match self
+            .components_of_entity
+            .get(entity_id)
+            .and_then(|c| c.get(&component))
+        {
+            None => self.insert_component_data(entity_id, component, data),
+            Some(stored_data) => {
+                let stored_data: &RefCell<TComponentData> = stored_data.borrow();
+                *stored_data.borrow_mut() = data.clone();
+                self.update_columns_of_data(component, &data);
             }
         }
+    }
 
-        fn set_bool(&mut self, value: bool) {
-            *self = TestComponentValue::Bool(value)
+    fn take_component_data(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+        component: usize,
+    ) -> Option<TComponentData> {
+        let entity_components = self.components_of_entity.get_mut(entity_id)?;
+        let data = entity_components.remove(&component)?;
+        if entity_components.is_empty() {
+            self.components_of_entity.remove(entity_id);
+        }
+        self.components[component].remove(entity_id);
+        self.disabled_components.remove(&(*entity_id, component));
+        if let Some(columns) = self.component_columns.get_mut(&component) {
+            columns.remove(entity_id);
         }
+        let data: &RefCell<TComponentData> = data.borrow();
+        let data = data.borrow().clone();
+        Some(data)
+    }
 
-        fn get_bool(&self) -> bool {
-            match self {
-                TestComponentValue::Nil => false,
-                TestComponentValue::Int(value) => *value != 0,
-                TestComponentValue::String(value) => value.is_empty(),
-                TestComponentValue::Bool(value) => *value,
-                TestComponentValue::Real(value) => *value != 0.0,
+    /// Moves the entity and the data of its components into the entity pool and resets the data
+    /// to default values. Returns the index, data and enabled state every component had.
+    fn park_entity(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> Vec<(usize, TComponentData, bool)> {
+        self.entities.remove(entity_id);
+        self.disabled_entities.remove(entity_id);
+        let mut components: PooledComponents<TComponentData> = self
+            .components_of_entity
+            .remove(entity_id)
+            .map(|c| c.into_iter().collect())
+            .unwrap_or_default();
+        components.sort_by_key(|(index, _)| *index);
+        let mut previous = Vec::with_capacity(components.len());
+        for (index, data) in &components {
+            self.components[*index].remove(entity_id);
+            if let Some(columns) = self.component_columns.get_mut(index) {
+                columns.remove(entity_id);
+            }
+            let enabled = !self.disabled_components.remove(&(*entity_id, *index));
+            let data: &RefCell<TComponentData> = data.borrow();
+            let mut data = data.borrow_mut();
+            previous.push((*index, data.clone(), enabled));
+            for field in 0..data.get_layout().len() {
+                data.set_field_at(field, &TComponentData::ComponentValueType::default());
             }
         }
+        self.entity_pool.park(*entity_id, components);
+        previous
+    }
 
-        fn set_real(&mut self, value: f64) {
-            *self = TestComponentValue::Real(value)
+    /// Adds the data of a parked entity back to the world. The entity itself has to be added to
+    /// the entities before.
+    fn unpark_entity(
+        &mut self,
+        entity_id: TComponentData::EntityIdType,
+        components: PooledComponents<TComponentData>,
+    ) {
+        for (index, data) in components {
+            {
+                let data: &RefCell<TComponentData> = data.borrow();
+                self.update_columns_of_data(index, &data.borrow());
+            }
+            self.components[index].insert(entity_id, data.clone());
+            self.components_of_entity
+                .entry(entity_id)
+                .or_default()
+                .insert(index, data);
         }
+    }
 
-        fn get_real(&self) -> f64 {
-            match self {
-                TestComponentValue::Nil => 0.0,
-                TestComponentValue::Int(value) => *value as f64,
-                TestComponentValue::String(value) => {
-                    let result = str::parse::<f64>(value);
-                    result.unwrap_or(0.0)
-                }
-                TestComponentValue::Bool(value) => {
-                    if *value {
-                        1.0
-                    } else {
-                        0.0
-                    }
-                }
-                TestComponentValue::Real(value) => *value,
+    /// The next id of the id generator that is neither in use nor held by the entity pool.
+    fn generate_entity_id(&mut self) -> TComponentData::EntityIdType {
+        loop {
+            let id = self.entity_id_generator.generate();
+            if !self.entities.contains(&id) && !self.entity_pool.contains(&id) {
+                return id;
             }
         }
     }
 
-    impl Default for TestComponentValue {
-        fn default() -> Self {
-            TestComponentValue::Nil
-        }
+    fn advance_generation(&mut self, entity_id: &TComponentData::EntityIdType, delta: i32) {
+        let generation = self
+            .entity_pool
+            .get_generation(entity_id)
+            .wrapping_add_signed(delta);
+        self.entity_pool.set_generation(entity_id, generation);
     }
 
-    #[derive(Default, Clone)]
-    pub struct TestComponentData {
-        pub entity: TestEntityId,
-        pub fields: HashMap<
-            String,
-            <ecs_world::tests::TestComponentData as ComponentData>::ComponentValueType,
-        >,
+    fn remove_entity(
+        &mut self,
+        entity_id: &TComponentData::EntityIdType,
+    ) -> Vec<(usize, TComponentData, bool)> {
+        self.entities.remove(entity_id);
+        self.disabled_entities.remove(entity_id);
+        let mut component_indices: Vec<usize> = self
+            .components_of_entity
+            .get(entity_id)
+            .map(|c| c.keys().cloned().collect())
+            .unwrap_or_default();
+        component_indices.sort();
+        component_indices
+            .into_iter()
+            .map(|component| {
+                let enabled = !self.disabled_components.contains(&(*entity_id, component));
+                let data = self.take_component_data(entity_id, component).unwrap();
+                (component, data, enabled)
+            })
+            .collect()
     }
+}
 
-    impl ComponentData for TestComponentData {
-        type EntityIdType = TestEntityId;
-        type ComponentValueType = TestComponentValue;
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::backend::component_data::DefaultComponentData;
+    use crate::backend::component_definition::{
+        DefaultComponentDefinition, DefaultComponentFieldDefinition,
+    };
+    use crate::backend::component_info::DefaultComponentInfo;
+    use crate::backend::component_value::DefaultComponentValue;
+    use crate::backend::entity_id::DefaultEntityId;
+    use crate::backend::DefaultECSWorld;
+    use crate::component::component_data::ComponentData;
+    use crate::component::component_definition::ComponentDefinition;
+    use crate::component::component_definition::ComponentFieldDefinition;
+    use crate::component::component_info::ComponentInfo;
+    use crate::component::component_value::ComponentValue;
+    use crate::entity::entity_id_generator::EntityIdGeneration;
+    use crate::entity::EntityId;
+    use crate::variant::VariantType;
+    use crate::world::ecs_world;
+    use crate::world::ecs_world::ECSWorld;
+    use crate::world::entity_reference::EntityReferencePolicy;
+    use crate::world::errors::SetComponentDataError::{ComponentNotFound, EntityNotFound};
+    use crate::world::errors::{
+        BatchError, ColumnsError, DespawnEntityError, GetComponentDataError,
+        GetComponentOfEntityError, JournalError, RegisterComponentError, RegisterEntityError,
+        RemoveComponentError, SetComponentDataError, SetEnabledError, SpawnEntityError, WorldError,
+    };
+    use std::borrow::Borrow;
+    use std::cell::Cell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
 
-        fn new(entity: TestEntityId) -> Self {
-            TestComponentData {
-                entity,
-                fields: HashMap::new(),
-            }
-        }
+    pub type TestComponentFieldDefinition = DefaultComponentFieldDefinition;
+    pub type TestComponentDefinition = DefaultComponentDefinition;
+    pub type TestComponentValue = DefaultComponentValue<TestEntityId>;
+    pub type TestComponentData = DefaultComponentData<TestEntityId>;
+    pub type TestComponentInfo = DefaultComponentInfo;
 
-        fn get_entity(&self) -> TestEntityId {
-            self.entity
-        }
+    #[derive(Default, Hash, Eq, PartialEq, Copy, Clone, Debug)]
+    pub struct TestEntityId {
+        pub id: u8,
+    }
 
-        fn get_field(&self, field: String) -> &Self::ComponentValueType {
-            self.fields.get(&field).unwrap()
+    impl EntityId for TestEntityId {
+        fn create() -> Self
+        where
+            Self: Sized,
+        {
+            thread_local! {
+                static NEXT_ID: Cell<u8> = const { Cell::new(0) };
+            }
+            let id = NEXT_ID.with(|next| next.replace(next.get().wrapping_add(1)));
+            TestEntityId { id }
         }
 
-        fn set_field(&mut self, field: String, value: &Self::ComponentValueType) {
-            self.fields.insert(field, value.clone());
+        fn from_u128(value: u128) -> Self
+        where
+            Self: Sized,
+        {
+            TestEntityId { id: value as u8 }
         }
-    }
 
-    #[derive(Default, Hash, Eq, PartialEq, Copy, Clone)]
-    pub struct TestComponentInfo {
-        pub hash: u64,
-    }
-
-    impl ComponentInfo for TestComponentInfo {
-        fn get_hash(&self) -> u64 {
-            self.hash
+        fn as_string(&self) -> String {
+            self.id.to_string()
         }
 
-        fn create(hash: u64) -> Self
+        fn parse_str(input: &str) -> Result<Self, String>
         where
             Self: Sized,
         {
-            Self { hash }
+            match str::parse::<u8>(input) {
+                Ok(id) => Ok(TestEntityId { id }),
+                Err(err) => Err(err.to_string()),
+            }
         }
     }
 
@@ -502,7 +1713,10 @@ mod tests {
             ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
         let result = world.get_component_of_entity(&TestEntityId::create(), "Test".to_string());
         assert!(
-            matches!(result, Err(GetComponentOfEntityError::EntityNotFound)),
+            matches!(
+                result,
+                Err(GetComponentOfEntityError::EntityNotFound { .. })
+            ),
             "Should have returned error with EntityNotFound"
         );
     }
@@ -515,7 +1729,10 @@ mod tests {
         let entity_id = world.create_entity();
         let result = world.get_component_of_entity(&entity_id, "Test".to_string());
         assert!(
-            matches!(result, Err(GetComponentOfEntityError::ComponentNotInEntity)),
+            matches!(
+                result,
+                Err(GetComponentOfEntityError::ComponentNotInEntity { .. })
+            ),
             "Should have returned error with ComponentNotInEntity"
         );
     }
@@ -539,7 +1756,10 @@ mod tests {
         let result = world
             .get_component_of_entity(&entity_id, format!("not_{}", component_name).to_string());
         assert!(
-            matches!(result, Err(GetComponentOfEntityError::ComponentNotInEntity)),
+            matches!(
+                result,
+                Err(GetComponentOfEntityError::ComponentNotInEntity { .. })
+            ),
             "Should have returned error with ComponentNotInEntity"
         );
     }
@@ -563,7 +1783,7 @@ mod tests {
 
         let entity_id = world.create_entity();
         let mut data = TestComponentData::new(*entity_id);
-        data.set_field(field_name.to_string(), &TestComponentValue::Int(9));
+        data.set_field(field_name, &TestComponentValue::Int(9));
 
         let field_name = "TestField";
         world
@@ -574,7 +1794,7 @@ mod tests {
 
         world.register_entity(&entity_id).unwrap();
         let mut data = TestComponentData::new(entity_id);
-        data.set_field(field_name.to_string(), &TestComponentValue::Int(27));
+        data.set_field(field_name, &TestComponentValue::Int(27));
         world
             .set_component_data(&entity_id, component_name.to_string(), &data)
             .unwrap();
@@ -584,7 +1804,7 @@ mod tests {
         assert_eq!(2, components.len());
 
         for data in components {
-            let _value = data.get_field(field_name.to_string());
+            let _value = data.get_field(field_name);
             match data.entity.id {
                 0 => {
                     assert!(
@@ -611,7 +1831,7 @@ mod tests {
         let result = world.get_component_data(component_name.to_string());
 
         assert!(
-            matches!(result, Err(GetComponentDataError::ComponentNotFound)),
+            matches!(result, Err(GetComponentDataError::ComponentNotFound { .. })),
             "Should have returned ComponentNotFound"
         );
     }
@@ -697,37 +1917,36 @@ mod tests {
         component_definition.add_field(field_definition);
 
         let component_name = "Test";
-        let result: Result<TestComponentInfo, String> =
+        let result: Result<TestComponentInfo, RegisterComponentError> =
             world.register_component(component_name.to_string(), component_definition.clone());
         assert!(result.is_ok(), "register_component should have returned Ok");
 
         let returned_info = result.unwrap();
         assert_ne!(0, returned_info.hash, "Hash should have a value");
 
-        assert!(
-            world.component_definitions.contains_key(&returned_info),
-            "Component Information should have been stored in component_definitions"
+        assert_eq!(
+            0, returned_info.index,
+            "The first component should have index 0"
         );
-        let stored_definition = world.component_definitions.get(&returned_info).unwrap();
+        let stored = &world.registered_components[returned_info.index as usize];
         assert_eq!(
-            component_definition, *stored_definition,
+            component_definition, stored.definition,
             "Stored definition should be the same as the one added"
         );
-
-        assert!(
-            world
-                .component_names
-                .contains_key(&component_name.to_string()),
-            "component_names should have the passed name as a key"
+        assert_eq!(
+            returned_info, stored.info,
+            "Stored info should be the same as the one returned"
         );
-        let stored_info = world.component_names.get(component_name).unwrap();
+
         assert_eq!(
-            returned_info.hash, stored_info.hash,
-            "Hash of stored info should be the same as the one returned"
+            Some(&0),
+            world.component_indices.get(component_name),
+            "component_indices should map the passed name to the index of the component"
         );
 
-        assert!(
-            world.components.contains_key(component_name),
+        assert_eq!(
+            1,
+            world.components.len(),
             "A entry for the new component in components should have been added"
         );
 
@@ -738,70 +1957,529 @@ mod tests {
 
         let mut component_definition_2 = TestComponentDefinition::default();
 
-        component_definition_2.add_field(field_definition_2);
+        component_definition_2.add_field(field_definition_2);
+
+        let result =
+            world.register_component(format!("{}_", component_name), component_definition_2);
+        assert!(result.is_ok(), "Should have added a new component");
+
+        let info_2 = result.unwrap();
+        assert_ne!(
+            returned_info.hash, info_2.hash,
+            "Hash of different components should differ"
+        );
+    }
+
+    #[test]
+    pub fn register_component_does_not_allow_adding_of_a_component_with_a_name_that_already_exists()
+    {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        let field_definition = TestComponentFieldDefinition {
+            name: "Field".to_string(),
+            field_type: VariantType::Nil,
+        };
+
+        let mut component_definition = TestComponentDefinition::default();
+        component_definition.add_field(field_definition);
+
+        let field_definition_2 = TestComponentFieldDefinition {
+            name: "Field".to_string(),
+            field_type: VariantType::Int,
+        };
+
+        let mut component_definition_2 = TestComponentDefinition::default();
+        component_definition_2.add_field(field_definition_2);
+
+        world
+            .register_component("Test".to_string(), component_definition)
+            .unwrap();
+        let result = world.register_component("Test".to_string(), component_definition_2);
+        assert!(result.is_err())
+    }
+
+    #[test]
+    pub fn register_component_adds_a_component_with_an_existing_definition_under_a_different_name_with_a_unique_hash(
+    ) {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        let field_definition = TestComponentFieldDefinition {
+            name: "Field".to_string(),
+            field_type: VariantType::Nil,
+        };
+
+        let mut component_definition = TestComponentDefinition::default();
+        component_definition.add_field(field_definition);
+
+        let info_1 = world
+            .register_component("Test".to_string(), component_definition.clone())
+            .unwrap();
+        let info_2 = world
+            .register_component("Test2".to_string(), component_definition)
+            .unwrap();
+
+        assert_ne!(
+            info_1.hash, info_2.hash,
+            "Components with different names should have different hashes"
+        );
+    }
+
+    #[test]
+    pub fn registered_components_can_be_queried_by_name_and_info() {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        let mut component_definition = TestComponentDefinition::default();
+        component_definition.add_field(TestComponentFieldDefinition::create(
+            "Field".to_string(),
+            VariantType::Int,
+        ));
+
+        let info_b = world
+            .register_component("B".to_string(), component_definition.clone())
+            .unwrap();
+        let info_a = world
+            .register_component("A".to_string(), TestComponentDefinition::default())
+            .unwrap();
+
+        assert_eq!(
+            vec!["A".to_string(), "B".to_string()],
+            world.get_component_names()
+        );
+        assert_eq!(Some(info_b), world.get_component_info("B".to_string()));
+        assert_eq!(None, world.get_component_info("C".to_string()));
+        assert_eq!(Some("A".to_string()), world.get_component_name(&info_a));
+        assert_eq!(
+            Some(&component_definition),
+            world.get_component_definition("B".to_string())
+        );
+        assert_eq!(None, world.get_component_definition("C".to_string()));
+        assert_eq!(
+            Some(&component_definition),
+            world.get_component_definition_by_info(&info_b)
+        );
+        assert_eq!(
+            None,
+            world.get_component_name(&TestComponentInfo::create(0, 0, 0))
+        );
+    }
+
+    #[test]
+    fn components_can_be_accessed_by_their_info() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        let info = world.get_component_info("Test").unwrap();
+        let entity_id = *world.create_entity();
+        let mut data = TestComponentData::new(entity_id);
+        data.set_field("Field", &TestComponentValue::Int(3));
+
+        world.set_component_data(&entity_id, info, &data).unwrap();
+
+        assert!(world.has_component(info));
+        assert!(world.is_component_added_to_entity(&entity_id, info));
+        assert_eq!(
+            TestComponentValue::Int(3),
+            *world
+                .get_component_of_entity(&entity_id, info)
+                .unwrap()
+                .get_field("Field")
+        );
+        assert_eq!(1, world.get_component_data(info).unwrap().len());
+        world
+            .remove_component_from_entity(&entity_id, info)
+            .unwrap();
+        assert!(!world.is_component_added_to_entity(&entity_id, "Test"));
+    }
+
+    #[test]
+    fn data_of_a_component_is_stored_by_the_index_of_its_fields() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+        world.register_entity(&first).unwrap();
+        world.register_entity(&second).unwrap();
+        let mut data = TestComponentData::new(first);
+        data.set_field("Unknown", &TestComponentValue::Int(1));
+        data.set_field("Field", &TestComponentValue::Int(3));
+
+        world.set_component_data(&first, "Test", &data).unwrap();
+        world
+            .set_component_data(&second, "Test", &TestComponentData::new(second))
+            .unwrap();
+
+        let first_data = world.get_component_of_entity(&first, "Test").unwrap();
+        let second_data = world.get_component_of_entity(&second, "Test").unwrap();
+        assert_eq!(Some(0), first_data.get_layout().get_index("Field"));
+        assert_eq!(None, first_data.get_layout().get_index("Unknown"));
+        assert_eq!(TestComponentValue::Int(3), *first_data.get_field_at(0));
+        assert_eq!(TestComponentValue::Nil, *second_data.get_field_at(0));
+        assert_eq!(first_data.get_layout(), second_data.get_layout());
+    }
+
+    #[test]
+    fn columns_follow_the_component_data_and_write_changes_back() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+        world.register_entity(&first).unwrap();
+        world.register_entity(&second).unwrap();
+        set_integer(&mut world, &first, "Test", "Field", 1);
+        assert_eq!(
+            Err(ColumnsError::ColumnsDisabled {
+                component: "Test".to_string()
+            }),
+            world.get_columns("Test").map(|_| ())
+        );
+
+        world.enable_columns("Test").unwrap();
+        set_integer(&mut world, &second, "Test", "Field", 2);
+        assert_eq!(
+            Some(&[1, 2][..]),
+            world.get_columns("Test").unwrap().get_ints("Field")
+        );
+
+        world.enable_journal();
+        world
+            .update_columns("Test", |columns| {
+                for value in columns.get_ints_mut("Field").unwrap() {
+                    *value *= 10;
+                }
+            })
+            .unwrap();
+        assert_eq!(
+            TestComponentValue::Int(20),
+            *world
+                .get_component_of_entity(&second, "Test")
+                .unwrap()
+                .get_field("Field")
+        );
+
+        assert_eq!(Some("update_columns".to_string()), world.get_undo_name());
+
+        world.undo().unwrap();
+        assert_eq!(1, get_integer(&world, &first, "Test", "Field"));
+        assert_eq!(2, get_integer(&world, &second, "Test", "Field"));
+        world.remove_component_from_entity(&first, "Test").unwrap();
+        assert_eq!(
+            Some(&[2][..]),
+            world.get_columns("Test").unwrap().get_ints("Field")
+        );
+        assert_eq!(&[second], world.get_columns("Test").unwrap().get_entities());
+    }
+
+    /// Time per call of the entity lookups of the world API in a world that already has
+    /// `entity_count` entities, the fastest of a few runs.
+    fn time_entity_lookups(entity_count: u128) -> Duration {
+        const CALLS: u128 = 1_000;
+        let mut world = DefaultECSWorld::default();
+        let mut definition = DefaultComponentDefinition::default();
+        definition.add_field(DefaultComponentFieldDefinition {
+            name: "Field".to_string(),
+            field_type: VariantType::Int,
+        });
+        world
+            .register_component("Test".to_string(), definition)
+            .unwrap();
+        for id in 0..entity_count {
+            world
+                .register_entity(&DefaultEntityId::from_u128(id))
+                .unwrap();
+        }
+
+        (0..3)
+            .map(|run| {
+                let first = entity_count + run * CALLS;
+                let start = Instant::now();
+                for id in first..first + CALLS {
+                    let entity = DefaultEntityId::from_u128(id);
+                    world.register_entity(&entity).unwrap();
+                    let data = DefaultComponentData::new(entity);
+                    world.set_component_data(&entity, "Test", &data).unwrap();
+                    world.get_components_of_entity(&entity).unwrap();
+                    world.get_component_of_entity(&entity, "Test").unwrap();
+                }
+                start.elapsed() / CALLS as u32
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    #[ignore = "measures wall-clock time, run with --ignored on an idle machine"]
+    fn entity_lookups_take_the_same_time_with_100k_entities() {
+        let small = time_entity_lookups(1_000);
+        let large = time_entity_lookups(100_000);
+        assert!(
+            large < small * 10,
+            "{:?} per call with 100k entities, {:?} with 1k entities",
+            large,
+            small
+        );
+    }
+
+    #[test]
+    fn batches_report_errors_per_entity_and_are_undone_as_one_group() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        world.enable_journal();
+        let mut data = TestComponentData::new(TestEntityId::default());
+        data.set_field("Field", &TestComponentValue::Int(5));
+
+        assert_eq!(
+            Err(WorldError::SpawnEntity(
+                SpawnEntityError::ComponentNotFound {
+                    component: "Unknown".to_string()
+                }
+            )),
+            world.spawn_batch(2, "Unknown", &data)
+        );
+        let entities = world.spawn_batch(3, "Test", &data).unwrap();
+        assert_eq!(3, entities.len());
+        assert_eq!(Some("spawn_batch".to_string()), world.get_undo_name());
+        assert_eq!(5, get_integer(&world, &entities[2], "Test", "Field"));
+
+        data.set_field("Field", &TestComponentValue::Int(7));
+        assert_eq!(
+            Err(BatchError::LengthMismatch {
+                entities: 2,
+                data: 1
+            }),
+            world.set_component_data_batch(&entities[..2], "Test", &[data.clone()])
+        );
+        let missing = TestEntityId { id: 100 };
+        let results = world
+            .set_component_data_batch(
+                &[entities[0], missing, entities[1]],
+                "Test",
+                &[data.clone(), data.clone(), data.clone()],
+            )
+            .unwrap();
+        assert_eq!(
+            vec![
+                Ok(()),
+                Err(EntityNotFound {
+                    entity: missing.as_string()
+                }),
+                Ok(())
+            ],
+            results
+        );
+        assert_eq!(7, get_integer(&world, &entities[1], "Test", "Field"));
+
+        world.undo().unwrap();
+        assert_eq!(5, get_integer(&world, &entities[0], "Test", "Field"));
+        assert_eq!(5, get_integer(&world, &entities[1], "Test", "Field"));
+        world.undo().unwrap();
+        assert!(world.get_entities().next().is_none());
+    }
+
+    #[test]
+    fn spawn_batch_skips_ids_that_are_taken() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        world.enable_journal();
+        let data = TestComponentData::new(TestEntityId::default());
+        let taken = TestEntityId { id: 2 };
+        world.register_entity(&taken).unwrap();
+
+        let entities = world.spawn_batch(3, "Test", &data).unwrap();
+        assert_eq!(
+            vec![
+                TestEntityId { id: 1 },
+                TestEntityId { id: 3 },
+                TestEntityId { id: 4 }
+            ],
+            entities
+        );
+        assert_eq!(4, world.get_entities().count());
+        world.undo().unwrap();
+        assert_eq!(
+            vec![taken],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn generated_ids_skip_pooled_entities() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        let entities = world
+            .spawn_batch(2, "Test", &TestComponentData::default())
+            .unwrap();
+        world.release_entity(&entities[1]).unwrap();
+
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        assert_eq!(TestEntityId { id: 3 }, *world.create_entity());
+        assert_eq!(1, world.get_pooled_entity_count());
+        assert_eq!(Ok(entities[1]), world.spawn_entity(["Test"]));
+        assert_eq!(Ok(TestEntityId { id: 4 }), world.spawn_entity(["Test"]));
+        assert_eq!(4, world.get_entities().count());
+    }
+
+    #[test]
+    fn spawn_batch_reuses_released_entities() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        let mut data = TestComponentData::new(TestEntityId::default());
+        let entities = world.spawn_batch(2, "Test", &data).unwrap();
+        world.release_entity(&entities[1]).unwrap();
+
+        data.set_field("Field", &TestComponentValue::Int(3));
+        let spawned = world.spawn_batch(2, "Test", &data).unwrap();
+        assert_eq!(entities[1], spawned[0]);
+        assert_eq!(0, world.get_pooled_entity_count());
+        assert_eq!(Some(1), world.get_entity_generation(&spawned[0]));
+        assert_eq!(3, get_integer(&world, &spawned[0], "Test", "Field"));
+        assert_eq!(3, get_integer(&world, &spawned[1], "Test", "Field"));
+    }
+
+    #[test]
+    fn disabled_entities_and_components_are_skipped_and_undo_restores_their_state() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        let data = TestComponentData::new(TestEntityId::default());
+        let entities = world.spawn_batch(3, "Test", &data).unwrap();
+        world.enable_columns("Test").unwrap();
+        world.enable_journal();
+
+        world.set_entity_enabled(&entities[0], false).unwrap();
+        world
+            .set_component_enabled(&entities[1], "Test", false)
+            .unwrap();
+        assert!(!world.is_entity_enabled(&entities[0]));
+        assert!(world.is_component_enabled(&entities[0], "Test"));
+        assert!(!world.is_component_enabled(&entities[1], "Test"));
+        assert_eq!(
+            Err(SetEnabledError::ComponentNotInEntity {
+                entity: entities[2].as_string(),
+                component: "Unknown".to_string()
+            }),
+            world.set_component_enabled(&entities[2], "Unknown", false)
+        );
+        let enabled: Vec<TestEntityId> = world
+            .get_component_data("Test")
+            .unwrap()
+            .iter()
+            .map(|data| data.get_entity())
+            .collect();
+        assert_eq!(vec![entities[2]], enabled);
+        assert_eq!(
+            3,
+            world
+                .get_component_data_including_disabled("Test")
+                .unwrap()
+                .len()
+        );
+        assert_eq!(
+            &[false, false, true],
+            world.get_columns("Test").unwrap().get_enabled()
+        );
+
+        world.despawn_entity(&entities[0]).unwrap();
+        world.undo().unwrap();
+        assert!(!world.is_entity_enabled(&entities[0]));
+        world.undo().unwrap();
+        world.undo().unwrap();
+        assert!(world.is_entity_enabled(&entities[0]));
+        assert!(world.is_component_enabled(&entities[1], "Test"));
+        assert_eq!(3, world.get_component_data("Test").unwrap().len());
+    }
 
-        let result =
-            world.register_component(format!("{}_", component_name), component_definition_2);
-        assert!(result.is_ok(), "Should have added a new component");
+    #[test]
+    fn released_entities_are_recycled_with_their_data_and_a_new_generation() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        assert_eq!(
+            Err(SpawnEntityError::ComponentNotFound {
+                component: "Unknown".to_string()
+            }),
+            world.spawn_entity(["Unknown"])
+        );
+        let entity = world.spawn_entity(["Test"]).unwrap();
+        let mut data = TestComponentData::new(entity);
+        data.set_field("Field", &TestComponentValue::Int(5));
+        world.set_component_data(&entity, "Test", &data).unwrap();
+        let allocation = world.components_of_entity[&entity][&0].clone();
+        world.enable_journal();
+
+        world.release_entity(&entity).unwrap();
+        assert!(world.get_entities().next().is_none());
+        assert!(world.get_component_data("Test").unwrap().is_empty());
+        assert_eq!(None, world.get_entity_generation(&entity));
+        assert_eq!(
+            Err(RegisterEntityError::AlreadyRegistered {
+                entity: entity.as_string()
+            }),
+            world.register_entity(&entity)
+        );
 
-        let info_2 = result.unwrap();
-        assert_ne!(
-            returned_info.hash, info_2.hash,
-            "Hash of different components should differ"
+        assert_eq!(Ok(entity), world.spawn_entity(["Test"]));
+        assert_eq!(0, world.get_pooled_entity_count());
+        assert!(Rc::ptr_eq(
+            &allocation,
+            &world.components_of_entity[&entity][&0]
+        ));
+        assert_eq!(
+            TestComponentValue::Nil,
+            *world
+                .get_component_of_entity(&entity, "Test")
+                .unwrap()
+                .get_field("Field")
         );
+        assert!(!world.is_entity_current(&entity, 0));
+        assert!(world.is_entity_current(&entity, 1));
+
+        world.undo().unwrap();
+        assert_eq!(1, world.get_pooled_entity_count());
+        world.undo().unwrap();
+        assert!(world.is_entity_current(&entity, 0));
+        assert_eq!(5, get_integer(&world, &entity, "Test", "Field"));
+        world.redo().unwrap();
+        world.redo().unwrap();
+        assert_eq!(Some(1), world.get_entity_generation(&entity));
+        assert_ne!(entity, world.spawn_entity(["Test"]).unwrap());
     }
 
     #[test]
-    pub fn register_component_does_not_allow_adding_of_a_component_with_a_name_that_already_exists()
-    {
-        let mut world =
-            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
-        let field_definition = TestComponentFieldDefinition {
-            name: "Field".to_string(),
-            field_type: VariantType::Nil,
-        };
-
-        let mut component_definition = TestComponentDefinition::default();
-        component_definition.add_field(field_definition);
-
-        let field_definition_2 = TestComponentFieldDefinition {
-            name: "Field".to_string(),
-            field_type: VariantType::Int,
-        };
-
-        let mut component_definition_2 = TestComponentDefinition::default();
-        component_definition_2.add_field(field_definition_2);
+    fn references_to_released_entities_follow_the_policies_and_are_stale_after_reuse() {
+        let mut world = create_world_with_reference_component();
+        let weapon = *world.create_entity();
+        let bullet = world.spawn_entity(["Test"]).unwrap();
+        set_target(&mut world, &weapon, &bullet);
+        let captured = get_target(&world, &weapon).unwrap();
+        let generation = world.get_entity_generation(&captured).unwrap();
+        world.enable_journal();
+
+        world.release_entity(&bullet).unwrap();
+        assert_eq!(None, get_target(&world, &weapon));
+        assert_eq!(Ok(bullet), world.spawn_entity(["Test"]));
+        assert!(!world.is_entity_current(&captured, generation));
+
+        world.undo().unwrap();
+        world.undo().unwrap();
+        assert_eq!(Some(bullet), get_target(&world, &weapon));
+        assert!(world.is_entity_current(&captured, generation));
 
         world
-            .register_component("Test".to_string(), component_definition)
+            .set_component_entity_reference_policy("Target", EntityReferencePolicy::DespawnEntity)
             .unwrap();
-        let result = world.register_component("Test".to_string(), component_definition_2);
-        assert!(result.is_err())
+        world.release_entity(&bullet).unwrap();
+        assert!(world.get_entities().next().is_none());
+        assert_eq!(Some("release_entity".to_string()), world.get_undo_name());
     }
 
     #[test]
-    pub fn register_component_adds_a_component_with_an_existing_definition_under_a_different_name_with_a_unique_hash(
-    ) {
-        let mut world =
-            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
-        let field_definition = TestComponentFieldDefinition {
-            name: "Field".to_string(),
-            field_type: VariantType::Nil,
-        };
-
-        let mut component_definition = TestComponentDefinition::default();
-        component_definition.add_field(field_definition);
-
-        let info_1 = world
-            .register_component("Test".to_string(), component_definition.clone())
-            .unwrap();
-        let info_2 = world
-            .register_component("Test2".to_string(), component_definition)
-            .unwrap();
+    fn component_infos_of_another_world_are_rejected() {
+        let mut world = create_world_with_integer_component("Test", "Field");
+        let other_world = create_world_with_integer_component("Test", "Field");
+        let other_info = other_world.get_component_info("Test").unwrap();
+        let entity_id = *world.create_entity();
 
-        assert_ne!(
-            info_1.hash, info_2.hash,
-            "Components with different names should have different hashes"
+        assert!(!world.has_component(other_info));
+        assert_eq!(None, world.get_component_name(&other_info));
+        assert_eq!(
+            Err(SetComponentDataError::ComponentNotFound {
+                component: format!("#{:016x}", other_info.hash)
+            }),
+            world.set_component_data(&entity_id, other_info, &TestComponentData::new(entity_id))
         );
     }
 
@@ -810,13 +2488,33 @@ mod tests {
         let mut world =
             ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
 
-        let mut uuid_1 = *world.create_entity();
-        uuid_1.id = 1;
+        let uuid_1 = *world.create_entity();
         let uuid_2 = *world.create_entity();
 
         assert_ne!(uuid_1, uuid_2, "Should have created unique entities");
     }
 
+    #[test]
+    pub fn create_entity_uses_the_id_generation_of_the_world() {
+        let mut world = ecs_world::create_ecs_world::<
+            TestComponentDefinition,
+            TestComponentData,
+            TestComponentInfo,
+        >(EntityIdGeneration::Sequential);
+
+        assert_eq!(TestEntityId { id: 1 }, *world.create_entity());
+        assert_eq!(TestEntityId { id: 2 }, *world.create_entity());
+
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        assert_eq!(TestEntityId { id: 3 }, *world.create_entity());
+
+        world.set_entity_id_generation(EntityIdGeneration::Seeded(7));
+        let seeded = *world.create_entity();
+        world.set_entity_id_generation(EntityIdGeneration::Seeded(7));
+        assert_ne!(seeded, *world.create_entity());
+        assert_eq!(5, world.get_entities().count());
+    }
+
     #[test]
     pub fn add_component_to_entity_adds_a_new_component_to_an_entity() {
         let mut world =
@@ -841,10 +2539,10 @@ mod tests {
         assert!(result.is_ok(), "Result should have been Ok");
         drop(result);
 
-        let components = world.components.get(component_name).unwrap();
+        let components = &world.components[0];
         assert_eq!(
             1,
-            components.len(),
+            components.iter().count(),
             "Should have added a component to components"
         );
 
@@ -934,7 +2632,7 @@ mod tests {
 
         let mut data = TestComponentData::new(entity_id);
         let value = 2;
-        data.set_field(field_name.to_string(), &TestComponentValue::Int(value));
+        data.set_field(field_name, &TestComponentValue::Int(value));
 
         assert!(
             world
@@ -947,7 +2645,7 @@ mod tests {
             .get_component_of_entity(&entity_id, component_name.to_string())
             .unwrap();
 
-        let field_data = stored_data.get_field(field_name.to_string());
+        let field_data = stored_data.get_field(field_name);
         assert_eq!(
             TestComponentValue::Int(value),
             *field_data,
@@ -958,7 +2656,7 @@ mod tests {
             .get_component_data(component_name.to_string())
             .unwrap();
         let stored_data = components.first().unwrap();
-        let field_data = stored_data.get_field(field_name.to_string());
+        let field_data = stored_data.get_field(field_name);
         assert_eq!(
             TestComponentValue::Int(value),
             *field_data,
@@ -974,7 +2672,9 @@ mod tests {
         let data = ComponentData::new(entity_id);
 
         assert_eq!(
-            Err(EntityNotFound),
+            Err(EntityNotFound {
+                entity: "0".to_string()
+            }),
             world.set_component_data(&entity_id, "Test".to_string(), &data)
         );
     }
@@ -987,7 +2687,9 @@ mod tests {
         let entity_id = *world.create_entity();
         let data = ComponentData::new(entity_id);
         assert_eq!(
-            Err(ComponentNotFound),
+            Err(ComponentNotFound {
+                component: "Test".to_string()
+            }),
             world.set_component_data(&entity_id, "Test".to_string(), &data)
         );
     }
@@ -1028,9 +2730,9 @@ mod tests {
 
         let mut data = TestComponentData::new(entity_id);
         let value = 2;
-        data.set_field(field_name.to_string(), &TestComponentValue::Int(value));
+        data.set_field(field_name, &TestComponentValue::Int(value));
 
-        assert!(data.fields.contains_key(field_name));
+        assert!(data.get_layout().get_index(field_name).is_some());
 
         let result = world.set_component_data(&entity_id, component_name.to_string(), &data);
         assert!(result.is_ok());
@@ -1039,7 +2741,7 @@ mod tests {
             .get_component_of_entity(&entity_id, component_name.to_string())
             .unwrap();
 
-        let field_data = stored_data.get_field(field_name.to_string());
+        let field_data = stored_data.get_field(field_name);
         assert_eq!(
             TestComponentValue::Int(value),
             *field_data,
@@ -1067,7 +2769,7 @@ mod tests {
         let entity_id = *world.create_entity();
         let mut data = TestComponentData::new(entity_id);
         let value = 2;
-        data.set_field(field_name.to_string(), &TestComponentValue::Int(value));
+        data.set_field(field_name, &TestComponentValue::Int(value));
 
         world
             .set_component_data(&entity_id, component_name.to_string(), &data)
@@ -1075,4 +2777,502 @@ mod tests {
 
         assert_eq!(1, world.components_of_entity.get(&entity_id).unwrap().len())
     }
+
+    fn create_world_with_integer_component(
+        component_name: &str,
+        field_name: &str,
+    ) -> ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo> {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        let mut component_definition = TestComponentDefinition::default();
+        component_definition.add_field(TestComponentFieldDefinition {
+            name: field_name.to_string(),
+            field_type: VariantType::Int,
+        });
+        world
+            .register_component(component_name.to_string(), component_definition)
+            .unwrap();
+        world
+    }
+
+    fn set_integer(
+        world: &mut ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo>,
+        entity_id: &TestEntityId,
+        component_name: &str,
+        field_name: &str,
+        value: i64,
+    ) {
+        let mut data = TestComponentData::new(*entity_id);
+        data.set_field(field_name, &TestComponentValue::Int(value));
+        world
+            .set_component_data(entity_id, component_name.to_string(), &data)
+            .unwrap();
+    }
+
+    fn get_integer(
+        world: &ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo>,
+        entity_id: &TestEntityId,
+        component_name: &str,
+        field_name: &str,
+    ) -> i64 {
+        world
+            .get_component_of_entity(entity_id, component_name.to_string())
+            .unwrap()
+            .get_field(field_name)
+            .get_int()
+    }
+
+    #[test]
+    pub fn remove_component_from_entity_removes_the_component_data() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        let entity_id = *world.create_entity();
+        set_integer(&mut world, &entity_id, "Test", "Integer", 1);
+
+        assert_eq!(
+            Ok(()),
+            world.remove_component_from_entity(&entity_id, "Test".to_string())
+        );
+        assert!(!world.is_component_added_to_entity(&entity_id, "Test".to_string()));
+        assert!(world
+            .get_component_data("Test".to_string())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            Err(RemoveComponentError::ComponentNotInEntity {
+                entity: entity_id.as_string(),
+                component: "Test".to_string()
+            }),
+            world.remove_component_from_entity(&entity_id, "Test".to_string())
+        );
+    }
+
+    #[test]
+    pub fn remove_component_from_entity_checks_that_entity_exists() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+
+        assert_eq!(
+            Err(RemoveComponentError::EntityNotFound {
+                entity: "0".to_string()
+            }),
+            world.remove_component_from_entity(&TestEntityId::create(), "Test".to_string())
+        );
+    }
+
+    #[test]
+    pub fn despawn_entity_removes_the_entity_and_its_components() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        let entity_id = *world.create_entity();
+        set_integer(&mut world, &entity_id, "Test", "Integer", 1);
+
+        assert_eq!(Ok(()), world.despawn_entity(&entity_id));
+        assert!(world.get_entities().next().is_none());
+        assert!(world
+            .get_component_data("Test".to_string())
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            Err(GetComponentOfEntityError::EntityNotFound {
+                entity: entity_id.as_string()
+            }),
+            world.get_components_of_entity(&entity_id)
+        );
+        assert_eq!(
+            Err(DespawnEntityError::EntityNotFound {
+                entity: entity_id.as_string()
+            }),
+            world.despawn_entity(&entity_id)
+        );
+    }
+
+    fn create_world_with_reference_component(
+    ) -> ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo> {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        world.set_entity_id_generation(EntityIdGeneration::Sequential);
+        let mut component_definition = TestComponentDefinition::default();
+        component_definition.add_field(TestComponentFieldDefinition {
+            name: "target".to_string(),
+            field_type: VariantType::Entity,
+        });
+        world
+            .register_component("Target".to_string(), component_definition)
+            .unwrap();
+        world
+    }
+
+    fn set_target(
+        world: &mut ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo>,
+        entity_id: &TestEntityId,
+        target: &TestEntityId,
+    ) {
+        let mut data = TestComponentData::new(*entity_id);
+        data.set_field("target", &TestComponentValue::Entity(*target));
+        world
+            .set_component_data(entity_id, "Target".to_string(), &data)
+            .unwrap();
+    }
+
+    fn get_target(
+        world: &ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo>,
+        entity_id: &TestEntityId,
+    ) -> Option<TestEntityId> {
+        world
+            .get_component_of_entity(entity_id, "Target".to_string())
+            .unwrap()
+            .get_field("target")
+            .get_entity()
+    }
+
+    #[test]
+    pub fn despawn_entity_sets_references_to_nil_by_default() {
+        let mut world = create_world_with_reference_component();
+        let target = *world.create_entity();
+        let entity_id = *world.create_entity();
+        set_target(&mut world, &entity_id, &target);
+
+        world.despawn_entity(&target).unwrap();
+
+        assert_eq!(None, get_target(&world, &entity_id));
+        assert_eq!(
+            VariantType::Nil,
+            world
+                .get_component_of_entity(&entity_id, "Target".to_string())
+                .unwrap()
+                .get_field("target")
+                .get_type()
+        );
+    }
+
+    #[test]
+    pub fn despawn_entity_removes_referencing_components() {
+        let mut world = create_world_with_reference_component();
+        world
+            .set_component_entity_reference_policy(
+                "Target".to_string(),
+                EntityReferencePolicy::RemoveComponent,
+            )
+            .unwrap();
+        let target = *world.create_entity();
+        let entity_id = *world.create_entity();
+        set_target(&mut world, &entity_id, &target);
+        set_integer(&mut world, &entity_id, "Test", "Integer", 1);
+
+        world.despawn_entity(&target).unwrap();
+
+        assert_eq!(
+            Ok(vec!["Test".to_string()]),
+            world.get_components_of_entity(&entity_id)
+        );
+        assert_eq!(
+            Err(GetComponentDataError::ComponentNotFound {
+                component: "Unknown".to_string()
+            }),
+            world.set_component_entity_reference_policy(
+                "Unknown".to_string(),
+                EntityReferencePolicy::SetNil
+            )
+        );
+    }
+
+    #[test]
+    pub fn despawn_entity_despawns_referencing_entities_and_undo_restores_them() {
+        let mut world = create_world_with_reference_component();
+        world.set_entity_reference_policy(EntityReferencePolicy::DespawnEntity);
+        let owner = *world.create_entity();
+        let weapon = *world.create_entity();
+        let projectile = *world.create_entity();
+        let bystander = *world.create_entity();
+        set_target(&mut world, &weapon, &owner);
+        set_target(&mut world, &projectile, &weapon);
+        // A cycle back to the entity that is despawned first.
+        set_target(&mut world, &owner, &projectile);
+        world.enable_journal();
+
+        world.despawn_entity(&owner).unwrap();
+        assert_eq!(
+            vec![bystander],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+        assert!(world
+            .get_component_data("Target".to_string())
+            .unwrap()
+            .is_empty());
+
+        world.undo().unwrap();
+        assert_eq!(
+            vec![owner, weapon, projectile, bystander],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(owner), get_target(&world, &weapon));
+        assert_eq!(Some(projectile), get_target(&world, &owner));
+        assert_eq!(Err(JournalError::NothingToUndo), world.undo());
+    }
+
+    #[test]
+    pub fn remap_entity_references_points_references_at_the_new_entities() {
+        let mut world = create_world_with_reference_component();
+        let old_target = TestEntityId { id: 1 };
+        let new_target = TestEntityId { id: 2 };
+        let entity_id = TestEntityId { id: 3 };
+        world.register_entity(&new_target).unwrap();
+        world.register_entity(&entity_id).unwrap();
+        set_target(&mut world, &entity_id, &old_target);
+
+        world
+            .remap_entity_references(&HashMap::from([(old_target, new_target)]))
+            .unwrap();
+
+        assert_eq!(Some(new_target), get_target(&world, &entity_id));
+    }
+
+    #[test]
+    pub fn undo_fails_when_the_journal_is_not_enabled() {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        world.create_entity();
+
+        assert_eq!(Err(JournalError::JournalDisabled), world.undo());
+        assert_eq!(Err(JournalError::JournalDisabled), world.redo());
+    }
+
+    #[test]
+    pub fn undo_reverts_create_entity_and_redo_restores_it() {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        world.enable_journal();
+        let entity_id = *world.create_entity();
+
+        assert_eq!(Ok(()), world.undo());
+        assert!(world.get_entities().next().is_none());
+        assert_eq!(Err(JournalError::NothingToUndo), world.undo());
+
+        assert_eq!(Ok(()), world.redo());
+        assert_eq!(
+            vec![entity_id],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(Err(JournalError::NothingToRedo), world.redo());
+    }
+
+    #[test]
+    pub fn undo_restores_previous_component_data() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        let entity_id = *world.create_entity();
+        world.enable_journal();
+        set_integer(&mut world, &entity_id, "Test", "Integer", 1);
+        set_integer(&mut world, &entity_id, "Test", "Integer", 2);
+
+        world.undo().unwrap();
+        assert_eq!(1, get_integer(&world, &entity_id, "Test", "Integer"));
+
+        world.undo().unwrap();
+        assert!(
+            !world.is_component_added_to_entity(&entity_id, "Test".to_string()),
+            "Undoing the first set_component_data should remove the component again"
+        );
+
+        world.redo().unwrap();
+        world.redo().unwrap();
+        assert_eq!(2, get_integer(&world, &entity_id, "Test", "Integer"));
+    }
+
+    #[test]
+    pub fn undo_restores_removed_components_and_despawned_entities() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        world.enable_journal();
+        let entity_id = *world.create_entity();
+        set_integer(&mut world, &entity_id, "Test", "Integer", 7);
+        world
+            .remove_component_from_entity(&entity_id, "Test".to_string())
+            .unwrap();
+
+        world.undo().unwrap();
+        assert_eq!(7, get_integer(&world, &entity_id, "Test", "Integer"));
+
+        world.despawn_entity(&entity_id).unwrap();
+        world.undo().unwrap();
+        assert_eq!(
+            vec![entity_id],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(7, get_integer(&world, &entity_id, "Test", "Integer"));
+        assert_eq!(
+            1,
+            world.get_component_data("Test".to_string()).unwrap().len()
+        );
+
+        world.redo().unwrap();
+        assert!(world.get_entities().next().is_none());
+    }
+
+    #[test]
+    pub fn journal_groups_are_undone_and_redone_as_a_whole() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+        world.register_entity(&first).unwrap();
+        world.register_entity(&second).unwrap();
+        world.enable_journal();
+
+        world
+            .begin_journal_group("Move entities".to_string())
+            .unwrap();
+        set_integer(&mut world, &first, "Test", "Integer", 10);
+        world.begin_journal_group("Nested".to_string()).unwrap();
+        set_integer(&mut world, &second, "Test", "Integer", 20);
+        world.end_journal_group().unwrap();
+        assert_eq!(
+            Err(JournalError::GroupOpen),
+            world.undo(),
+            "Undo should not be possible while a group is open"
+        );
+        world.end_journal_group().unwrap();
+        assert_eq!(Err(JournalError::NoOpenGroup), world.end_journal_group());
+
+        assert_eq!(Some("Move entities".to_string()), world.get_undo_name());
+        world.undo().unwrap();
+        assert!(world
+            .get_component_data("Test".to_string())
+            .unwrap()
+            .is_empty());
+
+        assert_eq!(Some("Move entities".to_string()), world.get_redo_name());
+        world.redo().unwrap();
+        assert_eq!(10, get_integer(&world, &first, "Test", "Integer"));
+        assert_eq!(20, get_integer(&world, &second, "Test", "Integer"));
+    }
+
+    #[test]
+    pub fn new_mutations_clear_the_redo_history() {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        world.enable_journal();
+        world.register_entity(&TestEntityId { id: 1 }).unwrap();
+        world.undo().unwrap();
+        world.register_entity(&TestEntityId { id: 2 }).unwrap();
+
+        assert_eq!(Err(JournalError::NothingToRedo), world.redo());
+    }
+
+    #[test]
+    pub fn transaction_keeps_all_changes_when_it_returns_ok() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+
+        let result: Result<(), SetComponentDataError> = world.transaction(|tx| {
+            tx.register_entity(&first).unwrap();
+            tx.register_entity(&second).unwrap();
+            set_integer(tx, &first, "Test", "Integer", 1);
+            set_integer(tx, &second, "Test", "Integer", 2);
+            Ok(())
+        });
+
+        assert_eq!(Ok(()), result);
+        assert_eq!(
+            vec![first, second],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(1, get_integer(&world, &first, "Test", "Integer"));
+        assert_eq!(2, get_integer(&world, &second, "Test", "Integer"));
+    }
+
+    #[test]
+    pub fn transaction_rolls_back_all_changes_when_it_returns_err() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+        world.register_entity(&first).unwrap();
+        world.register_entity(&second).unwrap();
+        set_integer(&mut world, &first, "Test", "Integer", 1);
+
+        let result = world.transaction(|tx| {
+            let mut data = TestComponentData::new(first);
+            data.set_field("Integer", &TestComponentValue::Int(10));
+            tx.set_component_data(&first, "Test".to_string(), &data)?;
+            tx.set_component_data(&second, "Test".to_string(), &data)?;
+            tx.despawn_entity(&first).unwrap();
+            tx.set_component_data(&TestEntityId { id: 3 }, "Test".to_string(), &data)?;
+            Ok(())
+        });
+
+        assert_eq!(
+            Err(EntityNotFound {
+                entity: "3".to_string()
+            }),
+            result
+        );
+        assert_eq!(
+            vec![first, second],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(1, get_integer(&world, &first, "Test", "Integer"));
+        assert!(!world.is_component_added_to_entity(&second, "Test".to_string()));
+        assert_eq!(
+            1,
+            world.get_component_data("Test".to_string()).unwrap().len()
+        );
+    }
+
+    #[test]
+    pub fn nested_transaction_rolls_back_only_its_own_changes() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+
+        let result: Result<(), RegisterEntityError> = world.transaction(|tx| {
+            tx.register_entity(&first)?;
+            let inner = tx.transaction(|tx| {
+                tx.register_entity(&second)?;
+                tx.register_entity(&first)
+            });
+            assert_eq!(
+                Err(RegisterEntityError::AlreadyRegistered {
+                    entity: "1".to_string()
+                }),
+                inner
+            );
+            Ok(())
+        });
+
+        assert_eq!(Ok(()), result);
+        assert_eq!(
+            vec![first],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    pub fn transaction_is_undone_as_a_single_journal_group() {
+        let mut world = create_world_with_integer_component("Test", "Integer");
+        world.enable_journal();
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+
+        world
+            .transaction(|tx| {
+                tx.register_entity(&first)?;
+                tx.register_entity(&second)
+            })
+            .unwrap();
+        assert_eq!(Some("transaction".to_string()), world.get_undo_name());
+
+        world.undo().unwrap();
+        assert!(world.get_entities().next().is_none());
+        assert_eq!(Err(JournalError::NothingToUndo), world.undo());
+    }
+
+    #[test]
+    pub fn rolled_back_transaction_is_not_recorded_in_the_journal() {
+        let mut world =
+            ECSWorld::<TestComponentDefinition, TestComponentData, TestComponentInfo>::default();
+        world.enable_journal();
+
+        let result = world.transaction(|tx| {
+            tx.register_entity(&TestEntityId { id: 1 })?;
+            tx.register_entity(&TestEntityId { id: 1 })
+        });
+
+        assert!(result.is_err());
+        assert_eq!(Err(JournalError::NothingToUndo), world.undo());
+    }
 }
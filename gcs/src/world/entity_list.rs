@@ -0,0 +1,175 @@
+use crate::entity::EntityId;
+use std::collections::HashMap;
+
+struct Node<TEntityId: EntityId> {
+    entity: TEntityId,
+    previous: Option<usize>,
+    next: Option<usize>,
+}
+
+/// The entities of a world in the order they were added, with constant time lookup, insertion
+/// and removal. The entities are linked in order through the slots of `nodes`, slots of removed
+/// entities are reused.
+#[derive(Default)]
+pub(crate) struct EntityList<TEntityId: EntityId> {
+    nodes: Vec<Node<TEntityId>>,
+    slots: HashMap<TEntityId, usize>,
+    free: Vec<usize>,
+    first: Option<usize>,
+    last: Option<usize>,
+}
+
+impl<TEntityId: EntityId> EntityList<TEntityId> {
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &TEntityId> + '_ {
+        std::iter::successors(self.first, |slot| self.nodes[*slot].next)
+            .map(|slot| &self.nodes[slot].entity)
+    }
+
+    pub(crate) fn contains(&self, entity: &TEntityId) -> bool {
+        self.slots.contains_key(entity)
+    }
+
+    /// The entity right before `entity`, `None` if it is the first one or not part of the list.
+    pub(crate) fn get_previous(&self, entity: &TEntityId) -> Option<TEntityId> {
+        let previous = self.nodes[*self.slots.get(entity)?].previous?;
+        Some(self.nodes[previous].entity)
+    }
+
+    /// Returns false if the entity is already part of the list.
+    pub(crate) fn push(&mut self, entity: TEntityId) -> bool {
+        let last = self.last.map(|slot| self.nodes[slot].entity);
+        self.insert_after(last.as_ref(), entity)
+    }
+
+    /// Inserts `entity` right after `previous`, which has to be part of the list, or first if
+    /// `previous` is `None`. Returns false if the entity is already part of the list.
+    pub(crate) fn insert_after(&mut self, previous: Option<&TEntityId>, entity: TEntityId) -> bool {
+        if self.contains(&entity) {
+            return false;
+        }
+        let previous = previous.map(|previous| self.slots[previous]);
+        let next = match previous {
+            Some(previous) => self.nodes[previous].next,
+            None => self.first,
+        };
+        let node = Node {
+            entity,
+            previous,
+            next,
+        };
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = node;
+                slot
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+        match previous {
+            Some(previous) => self.nodes[previous].next = Some(slot),
+            None => self.first = Some(slot),
+        }
+        match next {
+            Some(next) => self.nodes[next].previous = Some(slot),
+            None => self.last = Some(slot),
+        }
+        self.slots.insert(entity, slot);
+        true
+    }
+
+    /// Returns false if the entity was not part of the list.
+    pub(crate) fn remove(&mut self, entity: &TEntityId) -> bool {
+        let slot = match self.slots.remove(entity) {
+            None => return false,
+            Some(slot) => slot,
+        };
+        let (previous, next) = (self.nodes[slot].previous, self.nodes[slot].next);
+        match previous {
+            Some(previous) => self.nodes[previous].next = next,
+            None => self.first = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].previous = previous,
+            None => self.last = previous,
+        }
+        self.free.push(slot);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::entity_id::DefaultEntityId;
+    use crate::entity::EntityId;
+    use crate::world::ecs_world::tests::TestEntityId;
+    use crate::world::entity_list::EntityList;
+
+    type Links = Vec<(Option<usize>, Option<usize>)>;
+
+    fn collect(list: &EntityList<TestEntityId>) -> Vec<TestEntityId> {
+        list.iter().copied().collect()
+    }
+
+    fn get_links<TEntityId: EntityId>(list: &EntityList<TEntityId>) -> Links {
+        list.nodes
+            .iter()
+            .map(|node| (node.previous, node.next))
+            .collect()
+    }
+
+    fn count_changed_slots(before: &Links, after: &Links) -> usize {
+        before.iter().zip(after).filter(|(b, a)| b != a).count()
+            + before.len().abs_diff(after.len())
+    }
+
+    #[test]
+    fn order_follows_insertion_and_removal() {
+        let mut list = EntityList::default();
+        let entities: Vec<TestEntityId> = (0..4).map(|id| TestEntityId { id }).collect();
+        for entity in &entities {
+            assert!(list.push(*entity));
+        }
+        assert!(!list.push(entities[2]));
+
+        assert_eq!(Some(entities[0]), list.get_previous(&entities[1]));
+        assert!(list.remove(&entities[1]));
+        assert!(!list.remove(&entities[1]));
+        assert!(!list.contains(&entities[1]));
+        assert_eq!(Some(entities[0]), list.get_previous(&entities[2]));
+
+        assert!(list.remove(&entities[0]));
+        assert!(list.insert_after(None, entities[0]));
+        assert!(list.insert_after(Some(&entities[0]), entities[1]));
+        assert_eq!(entities, collect(&list));
+
+        assert!(list.remove(&entities[3]));
+        assert!(list.push(entities[3]));
+        assert_eq!(entities, collect(&list));
+        assert_eq!(None, list.get_previous(&entities[0]));
+    }
+
+    #[test]
+    fn removal_and_insertion_touch_the_same_number_of_slots_with_100k_entities() {
+        let mut list = EntityList::default();
+        let entities: Vec<DefaultEntityId> = (0..100_000).map(DefaultEntityId::from_u128).collect();
+        for entity in &entities {
+            assert!(list.push(*entity));
+        }
+
+        for index in [0, 50_000, 99_999] {
+            let entity = entities[index];
+            let previous = list.get_previous(&entity);
+            let before = get_links(&list);
+            assert!(list.remove(&entity));
+            assert!(count_changed_slots(&before, &get_links(&list)) <= 2);
+
+            let before = get_links(&list);
+            assert!(list.insert_after(previous.as_ref(), entity));
+            assert!(count_changed_slots(&before, &get_links(&list)) <= 3);
+            assert_eq!(entities.len(), list.nodes.len());
+        }
+        assert!(list.iter().eq(entities.iter()));
+    }
+}
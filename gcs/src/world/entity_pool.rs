@@ -0,0 +1,139 @@
+use crate::component::component_data::ComponentData;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// Component data a parked entity keeps allocated, by component index.
+pub(crate) type PooledComponents<TComponentData> = Vec<(usize, Rc<RefCell<TComponentData>>)>;
+
+type ParkedEntity<TComponentData> = (
+    <TComponentData as ComponentData>::EntityIdType,
+    PooledComponents<TComponentData>,
+);
+
+/// Released entities of a world, grouped by the sorted indices of their components so that
+/// spawning an entity with the same components can reuse one with all of its data. Also keeps
+/// the generation of every entity that was released at least once.
+pub(crate) struct EntityPool<TComponentData: ComponentData> {
+    parked: HashMap<Vec<usize>, Vec<ParkedEntity<TComponentData>>>,
+    archetypes: HashMap<TComponentData::EntityIdType, Vec<usize>>,
+    generations: HashMap<TComponentData::EntityIdType, u32>,
+}
+
+impl<TComponentData: ComponentData> Default for EntityPool<TComponentData> {
+    fn default() -> Self {
+        Self {
+            parked: HashMap::default(),
+            archetypes: HashMap::default(),
+            generations: HashMap::default(),
+        }
+    }
+}
+
+impl<TComponentData: ComponentData> EntityPool<TComponentData> {
+    pub(crate) fn len(&self) -> usize {
+        self.archetypes.len()
+    }
+
+    pub(crate) fn contains(&self, entity: &TComponentData::EntityIdType) -> bool {
+        self.archetypes.contains_key(entity)
+    }
+
+    /// 0 for entities that were never released.
+    pub(crate) fn get_generation(&self, entity: &TComponentData::EntityIdType) -> u32 {
+        self.generations.get(entity).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn set_generation(
+        &mut self,
+        entity: &TComponentData::EntityIdType,
+        generation: u32,
+    ) {
+        if generation == 0 {
+            self.generations.remove(entity);
+        } else {
+            self.generations.insert(*entity, generation);
+        }
+    }
+
+    /// `components` must be sorted by component index.
+    pub(crate) fn park(
+        &mut self,
+        entity: TComponentData::EntityIdType,
+        components: PooledComponents<TComponentData>,
+    ) {
+        let archetype: Vec<usize> = components.iter().map(|(index, _)| *index).collect();
+        self.archetypes.insert(entity, archetype.clone());
+        self.parked
+            .entry(archetype)
+            .or_default()
+            .push((entity, components));
+    }
+
+    /// The entity that was parked last with exactly the components of `archetype`.
+    pub(crate) fn take(&mut self, archetype: &[usize]) -> Option<ParkedEntity<TComponentData>> {
+        let (entity, components) = self.parked.get_mut(archetype)?.pop()?;
+        self.archetypes.remove(&entity);
+        Some((entity, components))
+    }
+
+    pub(crate) fn take_entity(
+        &mut self,
+        entity: &TComponentData::EntityIdType,
+    ) -> Option<PooledComponents<TComponentData>> {
+        let archetype = self.archetypes.remove(entity)?;
+        let parked = self.parked.get_mut(&archetype)?;
+        let position = parked.iter().rposition(|(parked, _)| parked == entity)?;
+        Some(parked.remove(position).1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::component_data::DefaultComponentData;
+    use crate::component::component_data::ComponentData;
+    use crate::world::ecs_world::tests::TestEntityId;
+    use crate::world::entity_pool::EntityPool;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type TestComponentData = DefaultComponentData<TestEntityId>;
+
+    fn create_components(
+        id: u8,
+        indices: &[usize],
+    ) -> Vec<(usize, Rc<RefCell<TestComponentData>>)> {
+        indices
+            .iter()
+            .map(|index| {
+                let data = TestComponentData::new(TestEntityId { id });
+                (*index, Rc::new(RefCell::new(data)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn entities_are_taken_back_by_their_components_or_their_id() {
+        let mut pool = EntityPool::<TestComponentData>::default();
+        let entities: Vec<TestEntityId> = (0..3).map(|id| TestEntityId { id }).collect();
+        pool.park(entities[0], create_components(0, &[0, 2]));
+        pool.park(entities[1], create_components(1, &[0]));
+        pool.park(entities[2], create_components(2, &[0, 2]));
+        assert_eq!(3, pool.len());
+
+        assert!(pool.take(&[2]).is_none());
+        let (entity, components) = pool.take(&[0, 2]).unwrap();
+        assert_eq!(entities[2], entity);
+        assert_eq!(2, components.len());
+        assert!(!pool.contains(&entities[2]));
+
+        assert_eq!(1, pool.take_entity(&entities[1]).unwrap().len());
+        assert!(pool.take_entity(&entities[1]).is_none());
+        assert_eq!(entities[0], pool.take(&[0, 2]).unwrap().0);
+        assert_eq!(0, pool.len());
+
+        assert_eq!(0, pool.get_generation(&entities[0]));
+        pool.set_generation(&entities[0], 2);
+        assert_eq!(2, pool.get_generation(&entities[0]));
+    }
+}
@@ -0,0 +1,31 @@
+/// What an `ECSWorld` does with a component whose `Entity` field points at an entity that is
+/// being despawned.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum EntityReferencePolicy {
+    /// Sets the field to nil.
+    #[default]
+    SetNil,
+    /// Removes the component from the referencing entity.
+    RemoveComponent,
+    /// Despawns the referencing entity as well, which applies the policies again.
+    DespawnEntity,
+}
+
+impl EntityReferencePolicy {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            EntityReferencePolicy::SetNil => "set_nil",
+            EntityReferencePolicy::RemoveComponent => "remove_component",
+            EntityReferencePolicy::DespawnEntity => "despawn_entity",
+        }
+    }
+
+    pub(crate) fn parse_str(input: &str) -> Result<Self, String> {
+        match input {
+            "set_nil" => Ok(EntityReferencePolicy::SetNil),
+            "remove_component" => Ok(EntityReferencePolicy::RemoveComponent),
+            "despawn_entity" => Ok(EntityReferencePolicy::DespawnEntity),
+            _ => Err(format!("'{}' is not an entity reference policy", input)),
+        }
+    }
+}
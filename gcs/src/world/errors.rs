@@ -1,77 +1,600 @@
+use std::error::Error;
 use std::fmt::{Display, Formatter};
 
+/// Category of an error, used by bindings to map errors to their own error codes.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ErrorKind {
+    NotFound,
+    AlreadyExists,
+    InUse,
+    Unavailable,
+    Io,
+    Parse,
+    InvalidData,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum RegisterComponentError {
+    AlreadyRegistered { component: String },
+}
+
+impl RegisterComponentError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::AlreadyRegistered { .. } => ErrorKind::AlreadyExists,
+        }
+    }
+}
+
+impl Display for RegisterComponentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AlreadyRegistered { component } => {
+                write!(f, "Component with name '{}' already registered", component)
+            }
+        }
+    }
+}
+
+impl Error for RegisterComponentError {}
+
 #[derive(PartialEq, Debug)]
 pub enum GetComponentOfEntityError {
-    EntityNotFound,
-    ComponentNotFound,
-    ComponentNotInEntity,
+    EntityNotFound { entity: String },
+    ComponentNotFound { component: String },
+    ComponentNotInEntity { entity: String, component: String },
+}
+
+impl GetComponentOfEntityError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::NotFound
+    }
 }
 
 impl Display for GetComponentOfEntityError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            GetComponentOfEntityError::EntityNotFound => {
-                write!(f, "Entity with that id was not found")
+            GetComponentOfEntityError::EntityNotFound { entity } => {
+                write!(f, "Entity '{}' was not found", entity)
             }
-            GetComponentOfEntityError::ComponentNotFound => {
-                write!(f, "Component with that name was not found")
+            GetComponentOfEntityError::ComponentNotFound { component } => {
+                write!(f, "Component '{}' was not found", component)
             }
-            GetComponentOfEntityError::ComponentNotInEntity => {
-                write!(f, "The entity does not have that component")
+            GetComponentOfEntityError::ComponentNotInEntity { entity, component } => {
+                write!(
+                    f,
+                    "Entity '{}' does not have component '{}'",
+                    entity, component
+                )
             }
         }
     }
 }
 
+impl Error for GetComponentOfEntityError {}
+
 #[derive(PartialEq, Debug)]
 pub enum GetComponentDataError {
-    ComponentNotFound,
+    ComponentNotFound { component: String },
+}
+
+impl GetComponentDataError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::NotFound
+    }
 }
 
 impl Display for GetComponentDataError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            GetComponentDataError::ComponentNotFound => {
-                write!(f, "Component with that name was not found")
+            GetComponentDataError::ComponentNotFound { component } => {
+                write!(f, "Component '{}' was not found", component)
             }
         }
     }
 }
 
+impl Error for GetComponentDataError {}
+
 #[derive(PartialEq, Debug)]
 pub enum SetComponentDataError {
-    EntityNotFound,
-    ComponentNotFound,
-    DataInUse,
+    EntityNotFound { entity: String },
+    ComponentNotFound { component: String },
+    DataInUse { entity: String, component: String },
+}
+
+impl SetComponentDataError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::EntityNotFound { .. } | Self::ComponentNotFound { .. } => ErrorKind::NotFound,
+            Self::DataInUse { .. } => ErrorKind::InUse,
+        }
+    }
 }
 
 impl Display for SetComponentDataError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::EntityNotFound => {
-                write!(f, "Entity with that id was not found")
+            Self::EntityNotFound { entity } => {
+                write!(f, "Entity '{}' was not found", entity)
             }
-            Self::ComponentNotFound => {
-                write!(f, "Component with that name is already registered")
+            Self::ComponentNotFound { component } => {
+                write!(f, "Component '{}' is not registered", component)
             }
-            Self::DataInUse => {
-                write!(f, "The data is already exclusively borrowed")
+            Self::DataInUse { entity, component } => {
+                write!(
+                    f,
+                    "The data of component '{}' of entity '{}' is already exclusively borrowed",
+                    component, entity
+                )
             }
         }
     }
 }
 
+impl Error for SetComponentDataError {}
+
 #[derive(PartialEq, Debug)]
 pub enum RegisterEntityError {
-    AlreadyRegistered,
+    AlreadyRegistered { entity: String },
+}
+
+impl RegisterEntityError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::AlreadyExists
+    }
 }
 
 impl Display for RegisterEntityError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::AlreadyRegistered => {
-                write!(f, "Entity with that id is already registered")
+            Self::AlreadyRegistered { entity } => {
+                write!(f, "Entity '{}' is already registered", entity)
+            }
+        }
+    }
+}
+
+impl Error for RegisterEntityError {}
+
+#[derive(PartialEq, Debug)]
+pub enum AddComponentError {
+    ComponentNotFound { component: String },
+    AlreadyAdded { entity: String, component: String },
+}
+
+impl AddComponentError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::ComponentNotFound { .. } => ErrorKind::NotFound,
+            Self::AlreadyAdded { .. } => ErrorKind::AlreadyExists,
+        }
+    }
+}
+
+impl Display for AddComponentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ComponentNotFound { component } => {
+                write!(f, "Component '{}' is not registered", component)
+            }
+            Self::AlreadyAdded { entity, component } => {
+                write!(
+                    f,
+                    "Component '{}' was already added to entity '{}'",
+                    component, entity
+                )
+            }
+        }
+    }
+}
+
+impl Error for AddComponentError {}
+
+#[derive(PartialEq, Debug)]
+pub enum RemoveComponentError {
+    EntityNotFound { entity: String },
+    ComponentNotInEntity { entity: String, component: String },
+}
+
+impl RemoveComponentError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::NotFound
+    }
+}
+
+impl Display for RemoveComponentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EntityNotFound { entity } => {
+                write!(f, "Entity '{}' was not found", entity)
+            }
+            Self::ComponentNotInEntity { entity, component } => {
+                write!(
+                    f,
+                    "Entity '{}' does not have component '{}'",
+                    entity, component
+                )
             }
         }
     }
 }
+
+impl Error for RemoveComponentError {}
+
+#[derive(PartialEq, Debug)]
+pub enum DespawnEntityError {
+    EntityNotFound { entity: String },
+}
+
+impl DespawnEntityError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::NotFound
+    }
+}
+
+impl Display for DespawnEntityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EntityNotFound { entity } => {
+                write!(f, "Entity '{}' was not found", entity)
+            }
+        }
+    }
+}
+
+impl Error for DespawnEntityError {}
+
+#[derive(PartialEq, Debug)]
+pub enum SetEnabledError {
+    EntityNotFound { entity: String },
+    ComponentNotInEntity { entity: String, component: String },
+}
+
+impl SetEnabledError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::NotFound
+    }
+}
+
+impl Display for SetEnabledError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EntityNotFound { entity } => {
+                write!(f, "Entity '{}' was not found", entity)
+            }
+            Self::ComponentNotInEntity { entity, component } => {
+                write!(
+                    f,
+                    "Entity '{}' does not have component '{}'",
+                    entity, component
+                )
+            }
+        }
+    }
+}
+
+impl Error for SetEnabledError {}
+
+#[derive(PartialEq, Debug)]
+pub enum SpawnEntityError {
+    ComponentNotFound { component: String },
+    EntityAlreadyExists { entity: String },
+}
+
+impl SpawnEntityError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::ComponentNotFound { .. } => ErrorKind::NotFound,
+            Self::EntityAlreadyExists { .. } => ErrorKind::AlreadyExists,
+        }
+    }
+}
+
+impl Display for SpawnEntityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ComponentNotFound { component } => {
+                write!(f, "Component '{}' was not found", component)
+            }
+            Self::EntityAlreadyExists { entity } => {
+                write!(f, "Entity '{}' already exists", entity)
+            }
+        }
+    }
+}
+
+impl Error for SpawnEntityError {}
+
+#[derive(PartialEq, Debug)]
+pub enum ReleaseEntityError {
+    EntityNotFound { entity: String },
+}
+
+impl ReleaseEntityError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::NotFound
+    }
+}
+
+impl Display for ReleaseEntityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::EntityNotFound { entity } => {
+                write!(f, "Entity '{}' was not found", entity)
+            }
+        }
+    }
+}
+
+impl Error for ReleaseEntityError {}
+
+#[derive(PartialEq, Debug)]
+pub enum BatchError {
+    LengthMismatch { entities: usize, data: usize },
+}
+
+impl BatchError {
+    pub fn get_kind(&self) -> ErrorKind {
+        ErrorKind::InvalidData
+    }
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LengthMismatch { entities, data } => {
+                write!(f, "{} entities were given, but data for {}", entities, data)
+            }
+        }
+    }
+}
+
+impl Error for BatchError {}
+
+#[derive(PartialEq, Debug)]
+pub enum ColumnsError {
+    ComponentNotFound { component: String },
+    ColumnsDisabled { component: String },
+    DataInUse { entity: String, component: String },
+}
+
+impl ColumnsError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::ComponentNotFound { .. } => ErrorKind::NotFound,
+            Self::ColumnsDisabled { .. } => ErrorKind::Unavailable,
+            Self::DataInUse { .. } => ErrorKind::InUse,
+        }
+    }
+}
+
+impl Display for ColumnsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ComponentNotFound { component } => {
+                write!(f, "Component '{}' was not found", component)
+            }
+            Self::ColumnsDisabled { component } => {
+                write!(f, "Component '{}' does not have columns enabled", component)
+            }
+            Self::DataInUse { entity, component } => {
+                write!(
+                    f,
+                    "Data of component '{}' of entity '{}' is in use",
+                    component, entity
+                )
+            }
+        }
+    }
+}
+
+impl Error for ColumnsError {}
+
+#[derive(PartialEq, Debug)]
+pub enum JournalError {
+    JournalDisabled,
+    NoOpenGroup,
+    GroupOpen,
+    NothingToUndo,
+    NothingToRedo,
+}
+
+impl JournalError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::JournalDisabled => ErrorKind::Unavailable,
+            Self::GroupOpen => ErrorKind::InUse,
+            Self::NoOpenGroup | Self::NothingToUndo | Self::NothingToRedo => ErrorKind::NotFound,
+        }
+    }
+}
+
+impl Display for JournalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::JournalDisabled => {
+                write!(f, "The journal is not enabled")
+            }
+            Self::NoOpenGroup => {
+                write!(f, "There is no open journal group")
+            }
+            Self::GroupOpen => {
+                write!(f, "A journal group is still open")
+            }
+            Self::NothingToUndo => {
+                write!(f, "There is nothing to undo")
+            }
+            Self::NothingToRedo => {
+                write!(f, "There is nothing to redo")
+            }
+        }
+    }
+}
+
+impl Error for JournalError {}
+
+#[derive(PartialEq, Debug)]
+pub enum ReplayError {
+    Io(String),
+    Parse {
+        line: usize,
+        message: String,
+    },
+    EventFailed {
+        frame: u64,
+        source: Box<WorldError>,
+    },
+    Diverged {
+        frame: u64,
+        expected: u64,
+        actual: u64,
+    },
+}
+
+impl ReplayError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::Io(_) => ErrorKind::Io,
+            Self::Parse { .. } => ErrorKind::Parse,
+            Self::EventFailed { .. } | Self::Diverged { .. } => ErrorKind::InvalidData,
+        }
+    }
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(message) => {
+                write!(f, "Could not access the replay log: {}", message)
+            }
+            Self::Parse { line, message } => {
+                write!(f, "Invalid replay log at line {}: {}", line, message)
+            }
+            Self::EventFailed { frame, source } => {
+                write!(f, "Replaying frame {} failed: {}", frame, source)
+            }
+            Self::Diverged {
+                frame,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Replay diverged at frame {}: expected checksum {:016x}, got {:016x}",
+                    frame, expected, actual
+                )
+            }
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::EventFailed { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Any error returned by the `ECSWorld` API.
+/// It is transparent: it displays as the wrapped error and has the same source.
+#[derive(PartialEq, Debug)]
+pub enum WorldError {
+    RegisterComponent(RegisterComponentError),
+    GetComponentOfEntity(GetComponentOfEntityError),
+    GetComponentData(GetComponentDataError),
+    SetComponentData(SetComponentDataError),
+    RegisterEntity(RegisterEntityError),
+    AddComponent(AddComponentError),
+    RemoveComponent(RemoveComponentError),
+    DespawnEntity(DespawnEntityError),
+    SetEnabled(SetEnabledError),
+    SpawnEntity(SpawnEntityError),
+    ReleaseEntity(ReleaseEntityError),
+    Batch(BatchError),
+    Columns(ColumnsError),
+    Journal(JournalError),
+    Replay(ReplayError),
+}
+
+impl WorldError {
+    pub fn get_kind(&self) -> ErrorKind {
+        match self {
+            Self::RegisterComponent(error) => error.get_kind(),
+            Self::GetComponentOfEntity(error) => error.get_kind(),
+            Self::GetComponentData(error) => error.get_kind(),
+            Self::SetComponentData(error) => error.get_kind(),
+            Self::RegisterEntity(error) => error.get_kind(),
+            Self::AddComponent(error) => error.get_kind(),
+            Self::RemoveComponent(error) => error.get_kind(),
+            Self::DespawnEntity(error) => error.get_kind(),
+            Self::SetEnabled(error) => error.get_kind(),
+            Self::SpawnEntity(error) => error.get_kind(),
+            Self::ReleaseEntity(error) => error.get_kind(),
+            Self::Batch(error) => error.get_kind(),
+            Self::Columns(error) => error.get_kind(),
+            Self::Journal(error) => error.get_kind(),
+            Self::Replay(error) => error.get_kind(),
+        }
+    }
+
+    fn get_error(&self) -> &(dyn Error + 'static) {
+        match self {
+            Self::RegisterComponent(error) => error,
+            Self::GetComponentOfEntity(error) => error,
+            Self::GetComponentData(error) => error,
+            Self::SetComponentData(error) => error,
+            Self::RegisterEntity(error) => error,
+            Self::AddComponent(error) => error,
+            Self::RemoveComponent(error) => error,
+            Self::DespawnEntity(error) => error,
+            Self::SetEnabled(error) => error,
+            Self::SpawnEntity(error) => error,
+            Self::ReleaseEntity(error) => error,
+            Self::Batch(error) => error,
+            Self::Columns(error) => error,
+            Self::Journal(error) => error,
+            Self::Replay(error) => error,
+        }
+    }
+}
+
+impl Display for WorldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_error())
+    }
+}
+
+impl Error for WorldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.get_error().source()
+    }
+}
+
+macro_rules! impl_from_for_world_error {
+    ($error:ident, $variant:ident) => {
+        impl From<$error> for WorldError {
+            fn from(error: $error) -> Self {
+                WorldError::$variant(error)
+            }
+        }
+    };
+}
+
+impl_from_for_world_error!(RegisterComponentError, RegisterComponent);
+impl_from_for_world_error!(GetComponentOfEntityError, GetComponentOfEntity);
+impl_from_for_world_error!(GetComponentDataError, GetComponentData);
+impl_from_for_world_error!(SetComponentDataError, SetComponentData);
+impl_from_for_world_error!(RegisterEntityError, RegisterEntity);
+impl_from_for_world_error!(AddComponentError, AddComponent);
+impl_from_for_world_error!(RemoveComponentError, RemoveComponent);
+impl_from_for_world_error!(DespawnEntityError, DespawnEntity);
+impl_from_for_world_error!(SetEnabledError, SetEnabled);
+impl_from_for_world_error!(SpawnEntityError, SpawnEntity);
+impl_from_for_world_error!(ReleaseEntityError, ReleaseEntity);
+impl_from_for_world_error!(BatchError, Batch);
+impl_from_for_world_error!(ColumnsError, Columns);
+impl_from_for_world_error!(JournalError, Journal);
+impl_from_for_world_error!(ReplayError, Replay);
@@ -0,0 +1,154 @@
+use crate::component::component_data::ComponentData;
+use crate::world::errors::JournalError;
+
+#[derive(Clone)]
+pub(crate) enum JournalEntry<TComponentData: ComponentData> {
+    EntityAdded(TComponentData::EntityIdType),
+    ComponentDataSet {
+        entity: TComponentData::EntityIdType,
+        component: usize,
+        previous: Option<TComponentData>,
+        data: TComponentData,
+    },
+    ComponentRemoved {
+        entity: TComponentData::EntityIdType,
+        component: usize,
+        data: TComponentData,
+        enabled: bool,
+    },
+    /// `components` holds the index, data and enabled state of every component of the entity.
+    EntityDespawned {
+        entity: TComponentData::EntityIdType,
+        previous: Option<TComponentData::EntityIdType>,
+        enabled: bool,
+        components: Vec<(usize, TComponentData, bool)>,
+    },
+    /// The entity was parked in the entity pool, `components` holds the data its components had
+    /// before they were reset.
+    EntityReleased {
+        entity: TComponentData::EntityIdType,
+        previous: Option<TComponentData::EntityIdType>,
+        enabled: bool,
+        components: Vec<(usize, TComponentData, bool)>,
+    },
+    /// A parked entity was taken out of the entity pool.
+    EntityRecycled(TComponentData::EntityIdType),
+    /// The entity, or its `component` if set, was enabled or disabled.
+    EnabledSet {
+        entity: TComponentData::EntityIdType,
+        component: Option<usize>,
+        enabled: bool,
+    },
+}
+
+impl<TComponentData: ComponentData> JournalEntry<TComponentData> {
+    fn get_name(&self) -> &'static str {
+        match self {
+            JournalEntry::EntityAdded(_) => "add_entity",
+            JournalEntry::ComponentDataSet { .. } => "set_component_data",
+            JournalEntry::ComponentRemoved { .. } => "remove_component_from_entity",
+            JournalEntry::EntityDespawned { .. } => "despawn_entity",
+            JournalEntry::EntityReleased { .. } => "release_entity",
+            JournalEntry::EntityRecycled(_) => "spawn_entity",
+            JournalEntry::EnabledSet {
+                component: None, ..
+            } => "set_entity_enabled",
+            JournalEntry::EnabledSet { .. } => "set_component_enabled",
+        }
+    }
+}
+
+pub(crate) struct JournalGroup<TComponentData: ComponentData> {
+    pub(crate) name: String,
+    pub(crate) entries: Vec<JournalEntry<TComponentData>>,
+}
+
+/// Undo and redo history of an `ECSWorld`.
+/// Every recorded mutation that is not part of an explicit group is its own group.
+pub(crate) struct Journal<TComponentData: ComponentData> {
+    undo_groups: Vec<JournalGroup<TComponentData>>,
+    redo_groups: Vec<JournalGroup<TComponentData>>,
+    open_group: Option<JournalGroup<TComponentData>>,
+    group_depth: usize,
+}
+
+impl<TComponentData: ComponentData> Default for Journal<TComponentData> {
+    fn default() -> Self {
+        Self {
+            undo_groups: Vec::new(),
+            redo_groups: Vec::new(),
+            open_group: None,
+            group_depth: 0,
+        }
+    }
+}
+
+impl<TComponentData: ComponentData> Journal<TComponentData> {
+    pub(crate) fn record(&mut self, entry: JournalEntry<TComponentData>) {
+        self.redo_groups.clear();
+        match &mut self.open_group {
+            Some(group) => group.entries.push(entry),
+            None => self.undo_groups.push(JournalGroup {
+                name: entry.get_name().to_string(),
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    pub(crate) fn begin_group(&mut self, name: String) {
+        if self.group_depth == 0 {
+            self.open_group = Some(JournalGroup {
+                name,
+                entries: Vec::new(),
+            });
+        }
+        self.group_depth += 1;
+    }
+
+    pub(crate) fn end_group(&mut self) -> Result<(), JournalError> {
+        if self.group_depth == 0 {
+            return Err(JournalError::NoOpenGroup);
+        }
+        self.group_depth -= 1;
+        if self.group_depth == 0 {
+            if let Some(group) = self.open_group.take() {
+                if !group.entries.is_empty() {
+                    self.undo_groups.push(group);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn take_undo_group(&mut self) -> Result<JournalGroup<TComponentData>, JournalError> {
+        if self.group_depth > 0 {
+            Err(JournalError::GroupOpen)
+        } else {
+            self.undo_groups.pop().ok_or(JournalError::NothingToUndo)
+        }
+    }
+
+    pub(crate) fn take_redo_group(&mut self) -> Result<JournalGroup<TComponentData>, JournalError> {
+        if self.group_depth > 0 {
+            Err(JournalError::GroupOpen)
+        } else {
+            self.redo_groups.pop().ok_or(JournalError::NothingToRedo)
+        }
+    }
+
+    pub(crate) fn push_undo_group(&mut self, group: JournalGroup<TComponentData>) {
+        self.undo_groups.push(group);
+    }
+
+    pub(crate) fn push_redo_group(&mut self, group: JournalGroup<TComponentData>) {
+        self.redo_groups.push(group);
+    }
+
+    pub(crate) fn get_undo_name(&self) -> Option<String> {
+        self.undo_groups.last().map(|group| group.name.clone())
+    }
+
+    pub(crate) fn get_redo_name(&self) -> Option<String> {
+        self.redo_groups.last().map(|group| group.name.clone())
+    }
+}
@@ -0,0 +1,1119 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::component::component_data::ComponentData;
+use crate::component::component_definition::{ComponentDefinition, ComponentFieldDefinition};
+use crate::component::component_info::ComponentInfo;
+use crate::component::component_value::ComponentValue;
+use crate::entity::EntityId;
+use crate::math::Reals;
+use crate::variant::VariantType;
+use crate::world::ecs_world::ECSWorld;
+use crate::world::entity_reference::EntityReferencePolicy;
+use crate::world::errors::{ReplayError, WorldError};
+
+const HEADER: &str = "gcs-replay";
+const VERSION: &str = "1";
+
+#[derive(Clone)]
+pub enum ReplayEvent<TComponentDefinition: ComponentDefinition, TComponentData: ComponentData> {
+    RegisterComponent {
+        name: String,
+        definition: TComponentDefinition,
+    },
+    CreateEntity(TComponentData::EntityIdType),
+    RegisterEntity(TComponentData::EntityIdType),
+    SetComponentData {
+        entity: TComponentData::EntityIdType,
+        component: String,
+        fields: Vec<(String, TComponentData::ComponentValueType)>,
+    },
+    RemoveComponentFromEntity {
+        entity: TComponentData::EntityIdType,
+        component: String,
+    },
+    DespawnEntity(TComponentData::EntityIdType),
+    SpawnEntity {
+        entity: TComponentData::EntityIdType,
+        components: Vec<String>,
+    },
+    ReleaseEntity(TComponentData::EntityIdType),
+    SetEntityReferencePolicy {
+        component: Option<String>,
+        policy: EntityReferencePolicy,
+    },
+    SetEnabled {
+        entity: TComponentData::EntityIdType,
+        component: Option<String>,
+        enabled: bool,
+    },
+    EnableJournal,
+    DisableJournal,
+    BeginJournalGroup(String),
+    EndJournalGroup,
+    Undo,
+    Redo,
+}
+
+impl<TComponentDefinition: ComponentDefinition, TComponentData: ComponentData>
+    ReplayEvent<TComponentDefinition, TComponentData>
+{
+    /// Whether the change of this event is journaled, and so reverted by undo and by rolled
+    /// back transactions.
+    pub fn is_journaled(&self) -> bool {
+        matches!(
+            self,
+            ReplayEvent::CreateEntity(_)
+                | ReplayEvent::RegisterEntity(_)
+                | ReplayEvent::SetComponentData { .. }
+                | ReplayEvent::RemoveComponentFromEntity { .. }
+                | ReplayEvent::DespawnEntity(_)
+                | ReplayEvent::SpawnEntity { .. }
+                | ReplayEvent::ReleaseEntity(_)
+                | ReplayEvent::SetEnabled { .. }
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct ReplayFrame<TComponentDefinition: ComponentDefinition, TComponentData: ComponentData> {
+    pub frame: u64,
+    pub events: Vec<ReplayEvent<TComponentDefinition, TComponentData>>,
+    /// Checksum of the world at the end of the frame. `None` if recording stopped mid-frame.
+    pub checksum: Option<u64>,
+}
+
+/// All external mutations applied to an `ECSWorld` while it was recording, grouped by frame.
+#[derive(Clone)]
+pub struct ReplayLog<TComponentDefinition: ComponentDefinition, TComponentData: ComponentData> {
+    pub seed: u64,
+    pub frames: Vec<ReplayFrame<TComponentDefinition, TComponentData>>,
+}
+
+impl<TComponentDefinition: ComponentDefinition, TComponentData: ComponentData>
+    ReplayLog<TComponentDefinition, TComponentData>
+{
+    pub(crate) fn new(seed: u64, frame: u64) -> Self {
+        Self {
+            seed,
+            frames: vec![ReplayFrame {
+                frame,
+                events: Vec::new(),
+                checksum: None,
+            }],
+        }
+    }
+
+    pub(crate) fn record(&mut self, event: ReplayEvent<TComponentDefinition, TComponentData>) {
+        self.frames.last_mut().unwrap().events.push(event);
+    }
+
+    pub(crate) fn end_frame(&mut self, checksum: u64) {
+        let current = self.frames.last_mut().unwrap();
+        current.checksum = Some(checksum);
+        let frame = current.frame + 1;
+        self.frames.push(ReplayFrame {
+            frame,
+            events: Vec::new(),
+            checksum: None,
+        });
+    }
+
+    pub(crate) fn close(&mut self) {
+        if self.frames.len() > 1 && self.frames.last().unwrap().events.is_empty() {
+            self.frames.pop();
+        }
+    }
+
+    pub(crate) fn get_mark(&self) -> (usize, usize) {
+        (
+            self.frames.len() - 1,
+            self.frames.last().unwrap().events.len(),
+        )
+    }
+
+    /// Drops the events recorded since `mark` whose changes were reverted by a rolled back
+    /// transaction. Events of changes that are not journaled, like registering a component,
+    /// are kept since the world still has them.
+    pub(crate) fn discard_reverted(&mut self, (frame, event): (usize, usize)) {
+        for (index, replay_frame) in self.frames.iter_mut().enumerate().skip(frame) {
+            let start = if index == frame { event } else { 0 };
+            let events = replay_frame.events.split_off(start);
+            replay_frame
+                .events
+                .extend(events.into_iter().filter(|e| !e.is_journaled()));
+            replay_frame.checksum = None;
+        }
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        (frame, event): (usize, usize),
+        replay_event: ReplayEvent<TComponentDefinition, TComponentData>,
+    ) {
+        self.frames[frame].events.insert(event, replay_event);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        let file = File::create(path).map_err(|err| ReplayError::Io(err.to_string()))?;
+        let mut writer = BufWriter::new(file);
+        self.write(&mut writer)?;
+        writer
+            .flush()
+            .map_err(|err| ReplayError::Io(err.to_string()))
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        let file = File::open(path).map_err(|err| ReplayError::Io(err.to_string()))?;
+        Self::read(BufReader::new(file))
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), ReplayError> {
+        let mut lines = vec![join(&[
+            HEADER.to_string(),
+            VERSION.to_string(),
+            self.seed.to_string(),
+        ])];
+        for frame in &self.frames {
+            lines.push(join(&["frame".to_string(), frame.frame.to_string()]));
+            for event in &frame.events {
+                lines.push(join(&encode_event(event)));
+            }
+            if let Some(checksum) = frame.checksum {
+                lines.push(join(&[
+                    "checksum".to_string(),
+                    format!("{:016x}", checksum),
+                ]));
+            }
+        }
+
+        for line in lines {
+            writeln!(writer, "{}", line).map_err(|err| ReplayError::Io(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, ReplayError> {
+        let mut log: Option<Self> = None;
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index + 1;
+            let parse_error = |message: String| ReplayError::Parse {
+                line: line_number,
+                message,
+            };
+            let line = line.map_err(|err| ReplayError::Io(err.to_string()))?;
+            if line.is_empty() {
+                continue;
+            }
+            let parts: Vec<String> = line.split('\t').map(unescape).collect();
+
+            let log = match &mut log {
+                None => {
+                    if parts.len() != 3 || parts[0] != HEADER || parts[1] != VERSION {
+                        return Err(parse_error("Missing replay header".to_string()));
+                    }
+                    let seed = parts[2]
+                        .parse::<u64>()
+                        .map_err(|e| parse_error(e.to_string()))?;
+                    log = Some(Self {
+                        seed,
+                        frames: Vec::new(),
+                    });
+                    continue;
+                }
+                Some(log) => log,
+            };
+
+            match parts[0].as_str() {
+                "frame" => {
+                    let frame = get_part(&parts, 1)
+                        .and_then(|p| p.parse::<u64>().map_err(|e| e.to_string()))
+                        .map_err(parse_error)?;
+                    log.frames.push(ReplayFrame {
+                        frame,
+                        events: Vec::new(),
+                        checksum: None,
+                    });
+                }
+                "checksum" => {
+                    let checksum = get_part(&parts, 1)
+                        .and_then(|p| u64::from_str_radix(p, 16).map_err(|e| e.to_string()))
+                        .map_err(parse_error)?;
+                    match log.frames.last_mut() {
+                        None => return Err(parse_error("Checksum outside of a frame".to_string())),
+                        Some(frame) => frame.checksum = Some(checksum),
+                    }
+                }
+                _ => {
+                    let event = decode_event(&parts).map_err(parse_error)?;
+                    match log.frames.last_mut() {
+                        None => return Err(parse_error("Event outside of a frame".to_string())),
+                        Some(frame) => frame.events.push(event),
+                    }
+                }
+            }
+        }
+
+        log.ok_or(ReplayError::Parse {
+            line: 0,
+            message: "Missing replay header".to_string(),
+        })
+    }
+}
+
+impl<
+        TComponentDefinition: ComponentDefinition,
+        TComponentData: ComponentData,
+        TComponentInfo: ComponentInfo,
+    > ECSWorld<TComponentDefinition, TComponentData, TComponentInfo>
+{
+    /// Applies all events of `log` to this world, which should be freshly created.
+    /// After each completed frame the checksum of the world is compared with the recorded one.
+    pub fn replay(
+        &mut self,
+        log: &ReplayLog<TComponentDefinition, TComponentData>,
+    ) -> Result<(), ReplayError> {
+        for frame in &log.frames {
+            self.set_frame(frame.frame);
+            for event in &frame.events {
+                self.apply_replay_event(event)
+                    .map_err(|error| ReplayError::EventFailed {
+                        frame: frame.frame,
+                        source: Box::new(error),
+                    })?;
+            }
+            if let Some(expected) = frame.checksum {
+                let actual = self.checksum();
+                if actual != expected {
+                    return Err(ReplayError::Diverged {
+                        frame: frame.frame,
+                        expected,
+                        actual,
+                    });
+                }
+                self.advance_frame();
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_replay_event(
+        &mut self,
+        event: &ReplayEvent<TComponentDefinition, TComponentData>,
+    ) -> Result<(), WorldError> {
+        match event {
+            ReplayEvent::RegisterComponent { name, definition } => self
+                .register_component(name.clone(), definition.clone())
+                .map(|_| ())
+                .map_err(WorldError::from),
+            ReplayEvent::CreateEntity(entity) | ReplayEvent::RegisterEntity(entity) => {
+                self.register_entity(entity).map_err(WorldError::from)
+            }
+            ReplayEvent::SetComponentData {
+                entity,
+                component,
+                fields,
+            } => {
+                let mut data = TComponentData::new(*entity);
+                for (field, value) in fields {
+                    data.set_field(field, value);
+                }
+                self.set_component_data(entity, component.clone(), &data)
+                    .map_err(WorldError::from)
+            }
+            ReplayEvent::RemoveComponentFromEntity { entity, component } => self
+                .remove_component_from_entity(entity, component.clone())
+                .map_err(WorldError::from),
+            ReplayEvent::DespawnEntity(entity) => {
+                self.despawn_entity(entity).map_err(WorldError::from)
+            }
+            ReplayEvent::SpawnEntity { entity, components } => self
+                .spawn_entity_with_id(components, Some(*entity))
+                .map(|_| ())
+                .map_err(WorldError::from),
+            ReplayEvent::ReleaseEntity(entity) => {
+                self.release_entity(entity).map_err(WorldError::from)
+            }
+            ReplayEvent::SetEntityReferencePolicy { component, policy } => match component {
+                None => {
+                    self.set_entity_reference_policy(*policy);
+                    Ok(())
+                }
+                Some(component) => self
+                    .set_component_entity_reference_policy(component.clone(), *policy)
+                    .map_err(WorldError::from),
+            },
+            ReplayEvent::SetEnabled {
+                entity,
+                component,
+                enabled,
+            } => match component {
+                None => self
+                    .set_entity_enabled(entity, *enabled)
+                    .map_err(WorldError::from),
+                Some(component) => self
+                    .set_component_enabled(entity, component.clone(), *enabled)
+                    .map_err(WorldError::from),
+            },
+            ReplayEvent::EnableJournal => {
+                self.enable_journal();
+                Ok(())
+            }
+            ReplayEvent::DisableJournal => {
+                self.disable_journal();
+                Ok(())
+            }
+            ReplayEvent::BeginJournalGroup(name) => self
+                .begin_journal_group(name.clone())
+                .map_err(WorldError::from),
+            ReplayEvent::EndJournalGroup => self.end_journal_group().map_err(WorldError::from),
+            ReplayEvent::Undo => self.undo().map_err(WorldError::from),
+            ReplayEvent::Redo => self.redo().map_err(WorldError::from),
+        }
+    }
+}
+
+pub(crate) fn encode_value<TComponentValue: ComponentValue>(value: &TComponentValue) -> String {
+    let payload = match value.get_type() {
+        VariantType::Nil => String::new(),
+        VariantType::Entity => value
+            .get_entity()
+            .map(|entity| entity.as_string())
+            .unwrap_or_default(),
+        VariantType::Bool => value.get_bool().to_string(),
+        VariantType::Int => value.get_int().to_string(),
+        VariantType::Real => value.get_real().to_string(),
+        VariantType::Vector2 => encode_reals(&[value.get_vector2()]),
+        VariantType::Rect2 => encode_reals(&[value.get_rect2()]),
+        VariantType::Vector3 => encode_reals(&[value.get_vector3()]),
+        VariantType::Transform2D => encode_reals(&[value.get_transform2d()]),
+        VariantType::Plan => encode_reals(&[value.get_plane()]),
+        VariantType::Quat => encode_reals(&[value.get_quat()]),
+        VariantType::Aaab => encode_reals(&[value.get_aabb()]),
+        VariantType::Basis => encode_reals(&[value.get_basis()]),
+        VariantType::Transform => encode_reals(&[value.get_transform()]),
+        VariantType::Color => encode_reals(&[value.get_color()]),
+        VariantType::Array => encode_values(value.get_array().iter()),
+        VariantType::Dictionary => encode_values(
+            value
+                .get_dictionary()
+                .iter()
+                .flat_map(|(key, value)| [key, value]),
+        ),
+        VariantType::PoolByteArray => encode_list(value.get_pool_byte_array().iter()),
+        VariantType::PoolIntArray => encode_list(value.get_pool_int_array().iter()),
+        VariantType::PoolRealArray => encode_list(value.get_pool_real_array().iter()),
+        VariantType::PoolStringArray => encode_strings(value.get_pool_string_array().iter()),
+        VariantType::PoolVector2Array => encode_reals(&value.get_pool_vector2_array()),
+        VariantType::PoolVector3Array => encode_reals(&value.get_pool_vector3_array()),
+        VariantType::PoolColorArray => encode_reals(&value.get_pool_color_array()),
+        _ => value.get_string(),
+    };
+    format!("{}:{}", value.get_type() as u32, payload)
+}
+
+pub(crate) fn decode_value<TComponentValue: ComponentValue>(
+    input: &str,
+) -> Result<TComponentValue, String> {
+    let (variant_type, payload) = input
+        .split_once(':')
+        .ok_or_else(|| format!("'{}' is not a valid value", input))?;
+    let variant_type = variant_type.parse::<u32>().map_err(|e| e.to_string())?;
+    let mut value = TComponentValue::default();
+    match VariantType::try_from(variant_type)? {
+        VariantType::Nil => value.set_nil(),
+        VariantType::Bool => value.set_bool(payload.parse::<bool>().map_err(|e| e.to_string())?),
+        VariantType::Int => value.set_int(payload.parse::<i64>().map_err(|e| e.to_string())?),
+        VariantType::Real => value.set_real(payload.parse::<f64>().map_err(|e| e.to_string())?),
+        VariantType::String => value.set_string(payload.to_string()),
+        VariantType::Entity => value.set_entity(TComponentValue::EntityIdType::parse_str(payload)?),
+        VariantType::Vector2 => value.set_vector2(decode_real(payload)?),
+        VariantType::Rect2 => value.set_rect2(decode_real(payload)?),
+        VariantType::Vector3 => value.set_vector3(decode_real(payload)?),
+        VariantType::Transform2D => value.set_transform2d(decode_real(payload)?),
+        VariantType::Plan => value.set_plane(decode_real(payload)?),
+        VariantType::Quat => value.set_quat(decode_real(payload)?),
+        VariantType::Aaab => value.set_aabb(decode_real(payload)?),
+        VariantType::Basis => value.set_basis(decode_real(payload)?),
+        VariantType::Transform => value.set_transform(decode_real(payload)?),
+        VariantType::Color => value.set_color(decode_real(payload)?),
+        VariantType::Array => value.set_array(decode_values(payload)?),
+        VariantType::Dictionary => {
+            let values: Vec<TComponentValue> = decode_values(payload)?;
+            if !values.len().is_multiple_of(2) {
+                return Err(format!("'{}' has a key without a value", payload));
+            }
+            value.set_dictionary(
+                values
+                    .chunks(2)
+                    .map(|entry| (entry[0].clone(), entry[1].clone()))
+                    .collect(),
+            )
+        }
+        VariantType::PoolByteArray => value.set_pool_byte_array(decode_list(payload)?),
+        VariantType::PoolIntArray => value.set_pool_int_array(decode_list(payload)?),
+        VariantType::PoolRealArray => value.set_pool_real_array(decode_list(payload)?),
+        VariantType::PoolStringArray => value.set_pool_string_array(
+            decode_strings(payload)?
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        ),
+        VariantType::PoolVector2Array => value.set_pool_vector2_array(decode_reals(payload)?),
+        VariantType::PoolVector3Array => value.set_pool_vector3_array(decode_reals(payload)?),
+        VariantType::PoolColorArray => value.set_pool_color_array(decode_reals(payload)?),
+        variant_type => {
+            return Err(format!(
+                "Values of type {:?} are not supported",
+                variant_type
+            ))
+        }
+    }
+    Ok(value)
+}
+
+/// Nested values are written as `<length>#<value>` so they can contain any character.
+fn encode_values<'a, TComponentValue: ComponentValue + 'a>(
+    values: impl Iterator<Item = &'a TComponentValue>,
+) -> String {
+    encode_strings(values.map(encode_value))
+}
+
+fn decode_values<TComponentValue: ComponentValue>(
+    payload: &str,
+) -> Result<Vec<TComponentValue>, String> {
+    decode_strings(payload)?
+        .into_iter()
+        .map(decode_value)
+        .collect()
+}
+
+fn encode_strings<T: AsRef<str>>(values: impl Iterator<Item = T>) -> String {
+    values
+        .map(|value| format!("{}#{}", value.as_ref().len(), value.as_ref()))
+        .collect()
+}
+
+fn decode_strings(mut payload: &str) -> Result<Vec<&str>, String> {
+    let mut values = Vec::new();
+    while !payload.is_empty() {
+        let (length, rest) = payload
+            .split_once('#')
+            .ok_or_else(|| format!("'{}' is not a valid nested value", payload))?;
+        let length = length.parse::<usize>().map_err(|e| e.to_string())?;
+        let value = rest
+            .get(..length)
+            .ok_or_else(|| format!("'{}' is shorter than {} bytes", rest, length))?;
+        values.push(value);
+        payload = &rest[length..];
+    }
+    Ok(values)
+}
+
+fn encode_list<T: ToString>(values: impl Iterator<Item = T>) -> String {
+    values
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn decode_list<T: FromStr>(payload: &str) -> Result<Vec<T>, String>
+where
+    T::Err: ToString,
+{
+    if payload.is_empty() {
+        return Ok(Vec::new());
+    }
+    payload
+        .split(',')
+        .map(|value| value.parse::<T>().map_err(|e| e.to_string()))
+        .collect()
+}
+
+fn encode_reals<T: Reals>(values: &[T]) -> String {
+    encode_list(values.iter().flat_map(Reals::to_reals))
+}
+
+fn decode_reals<T: Reals>(payload: &str) -> Result<Vec<T>, String> {
+    let reals = decode_list::<f32>(payload)?;
+    if !reals.len().is_multiple_of(T::LEN) {
+        return Err(format!(
+            "'{}' has {} values but a multiple of {} was expected",
+            payload,
+            reals.len(),
+            T::LEN
+        ));
+    }
+    Ok(reals.chunks(T::LEN).filter_map(T::from_reals).collect())
+}
+
+fn decode_real<T: Reals>(payload: &str) -> Result<T, String> {
+    let mut values = decode_reals(payload)?;
+    match values.len() {
+        1 => Ok(values.remove(0)),
+        _ => Err(format!(
+            "'{}' does not have exactly {} values",
+            payload,
+            T::LEN
+        )),
+    }
+}
+
+fn encode_event<TComponentDefinition: ComponentDefinition, TComponentData: ComponentData>(
+    event: &ReplayEvent<TComponentDefinition, TComponentData>,
+) -> Vec<String> {
+    match event {
+        ReplayEvent::RegisterComponent { name, definition } => {
+            let mut parts = vec!["register_component".to_string(), name.clone()];
+            for field in definition.get_fields() {
+                parts.push(field.get_name());
+                parts.push((field.get_type() as u32).to_string());
+            }
+            parts
+        }
+        ReplayEvent::CreateEntity(entity) => {
+            vec!["create_entity".to_string(), entity.as_string()]
+        }
+        ReplayEvent::RegisterEntity(entity) => {
+            vec!["register_entity".to_string(), entity.as_string()]
+        }
+        ReplayEvent::SetComponentData {
+            entity,
+            component,
+            fields,
+        } => {
+            let mut parts = vec![
+                "set_component_data".to_string(),
+                entity.as_string(),
+                component.clone(),
+            ];
+            for (field, value) in fields {
+                parts.push(field.clone());
+                parts.push(encode_value(value));
+            }
+            parts
+        }
+        ReplayEvent::RemoveComponentFromEntity { entity, component } => vec![
+            "remove_component_from_entity".to_string(),
+            entity.as_string(),
+            component.clone(),
+        ],
+        ReplayEvent::DespawnEntity(entity) => {
+            vec!["despawn_entity".to_string(), entity.as_string()]
+        }
+        ReplayEvent::SpawnEntity { entity, components } => {
+            let mut parts = vec!["spawn_entity".to_string(), entity.as_string()];
+            parts.extend(components.iter().cloned());
+            parts
+        }
+        ReplayEvent::ReleaseEntity(entity) => {
+            vec!["release_entity".to_string(), entity.as_string()]
+        }
+        ReplayEvent::SetEntityReferencePolicy { component, policy } => {
+            let mut parts = vec![
+                "set_entity_reference_policy".to_string(),
+                policy.as_str().to_string(),
+            ];
+            parts.extend(component.clone());
+            parts
+        }
+        ReplayEvent::SetEnabled {
+            entity,
+            component,
+            enabled,
+        } => {
+            let mut parts = vec![
+                "set_enabled".to_string(),
+                entity.as_string(),
+                enabled.to_string(),
+            ];
+            parts.extend(component.clone());
+            parts
+        }
+        ReplayEvent::EnableJournal => vec!["enable_journal".to_string()],
+        ReplayEvent::DisableJournal => vec!["disable_journal".to_string()],
+        ReplayEvent::BeginJournalGroup(name) => {
+            vec!["begin_journal_group".to_string(), name.clone()]
+        }
+        ReplayEvent::EndJournalGroup => vec!["end_journal_group".to_string()],
+        ReplayEvent::Undo => vec!["undo".to_string()],
+        ReplayEvent::Redo => vec!["redo".to_string()],
+    }
+}
+
+fn decode_event<TComponentDefinition: ComponentDefinition, TComponentData: ComponentData>(
+    parts: &[String],
+) -> Result<ReplayEvent<TComponentDefinition, TComponentData>, String> {
+    let entity = || get_part(parts, 1).and_then(TComponentData::EntityIdType::parse_str);
+    let event = match parts[0].as_str() {
+        "register_component" => {
+            let mut definition = TComponentDefinition::default();
+            for field in get_parts_from(parts, 2).chunks(2) {
+                if field.len() != 2 {
+                    return Err("Field definition without a type".to_string());
+                }
+                let field_type = field[1].parse::<u32>().map_err(|e| e.to_string())?;
+                definition.add_field(ComponentFieldDefinition::create(
+                    field[0].clone(),
+                    VariantType::try_from(field_type)?,
+                ));
+            }
+            ReplayEvent::RegisterComponent {
+                name: get_part(parts, 1)?.to_string(),
+                definition,
+            }
+        }
+        "create_entity" => ReplayEvent::CreateEntity(entity()?),
+        "register_entity" => ReplayEvent::RegisterEntity(entity()?),
+        "set_component_data" => {
+            let mut fields = Vec::new();
+            for field in get_parts_from(parts, 3).chunks(2) {
+                if field.len() != 2 {
+                    return Err("Field without a value".to_string());
+                }
+                fields.push((field[0].clone(), decode_value(&field[1])?));
+            }
+            ReplayEvent::SetComponentData {
+                entity: entity()?,
+                component: get_part(parts, 2)?.to_string(),
+                fields,
+            }
+        }
+        "remove_component_from_entity" => ReplayEvent::RemoveComponentFromEntity {
+            entity: entity()?,
+            component: get_part(parts, 2)?.to_string(),
+        },
+        "despawn_entity" => ReplayEvent::DespawnEntity(entity()?),
+        "spawn_entity" => ReplayEvent::SpawnEntity {
+            entity: entity()?,
+            components: get_parts_from(parts, 2).to_vec(),
+        },
+        "release_entity" => ReplayEvent::ReleaseEntity(entity()?),
+        "set_entity_reference_policy" => ReplayEvent::SetEntityReferencePolicy {
+            component: parts.get(2).cloned(),
+            policy: EntityReferencePolicy::parse_str(get_part(parts, 1)?)?,
+        },
+        "set_enabled" => ReplayEvent::SetEnabled {
+            entity: entity()?,
+            component: parts.get(3).cloned(),
+            enabled: get_part(parts, 2)?
+                .parse::<bool>()
+                .map_err(|e| e.to_string())?,
+        },
+        "enable_journal" => ReplayEvent::EnableJournal,
+        "disable_journal" => ReplayEvent::DisableJournal,
+        "begin_journal_group" => ReplayEvent::BeginJournalGroup(get_part(parts, 1)?.to_string()),
+        "end_journal_group" => ReplayEvent::EndJournalGroup,
+        "undo" => ReplayEvent::Undo,
+        "redo" => ReplayEvent::Redo,
+        event => return Err(format!("Unknown event '{}'", event)),
+    };
+    Ok(event)
+}
+
+fn get_part(parts: &[String], index: usize) -> Result<&str, String> {
+    parts
+        .get(index)
+        .map(|p| p.as_str())
+        .ok_or_else(|| format!("Missing value {} of '{}'", index, parts[0]))
+}
+
+/// Parts from `index` on, empty if the line is shorter.
+fn get_parts_from(parts: &[String], index: usize) -> &[String] {
+    parts.get(index..).unwrap_or_default()
+}
+
+fn join(parts: &[String]) -> String {
+    parts
+        .iter()
+        .map(|p| escape(p))
+        .collect::<Vec<String>>()
+        .join("\t")
+}
+
+fn escape(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn unescape(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('t') => output.push('\t'),
+                Some('n') => output.push('\n'),
+                Some('r') => output.push('\r'),
+                Some(other) => output.push(other),
+                None => output.push('\\'),
+            }
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::io::BufReader;
+
+    use crate::component::component_data::ComponentData;
+    use crate::component::component_definition::ComponentDefinition;
+    use crate::component::component_value::ComponentValue;
+    use crate::math::{Color, Transform, Vector2, Vector3};
+    use crate::variant::VariantType;
+    use crate::world::ecs_world::tests::{
+        TestComponentData, TestComponentDefinition, TestComponentFieldDefinition,
+        TestComponentInfo, TestComponentValue, TestEntityId,
+    };
+    use crate::world::ecs_world::ECSWorld;
+    use crate::world::entity_reference::EntityReferencePolicy;
+    use crate::world::errors::{DespawnEntityError, ErrorKind, ReplayError, WorldError};
+    use crate::world::replay::{decode_value, encode_value, ReplayEvent, ReplayLog};
+
+    type TestWorld = ECSWorld<TestComponentDefinition, TestComponentData, TestComponentInfo>;
+    type TestReplayLog = ReplayLog<TestComponentDefinition, TestComponentData>;
+
+    fn record_session() -> (TestReplayLog, u64) {
+        let mut world = TestWorld::default();
+        world.start_recording(1234);
+
+        let mut definition = TestComponentDefinition::default();
+        definition.add_field(TestComponentFieldDefinition {
+            name: "name".to_string(),
+            field_type: VariantType::String,
+        });
+        definition.add_field(TestComponentFieldDefinition {
+            name: "speed".to_string(),
+            field_type: VariantType::Real,
+        });
+        world
+            .register_component("Unit\tType".to_string(), definition)
+            .unwrap();
+        world.enable_journal();
+
+        let first = TestEntityId { id: 1 };
+        let second = TestEntityId { id: 2 };
+        world.register_entity(&first).unwrap();
+        world.register_entity(&second).unwrap();
+        world.advance_frame();
+
+        let mut data = TestComponentData::new(first);
+        data.set_field(
+            "name",
+            &TestComponentValue::String("Line\nbreak \\ and tab\t".to_string()),
+        );
+        data.set_field("speed", &TestComponentValue::Real(0.1));
+        world
+            .set_component_data(&first, "Unit\tType".to_string(), &data)
+            .unwrap();
+        world
+            .set_component_data(&second, "Unit\tType".to_string(), &data)
+            .unwrap();
+        world.undo().unwrap();
+        world
+            .set_component_enabled(&first, "Unit\tType".to_string(), false)
+            .unwrap();
+        world.set_entity_enabled(&second, false).unwrap();
+        let pooled = world.spawn_entity(["Unit\tType"]).unwrap();
+        world.release_entity(&pooled).unwrap();
+        assert_eq!(Ok(pooled), world.spawn_entity(["Unit\tType"]));
+        world.release_entity(&pooled).unwrap();
+        world.advance_frame();
+
+        let rolled_back: Result<(), String> = world.transaction(|tx| {
+            tx.despawn_entity(&first).unwrap();
+            Err("Abort".to_string())
+        });
+        assert!(rolled_back.is_err());
+        world.despawn_entity(&second).unwrap();
+
+        let checksum = world.checksum();
+        (world.stop_recording().unwrap(), checksum)
+    }
+
+    fn write_and_read(log: &TestReplayLog) -> TestReplayLog {
+        let mut buffer = Vec::new();
+        log.write(&mut buffer).unwrap();
+        TestReplayLog::read(BufReader::new(buffer.as_slice())).unwrap()
+    }
+
+    #[test]
+    fn replay_reproduces_the_recorded_world() {
+        let (log, checksum) = record_session();
+
+        let mut world = TestWorld::default();
+        assert_eq!(Ok(()), world.replay(&log));
+        assert_eq!(checksum, world.checksum());
+        assert_eq!(2, world.get_frame());
+        assert_eq!(
+            vec![TestEntityId { id: 1 }],
+            world.get_entities().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn replay_log_survives_writing_and_reading() {
+        let (log, checksum) = record_session();
+        let log = write_and_read(&log);
+
+        assert_eq!(1234, log.seed);
+        assert_eq!(3, log.frames.len());
+        assert_eq!(None, log.frames[2].checksum);
+        assert!(log.frames[1]
+            .events
+            .iter()
+            .any(|event| matches!(event, ReplayEvent::ReleaseEntity(_))));
+
+        let mut world = TestWorld::default();
+        assert_eq!(Ok(()), world.replay(&log));
+        assert_eq!(checksum, world.checksum());
+        assert_eq!(
+            "Line\nbreak \\ and tab\t",
+            world
+                .get_component_of_entity(&TestEntityId { id: 1 }, "Unit\tType".to_string())
+                .unwrap()
+                .get_field("name")
+                .get_string()
+        );
+        assert_eq!(1, world.get_pooled_entity_count());
+        assert!(!world.is_component_enabled(&TestEntityId { id: 1 }, "Unit\tType".to_string()));
+    }
+
+    #[test]
+    fn replay_reports_the_frame_where_it_diverged() {
+        let (mut log, _) = record_session();
+        let tampered = TestEntityId { id: 3 };
+        log.frames[1]
+            .events
+            .insert(0, ReplayEvent::RegisterEntity(tampered));
+
+        let mut world = TestWorld::default();
+        let result = world.replay(&log);
+        assert!(
+            matches!(result, Err(ReplayError::Diverged { frame: 1, .. })),
+            "Replay should have diverged in frame 1"
+        );
+    }
+
+    #[test]
+    fn replay_reports_events_that_fail() {
+        let (mut log, _) = record_session();
+        log.frames[0]
+            .events
+            .push(ReplayEvent::DespawnEntity(TestEntityId { id: 9 }));
+
+        let mut world = TestWorld::default();
+        let result = world.replay(&log);
+        assert!(
+            matches!(result, Err(ReplayError::EventFailed { frame: 0, .. })),
+            "Replay should have failed in frame 0"
+        );
+
+        let error = result.unwrap_err();
+        assert_eq!(ErrorKind::InvalidData, error.get_kind());
+        let source = error
+            .source()
+            .unwrap()
+            .downcast_ref::<WorldError>()
+            .unwrap();
+        assert_eq!(
+            &WorldError::DespawnEntity(DespawnEntityError::EntityNotFound {
+                entity: "9".to_string()
+            }),
+            source
+        );
+        assert_eq!(ErrorKind::NotFound, source.get_kind());
+        assert_eq!("Entity '9' was not found", source.to_string());
+    }
+
+    #[test]
+    fn read_rejects_logs_without_header() {
+        let result = TestReplayLog::read(BufReader::new("frame\t0\n".as_bytes()));
+        assert!(matches!(result, Err(ReplayError::Parse { line: 1, .. })));
+    }
+
+    #[test]
+    fn read_rejects_unknown_events() {
+        let input = "gcs-replay\t1\t0\nframe\t0\nexplode\t1\n";
+        let result = TestReplayLog::read(BufReader::new(input.as_bytes()));
+        assert!(matches!(result, Err(ReplayError::Parse { line: 3, .. })));
+    }
+
+    #[test]
+    fn read_rejects_truncated_events() {
+        for event in [
+            "register_component",
+            "set_component_data\t1",
+            "spawn_entity",
+            "set_enabled\t1",
+        ] {
+            let input = format!("gcs-replay\t1\t0\nframe\t0\n{}\n", event);
+            let result = TestReplayLog::read(BufReader::new(input.as_bytes()));
+            assert!(
+                matches!(result, Err(ReplayError::Parse { line: 3, .. })),
+                "'{}' should be rejected",
+                event
+            );
+        }
+    }
+
+    #[test]
+    fn checksum_differs_when_field_values_differ() {
+        let (log, _) = record_session();
+        let mut world = TestWorld::default();
+        world.replay(&log).unwrap();
+        let checksum = world.checksum();
+
+        let entity = TestEntityId { id: 1 };
+        let mut data = TestComponentData::new(entity);
+        data.set_field("speed", &TestComponentValue::Real(0.2));
+        world
+            .set_component_data(&entity, "Unit\tType".to_string(), &data)
+            .unwrap();
+
+        assert_ne!(checksum, world.checksum());
+    }
+
+    #[test]
+    fn replay_applies_entity_reference_policies() {
+        let mut world = TestWorld::default();
+        world.start_recording(0);
+        let mut definition = TestComponentDefinition::default();
+        definition.add_field(TestComponentFieldDefinition {
+            name: "owner".to_string(),
+            field_type: VariantType::Entity,
+        });
+        world
+            .register_component("Owned".to_string(), definition)
+            .unwrap();
+        world
+            .set_component_entity_reference_policy(
+                "Owned".to_string(),
+                EntityReferencePolicy::DespawnEntity,
+            )
+            .unwrap();
+        let owner = TestEntityId { id: 1 };
+        let owned = TestEntityId { id: 2 };
+        world.register_entity(&owner).unwrap();
+        world.register_entity(&owned).unwrap();
+        let mut data = TestComponentData::new(owned);
+        data.set_field("owner", &TestComponentValue::Entity(owner));
+        world
+            .set_component_data(&owned, "Owned".to_string(), &data)
+            .unwrap();
+        world.despawn_entity(&owner).unwrap();
+        let log = write_and_read(&world.stop_recording().unwrap());
+
+        let mut replayed = TestWorld::default();
+        replayed.replay(&log).unwrap();
+        assert!(replayed.get_entities().next().is_none());
+        assert_eq!(
+            EntityReferencePolicy::DespawnEntity,
+            replayed.get_entity_reference_policy("Owned")
+        );
+    }
+
+    #[test]
+    fn replay_keeps_components_registered_in_rolled_back_transactions() {
+        let mut world = TestWorld::default();
+        world.start_recording(0);
+        let rolled_back: Result<(), String> = world.transaction(|tx| {
+            let mut definition = TestComponentDefinition::default();
+            definition.add_field(TestComponentFieldDefinition {
+                name: "speed".to_string(),
+                field_type: VariantType::Real,
+            });
+            tx.register_component("Unit".to_string(), definition)
+                .unwrap();
+            tx.register_entity(&TestEntityId { id: 1 }).unwrap();
+            Err("Abort".to_string())
+        });
+        assert!(rolled_back.is_err());
+        let entity = TestEntityId { id: 2 };
+        world.register_entity(&entity).unwrap();
+        world
+            .set_component_data(&entity, "Unit".to_string(), &TestComponentData::new(entity))
+            .unwrap();
+        let checksum = world.checksum();
+        let log = write_and_read(&world.stop_recording().unwrap());
+
+        let mut replayed = TestWorld::default();
+        assert_eq!(Ok(()), replayed.replay(&log));
+        assert_eq!(checksum, replayed.checksum());
+        assert_eq!(
+            vec![entity],
+            replayed.get_entities().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn math_values_survive_encoding() {
+        let values = vec![
+            TestComponentValue::Vector2(Vector2::new(1.5, -0.1)),
+            TestComponentValue::Transform(Transform::new(
+                Default::default(),
+                Vector3::new(1.0, 2.0, 3.0),
+            )),
+            TestComponentValue::Color(Color::new(0.2, 0.4, 0.6, 0.8)),
+        ];
+        for value in values {
+            let decoded: TestComponentValue = decode_value(&encode_value(&value)).unwrap();
+            assert_eq!(value, decoded);
+        }
+    }
+
+    #[test]
+    fn nested_values_survive_encoding() {
+        let dictionary = [
+            (
+                TestComponentValue::String("a#b:c".to_string()),
+                TestComponentValue::Int(1),
+            ),
+            (
+                TestComponentValue::Int(0),
+                TestComponentValue::Array(vec![TestComponentValue::Nil]),
+            ),
+        ]
+        .into_iter()
+        .collect();
+        let value = TestComponentValue::Array(vec![
+            TestComponentValue::Vector2(Vector2::new(1.0, 2.0)),
+            TestComponentValue::Dictionary(dictionary),
+            TestComponentValue::Array(Vec::new()),
+        ]);
+        let decoded: TestComponentValue = decode_value(&encode_value(&value)).unwrap();
+        assert_eq!(value, decoded);
+    }
+
+    #[test]
+    fn pool_arrays_survive_encoding() {
+        let values = vec![
+            TestComponentValue::PoolByteArray(vec![0, 255, 7]),
+            TestComponentValue::PoolIntArray(Vec::new()),
+            TestComponentValue::PoolRealArray(vec![0.5, -1.25]),
+            TestComponentValue::PoolStringArray(vec!["a,b".to_string(), String::new()]),
+            TestComponentValue::PoolVector2Array(vec![Vector2::new(1.0, 2.0), Vector2::ZERO]),
+            TestComponentValue::PoolColorArray(vec![Color::rgb(1.0, 0.5, 0.0)]),
+        ];
+        for value in values {
+            let decoded: TestComponentValue = decode_value(&encode_value(&value)).unwrap();
+            assert_eq!(value, decoded);
+        }
+    }
+
+    #[test]
+    fn decode_value_rejects_math_values_of_the_wrong_length() {
+        let result = decode_value::<TestComponentValue>("5:1,2,3");
+        assert!(result.is_err());
+    }
+}
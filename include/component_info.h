@@ -19,6 +19,8 @@ protected:
     static void _bind_methods();
 public:
     ComponentInfo();
+
+    uint64_t get_hash() const;
 };
 
 
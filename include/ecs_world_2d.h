@@ -19,8 +19,48 @@ protected:
 public:
     Ref<ComponentInfo> register_component(const StringName& name, const Ref<ComponentDefinition>& componentDefinition) override;
     Ref<Entity> create_entity() override;
-    void register_entity(Ref<Entity> entity) override;
+    Ref<Entity> spawn_entity(const PoolStringArray &components) override;
+    Ref<Entity> get_entity(const String &id) const override;
+    Error release_entity(Ref<Entity> entity) override;
+    bool is_entity_current(Ref<Entity> entity) const override;
+    int get_pooled_entity_count() const override;
+    Error set_entity_id_generation(int mode, uint64_t seed) override;
+    Error set_entity_reference_policy(int policy) override;
+    Error set_component_entity_reference_policy(const StringName &component, int policy) override;
+    Error register_entity(Ref<Entity> entity) override;
+    Array get_component_names() const override;
+    Ref<ComponentInfo> get_component_info(const StringName &name) const override;
+    StringName get_component_name(Ref<ComponentInfo> info) const override;
+    Dictionary get_component_definition(const StringName &name) const override;
+    Dictionary get_component_definition_by_info(Ref<ComponentInfo> info) const override;
     Ref<ComponentInfo> register_script_component(const StringName &name, Ref<Script> resource) override;
     PoolStringArray get_components_of_entity(Ref<Entity> entity) override;
+    Dictionary get_component(Ref<Entity> entity, const StringName &component) override;
+    Error set_component(Ref<Entity> entity, const StringName &component, const Dictionary &data) override;
+    Array get_all_components(const StringName &component, bool include_disabled) override;
+    PoolStringArray spawn_batch(int count, const StringName &component, const Dictionary &data) override;
+    PoolIntArray set_component_batch(const PoolStringArray &entities, const StringName &component,
+                                     const Array &data) override;
+    Error despawn_entity(Ref<Entity> entity) override;
+    Error remove_component_from_entity(Ref<Entity> entity, const StringName &component) override;
+    Error set_entity_enabled(Ref<Entity> entity, bool enabled) override;
+    bool is_entity_enabled(Ref<Entity> entity) const override;
+    Error set_component_enabled(Ref<Entity> entity, const StringName &component, bool enabled) override;
+    bool is_component_enabled(Ref<Entity> entity, const StringName &component) const override;
+    void set_journal_enabled(bool enabled) override;
+    bool is_journal_enabled() const override;
+    Error begin_journal_group(const StringName &name) override;
+    Error end_journal_group() override;
+    Error undo() override;
+    Error redo() override;
+    StringName get_undo_name() const override;
+    StringName get_redo_name() const override;
+    void start_recording(uint64_t seed) override;
+    Error stop_recording(const String &path) override;
+    bool is_recording() const override;
+    void advance_frame() override;
+    uint64_t get_frame() const override;
+    uint64_t checksum() const override;
+    Error replay(const String &path) override;
 };
 #endif //GODOT_COMPONENT_SYSTEM_GODOT_WORLD_2D_H
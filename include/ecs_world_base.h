@@ -13,14 +13,70 @@
 class ECSWorldBase {
 private:
     ::rust::Box<::gcs::ffi::ECSWorld> world;
+
+    static Dictionary fields_to_dictionary(const ::rust::Vec<::gcs::ffi::ComponentFieldDefinition> &fields);
+    Ref<Entity> entity_from_id(::gcs::ffi::EntityId *entity_id) const;
 protected:
 public:
+    enum EntityIdGeneration {
+        ID_GENERATION_RANDOM,
+        ID_GENERATION_SEEDED,
+        ID_GENERATION_SEQUENTIAL,
+        ID_GENERATION_TIME_ORDERED,
+    };
+
+    enum EntityReferencePolicy {
+        REFERENCE_SET_NIL,
+        REFERENCE_REMOVE_COMPONENT,
+        REFERENCE_DESPAWN_ENTITY,
+    };
+
     ECSWorldBase();
     virtual Ref<ComponentInfo> register_component(const StringName &name, const Ref<ComponentDefinition> &componentDefinition) = 0;
     virtual Ref<ComponentInfo> register_script_component(const StringName &name, Ref<Script> resource) = 0;
     virtual Ref<Entity> create_entity() = 0;
+    virtual Ref<Entity> spawn_entity(const PoolStringArray &components) = 0;
+    virtual Ref<Entity> get_entity(const String &id) const = 0;
+    virtual Error release_entity(Ref<Entity> entity) = 0;
+    virtual bool is_entity_current(Ref<Entity> entity) const = 0;
+    virtual int get_pooled_entity_count() const = 0;
+    virtual Error set_entity_id_generation(int mode, uint64_t seed) = 0;
+    virtual Error set_entity_reference_policy(int policy) = 0;
+    virtual Error set_component_entity_reference_policy(const StringName &component, int policy) = 0;
     virtual PoolStringArray get_components_of_entity(Ref<Entity> entity) = 0;
-    virtual void register_entity(Ref<Entity> entity) = 0;
+    virtual Dictionary get_component(Ref<Entity> entity, const StringName &component) = 0;
+    virtual Error set_component(Ref<Entity> entity, const StringName &component, const Dictionary &data) = 0;
+    virtual Array get_all_components(const StringName &component, bool include_disabled) = 0;
+    virtual PoolStringArray spawn_batch(int count, const StringName &component, const Dictionary &data) = 0;
+    virtual PoolIntArray set_component_batch(const PoolStringArray &entities, const StringName &component,
+                                             const Array &data) = 0;
+    virtual Error register_entity(Ref<Entity> entity) = 0;
+    virtual Array get_component_names() const = 0;
+    virtual Ref<ComponentInfo> get_component_info(const StringName &name) const = 0;
+    virtual StringName get_component_name(Ref<ComponentInfo> info) const = 0;
+    virtual Dictionary get_component_definition(const StringName &name) const = 0;
+    virtual Dictionary get_component_definition_by_info(Ref<ComponentInfo> info) const = 0;
+    virtual Error despawn_entity(Ref<Entity> entity) = 0;
+    virtual Error remove_component_from_entity(Ref<Entity> entity, const StringName &component) = 0;
+    virtual Error set_entity_enabled(Ref<Entity> entity, bool enabled) = 0;
+    virtual bool is_entity_enabled(Ref<Entity> entity) const = 0;
+    virtual Error set_component_enabled(Ref<Entity> entity, const StringName &component, bool enabled) = 0;
+    virtual bool is_component_enabled(Ref<Entity> entity, const StringName &component) const = 0;
+    virtual void set_journal_enabled(bool enabled) = 0;
+    virtual bool is_journal_enabled() const = 0;
+    virtual Error begin_journal_group(const StringName &name) = 0;
+    virtual Error end_journal_group() = 0;
+    virtual Error undo() = 0;
+    virtual Error redo() = 0;
+    virtual StringName get_undo_name() const = 0;
+    virtual StringName get_redo_name() const = 0;
+    virtual void start_recording(uint64_t seed) = 0;
+    virtual Error stop_recording(const String &path) = 0;
+    virtual bool is_recording() const = 0;
+    virtual void advance_frame() = 0;
+    virtual uint64_t get_frame() const = 0;
+    virtual uint64_t checksum() const = 0;
+    virtual Error replay(const String &path) = 0;
 };
 
 
@@ -11,6 +11,7 @@ class Entity : public Reference {
 
 private:
     rust::box<gcs::ffi::EntityId> entityId;
+    uint32_t generation;
     void set_entity_id(gcs::ffi::EntityId* argEntityId);
     gcs::ffi::EntityId& get_entity_id();
 
@@ -21,6 +22,7 @@ public:
     Entity();
 
     StringName get_id() const;
+    uint32_t get_generation() const;
 };
 
 
@@ -24,7 +24,7 @@ void ComponentFieldDefinition::_bind_methods() {
     ClassDB::bind_method(D_METHOD("get_field_type"), &ComponentFieldDefinition::get_type);
 
     ADD_PROPERTY(PropertyInfo(Variant::STRING, "name"), "set_name", "get_name");
-    ADD_PROPERTY(PropertyInfo(Variant::INT, "field_type", PROPERTY_HINT_ENUM, "NIL,BOOL,INT,REAL,STRING"), "set_field_type", "get_field_type");
+    ADD_PROPERTY(PropertyInfo(Variant::INT, "field_type", PROPERTY_HINT_ENUM, "NIL,BOOL,INT,REAL,STRING,VECTOR2,RECT2,VECTOR3,TRANSFORM2D,PLANE,QUAT,AABB,BASIS,TRANSFORM,COLOR,DICTIONARY:18,ARRAY:19,POOL_BYTE_ARRAY,POOL_INT_ARRAY,POOL_REAL_ARRAY,POOL_STRING_ARRAY,POOL_VECTOR2_ARRAY,POOL_VECTOR3_ARRAY,POOL_COLOR_ARRAY,ENTITY:28"), "set_field_type", "get_field_type");
 }
 
 gcs::ffi::ComponentFieldDefinition ComponentFieldDefinition::get_definition() {
@@ -13,5 +13,10 @@ gcs::ffi::ComponentInfo &ComponentInfo::getComponentInfo() {
     return componentInfo.operator*();
 }
 
+uint64_t ComponentInfo::get_hash() const {
+    return componentInfo->get_hash();
+}
+
 void ComponentInfo::_bind_methods() {
+    ClassDB::bind_method(D_METHOD("get_hash"), &ComponentInfo::get_hash);
 }
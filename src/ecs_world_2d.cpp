@@ -6,8 +6,67 @@ void ECSWorld2D::_bind_methods() {
     ClassDB::bind_method(D_METHOD("register_script_component", "name", "resource"),
                          &ECSWorld2D::register_script_component);
     ClassDB::bind_method(D_METHOD("create_entity"), &ECSWorld2D::create_entity);
+    ClassDB::bind_method(D_METHOD("set_entity_id_generation", "mode", "seed"), &ECSWorld2D::set_entity_id_generation,
+                         DEFVAL(0));
+    ClassDB::bind_method(D_METHOD("set_entity_reference_policy", "policy"),
+                         &ECSWorld2D::set_entity_reference_policy);
+    ClassDB::bind_method(D_METHOD("set_component_entity_reference_policy", "component", "policy"),
+                         &ECSWorld2D::set_component_entity_reference_policy);
     ClassDB::bind_method(D_METHOD("register_entity", "entity"), &ECSWorld2D::register_entity);
+    ClassDB::bind_method(D_METHOD("spawn_entity", "components"), &ECSWorld2D::spawn_entity);
+    ClassDB::bind_method(D_METHOD("get_entity", "id"), &ECSWorld2D::get_entity);
+    ClassDB::bind_method(D_METHOD("release_entity", "entity"), &ECSWorld2D::release_entity);
+    ClassDB::bind_method(D_METHOD("is_entity_current", "entity"), &ECSWorld2D::is_entity_current);
+    ClassDB::bind_method(D_METHOD("get_pooled_entity_count"), &ECSWorld2D::get_pooled_entity_count);
     ClassDB::bind_method(D_METHOD("get_components_of_entity", "entity"), &ECSWorld2D::get_components_of_entity);
+    ClassDB::bind_method(D_METHOD("get_component", "entity", "component"), &ECSWorld2D::get_component);
+    ClassDB::bind_method(D_METHOD("set_component", "entity", "component", "data"), &ECSWorld2D::set_component);
+    ClassDB::bind_method(D_METHOD("get_all_components", "component", "include_disabled"),
+                         &ECSWorld2D::get_all_components, DEFVAL(false));
+    ClassDB::bind_method(D_METHOD("spawn_batch", "count", "component", "data"), &ECSWorld2D::spawn_batch);
+    ClassDB::bind_method(D_METHOD("set_component_batch", "entities", "component", "data"),
+                         &ECSWorld2D::set_component_batch);
+    ClassDB::bind_method(D_METHOD("get_component_names"), &ECSWorld2D::get_component_names);
+    ClassDB::bind_method(D_METHOD("get_component_info", "name"), &ECSWorld2D::get_component_info);
+    ClassDB::bind_method(D_METHOD("get_component_name", "info"), &ECSWorld2D::get_component_name);
+    ClassDB::bind_method(D_METHOD("get_component_definition", "name"), &ECSWorld2D::get_component_definition);
+    ClassDB::bind_method(D_METHOD("get_component_definition_by_info", "info"),
+                         &ECSWorld2D::get_component_definition_by_info);
+    ClassDB::bind_method(D_METHOD("despawn_entity", "entity"), &ECSWorld2D::despawn_entity);
+    ClassDB::bind_method(D_METHOD("remove_component_from_entity", "entity", "component"),
+                         &ECSWorld2D::remove_component_from_entity);
+    ClassDB::bind_method(D_METHOD("set_entity_enabled", "entity", "enabled"), &ECSWorld2D::set_entity_enabled);
+    ClassDB::bind_method(D_METHOD("is_entity_enabled", "entity"), &ECSWorld2D::is_entity_enabled);
+    ClassDB::bind_method(D_METHOD("set_component_enabled", "entity", "component", "enabled"),
+                         &ECSWorld2D::set_component_enabled);
+    ClassDB::bind_method(D_METHOD("is_component_enabled", "entity", "component"),
+                         &ECSWorld2D::is_component_enabled);
+    ClassDB::bind_method(D_METHOD("set_journal_enabled", "enabled"), &ECSWorld2D::set_journal_enabled);
+    ClassDB::bind_method(D_METHOD("is_journal_enabled"), &ECSWorld2D::is_journal_enabled);
+    ClassDB::bind_method(D_METHOD("begin_journal_group", "name"), &ECSWorld2D::begin_journal_group);
+    ClassDB::bind_method(D_METHOD("end_journal_group"), &ECSWorld2D::end_journal_group);
+    ClassDB::bind_method(D_METHOD("undo"), &ECSWorld2D::undo);
+    ClassDB::bind_method(D_METHOD("redo"), &ECSWorld2D::redo);
+    ClassDB::bind_method(D_METHOD("get_undo_name"), &ECSWorld2D::get_undo_name);
+    ClassDB::bind_method(D_METHOD("get_redo_name"), &ECSWorld2D::get_redo_name);
+    ClassDB::bind_method(D_METHOD("start_recording", "seed"), &ECSWorld2D::start_recording);
+    ClassDB::bind_method(D_METHOD("stop_recording", "path"), &ECSWorld2D::stop_recording);
+    ClassDB::bind_method(D_METHOD("is_recording"), &ECSWorld2D::is_recording);
+    ClassDB::bind_method(D_METHOD("advance_frame"), &ECSWorld2D::advance_frame);
+    ClassDB::bind_method(D_METHOD("get_frame"), &ECSWorld2D::get_frame);
+    ClassDB::bind_method(D_METHOD("checksum"), &ECSWorld2D::checksum);
+    ClassDB::bind_method(D_METHOD("replay", "path"), &ECSWorld2D::replay);
+
+    ADD_PROPERTY(PropertyInfo(Variant::BOOL, "journal_enabled"), "set_journal_enabled", "is_journal_enabled");
+
+    BIND_CONSTANT(ID_GENERATION_RANDOM);
+    BIND_CONSTANT(ID_GENERATION_SEEDED);
+    BIND_CONSTANT(ID_GENERATION_SEQUENTIAL);
+    BIND_CONSTANT(ID_GENERATION_TIME_ORDERED);
+
+    BIND_CONSTANT(REFERENCE_SET_NIL);
+    BIND_CONSTANT(REFERENCE_REMOVE_COMPONENT);
+    BIND_CONSTANT(REFERENCE_DESPAWN_ENTITY);
 }
 
 Ref<ComponentInfo>
@@ -19,8 +78,60 @@ Ref<Entity> ECSWorld2D::create_entity() {
     return ECSWorldBase::create_entity();
 }
 
-void ECSWorld2D::register_entity(Ref<Entity> entity) {
-    ECSWorldBase::register_entity(entity);
+Ref<Entity> ECSWorld2D::spawn_entity(const PoolStringArray &components) {
+    return ECSWorldBase::spawn_entity(components);
+}
+
+Ref<Entity> ECSWorld2D::get_entity(const String &id) const {
+    return ECSWorldBase::get_entity(id);
+}
+
+Error ECSWorld2D::release_entity(Ref<Entity> entity) {
+    return ECSWorldBase::release_entity(entity);
+}
+
+bool ECSWorld2D::is_entity_current(Ref<Entity> entity) const {
+    return ECSWorldBase::is_entity_current(entity);
+}
+
+int ECSWorld2D::get_pooled_entity_count() const {
+    return ECSWorldBase::get_pooled_entity_count();
+}
+
+Error ECSWorld2D::set_entity_id_generation(int mode, uint64_t seed) {
+    return ECSWorldBase::set_entity_id_generation(mode, seed);
+}
+
+Error ECSWorld2D::set_entity_reference_policy(int policy) {
+    return ECSWorldBase::set_entity_reference_policy(policy);
+}
+
+Error ECSWorld2D::set_component_entity_reference_policy(const StringName &component, int policy) {
+    return ECSWorldBase::set_component_entity_reference_policy(component, policy);
+}
+
+Error ECSWorld2D::register_entity(Ref<Entity> entity) {
+    return ECSWorldBase::register_entity(entity);
+}
+
+Array ECSWorld2D::get_component_names() const {
+    return ECSWorldBase::get_component_names();
+}
+
+Ref<ComponentInfo> ECSWorld2D::get_component_info(const StringName &name) const {
+    return ECSWorldBase::get_component_info(name);
+}
+
+StringName ECSWorld2D::get_component_name(Ref<ComponentInfo> info) const {
+    return ECSWorldBase::get_component_name(info);
+}
+
+Dictionary ECSWorld2D::get_component_definition(const StringName &name) const {
+    return ECSWorldBase::get_component_definition(name);
+}
+
+Dictionary ECSWorld2D::get_component_definition_by_info(Ref<ComponentInfo> info) const {
+    return ECSWorldBase::get_component_definition_by_info(info);
 }
 
 Ref<ComponentInfo> ECSWorld2D::register_script_component(const StringName &name, Ref<Script> resource) {
@@ -30,3 +141,108 @@ Ref<ComponentInfo> ECSWorld2D::register_script_component(const StringName &name,
 PoolStringArray ECSWorld2D::get_components_of_entity(Ref<Entity> entity) {
     return ECSWorldBase::get_components_of_entity(entity);
 }
+
+Dictionary ECSWorld2D::get_component(Ref<Entity> entity, const StringName &component) {
+    return ECSWorldBase::get_component(entity, component);
+}
+
+Error ECSWorld2D::set_component(Ref<Entity> entity, const StringName &component, const Dictionary &data) {
+    return ECSWorldBase::set_component(entity, component, data);
+}
+
+Array ECSWorld2D::get_all_components(const StringName &component, bool include_disabled) {
+    return ECSWorldBase::get_all_components(component, include_disabled);
+}
+
+PoolStringArray ECSWorld2D::spawn_batch(int count, const StringName &component, const Dictionary &data) {
+    return ECSWorldBase::spawn_batch(count, component, data);
+}
+
+PoolIntArray ECSWorld2D::set_component_batch(const PoolStringArray &entities, const StringName &component,
+                                             const Array &data) {
+    return ECSWorldBase::set_component_batch(entities, component, data);
+}
+
+Error ECSWorld2D::despawn_entity(Ref<Entity> entity) {
+    return ECSWorldBase::despawn_entity(entity);
+}
+
+Error ECSWorld2D::remove_component_from_entity(Ref<Entity> entity, const StringName &component) {
+    return ECSWorldBase::remove_component_from_entity(entity, component);
+}
+
+Error ECSWorld2D::set_entity_enabled(Ref<Entity> entity, bool enabled) {
+    return ECSWorldBase::set_entity_enabled(entity, enabled);
+}
+
+bool ECSWorld2D::is_entity_enabled(Ref<Entity> entity) const {
+    return ECSWorldBase::is_entity_enabled(entity);
+}
+
+Error ECSWorld2D::set_component_enabled(Ref<Entity> entity, const StringName &component, bool enabled) {
+    return ECSWorldBase::set_component_enabled(entity, component, enabled);
+}
+
+bool ECSWorld2D::is_component_enabled(Ref<Entity> entity, const StringName &component) const {
+    return ECSWorldBase::is_component_enabled(entity, component);
+}
+
+void ECSWorld2D::set_journal_enabled(bool enabled) {
+    ECSWorldBase::set_journal_enabled(enabled);
+}
+
+bool ECSWorld2D::is_journal_enabled() const {
+    return ECSWorldBase::is_journal_enabled();
+}
+
+Error ECSWorld2D::begin_journal_group(const StringName &name) {
+    return ECSWorldBase::begin_journal_group(name);
+}
+
+Error ECSWorld2D::end_journal_group() {
+    return ECSWorldBase::end_journal_group();
+}
+
+Error ECSWorld2D::undo() {
+    return ECSWorldBase::undo();
+}
+
+Error ECSWorld2D::redo() {
+    return ECSWorldBase::redo();
+}
+
+StringName ECSWorld2D::get_undo_name() const {
+    return ECSWorldBase::get_undo_name();
+}
+
+StringName ECSWorld2D::get_redo_name() const {
+    return ECSWorldBase::get_redo_name();
+}
+
+void ECSWorld2D::start_recording(uint64_t seed) {
+    ECSWorldBase::start_recording(seed);
+}
+
+Error ECSWorld2D::stop_recording(const String &path) {
+    return ECSWorldBase::stop_recording(path);
+}
+
+bool ECSWorld2D::is_recording() const {
+    return ECSWorldBase::is_recording();
+}
+
+void ECSWorld2D::advance_frame() {
+    ECSWorldBase::advance_frame();
+}
+
+uint64_t ECSWorld2D::get_frame() const {
+    return ECSWorldBase::get_frame();
+}
+
+uint64_t ECSWorld2D::checksum() const {
+    return ECSWorldBase::checksum();
+}
+
+Error ECSWorld2D::replay(const String &path) {
+    return ECSWorldBase::replay(path);
+}
@@ -1,5 +1,6 @@
 #include "ecs_world_base.h"
 #include "gcs-cxx/include/godot/string.h" // NOLINT(modernize-deprecated-headers)
+#include "gcs-cxx/include/godot/error.h"
 
 ECSWorldBase::ECSWorldBase() : world(gcs::ffi::create_ecs_world()) {
 
@@ -15,17 +16,128 @@ ECSWorldBase::register_component(const StringName &name, const Ref<ComponentDefi
     return {info_godot};
 }
 
-Ref<Entity> ECSWorldBase::create_entity() {
-    auto rust_entity = world->create_entity();
+Ref<Entity> ECSWorldBase::entity_from_id(gcs::ffi::EntityId *entity_id) const {
     auto entity = SAFE_CAST<Entity *>(ClassDB::creator<Entity>());
-    entity->set_entity_id(rust_entity.into_raw());
+    entity->set_entity_id(entity_id);
+    auto generation = world->get_entity_generation(entity->get_entity_id());
+    entity->generation = generation < 0 ? 0 : static_cast<uint32_t>(generation);
     return {entity};
 }
 
-void ECSWorldBase::register_entity(Ref<Entity> entity) {
+Ref<Entity> ECSWorldBase::create_entity() {
+    return entity_from_id(world->create_entity().into_raw());
+}
+
+Ref<Entity> ECSWorldBase::spawn_entity(const PoolStringArray &components) {
+    auto component_names = ::rust::Vec<::rust::String>();
+    component_names.reserve(components.size());
+    for (int i = 0; i < components.size(); ++i) {
+        component_names.push_back(godot_string_to_rust_string(components[i]));
+    }
+    auto result = world->spawn_entity(std::move(component_names));
+    ERR_FAIL_COND_V_MSG(result->is_error(), Ref<Entity>(), string_name_from_rust_string(result->get_error()));
+    return entity_from_id(result->get_result().into_raw());
+}
+
+Ref<Entity> ECSWorldBase::get_entity(const String &id) const {
+    auto result = gcs::ffi::entity_id_from_string(godot_string_to_rust_string(id));
+    ERR_FAIL_COND_V_MSG(result->is_error(), Ref<Entity>(), string_name_from_rust_string(result->get_error()));
+    return entity_from_id(result->get_result().into_raw());
+}
+
+Error ECSWorldBase::release_entity(Ref<Entity> entity) {
+    ERR_FAIL_COND_V(entity.is_null(), ERR_INVALID_PARAMETER);
+    auto result = world->release_entity(entity->get_entity_id());
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+bool ECSWorldBase::is_entity_current(Ref<Entity> entity) const {
+    ERR_FAIL_COND_V(entity.is_null(), false);
+    return world->get_entity_generation(entity->get_entity_id()) == static_cast<int64_t>(entity->generation);
+}
+
+int ECSWorldBase::get_pooled_entity_count() const {
+    return static_cast<int>(world->get_pooled_entity_count());
+}
+
+Error ECSWorldBase::set_entity_id_generation(int mode, uint64_t seed) {
+    ERR_FAIL_COND_V_MSG(mode < 0 || mode > 255, ERR_INVALID_PARAMETER, "Invalid entity id generation mode");
+    auto result = world->set_entity_id_generation(static_cast<uint8_t>(mode), seed);
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::set_entity_reference_policy(int policy) {
+    ERR_FAIL_COND_V_MSG(policy < 0 || policy > 255, ERR_INVALID_PARAMETER, "Invalid entity reference policy");
+    auto result = world->set_entity_reference_policy(static_cast<uint8_t>(policy));
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::set_component_entity_reference_policy(const StringName &component, int policy) {
+    ERR_FAIL_COND_V_MSG(policy < 0 || policy > 255, ERR_INVALID_PARAMETER, "Invalid entity reference policy");
+    auto result = world->set_component_entity_reference_policy(godot_string_to_rust_string(component),
+                                                               static_cast<uint8_t>(policy));
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::register_entity(Ref<Entity> entity) {
     auto result= world->register_entity(entity->get_entity_id());
 
-    ERR_FAIL_COND_MSG(result->is_error(), string_name_from_rust_string(result->get_error()));
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    entity->generation = static_cast<uint32_t>(world->get_entity_generation(entity->get_entity_id()));
+    return OK;
+}
+
+Array ECSWorldBase::get_component_names() const {
+    auto array = Array();
+    for (const auto &name : world->get_component_names()) {
+        array.push_back(string_name_from_rust_string(name));
+    }
+    return array;
+}
+
+Ref<ComponentInfo> ECSWorldBase::get_component_info(const StringName &name) const {
+    auto result = world->get_component_info(godot_string_to_rust_string(name));
+    ERR_FAIL_COND_V_MSG(result->is_error(), {}, string_name_from_rust_string(result->get_error()));
+    auto info_godot = SAFE_CAST<ComponentInfo *>(ClassDB::creator<ComponentInfo>());
+    info_godot->set_component_info(result->get_result().into_raw());
+    return {info_godot};
+}
+
+StringName ECSWorldBase::get_component_name(Ref<ComponentInfo> info) const {
+    ERR_FAIL_COND_V(info.is_null(), {});
+    auto result = world->get_component_name(info->getComponentInfo());
+    ERR_FAIL_COND_V_MSG(result->is_error(), {}, string_name_from_rust_string(result->get_error()));
+    return string_name_from_rust_string(result->get_result());
+}
+
+Dictionary ECSWorldBase::get_component_definition(const StringName &name) const {
+    auto result = world->get_component_fields(godot_string_to_rust_string(name));
+    ERR_FAIL_COND_V_MSG(result->is_error(), {}, string_name_from_rust_string(result->get_error()));
+    return fields_to_dictionary(result->get_result());
+}
+
+Dictionary ECSWorldBase::get_component_definition_by_info(Ref<ComponentInfo> info) const {
+    ERR_FAIL_COND_V(info.is_null(), {});
+    auto result = world->get_component_fields_by_info(info->getComponentInfo());
+    ERR_FAIL_COND_V_MSG(result->is_error(), {}, string_name_from_rust_string(result->get_error()));
+    return fields_to_dictionary(result->get_result());
+}
+
+Dictionary ECSWorldBase::fields_to_dictionary(const ::rust::Vec<::gcs::ffi::ComponentFieldDefinition> &fields) {
+    auto dictionary = Dictionary();
+    for (const auto &field : fields) {
+        dictionary[string_name_from_rust_string(field.name)] = static_cast<int>(field.field_type);
+    }
+    return dictionary;
 }
 
 Ref<ComponentInfo> ECSWorldBase::register_script_component(const StringName &name, Ref<Script> resource) {
@@ -55,3 +167,220 @@ PoolStringArray ECSWorldBase::get_components_of_entity(Ref<Entity> entity) {
     }
     return array;
 }
+
+Dictionary ECSWorldBase::get_component(Ref<Entity> entity, const StringName &component) {
+    ERR_FAIL_COND_V(entity.is_null(), {});
+    auto result = world->get_component_of_entity(entity->get_entity_id(), godot_string_to_rust_string(component));
+    ERR_FAIL_COND_V_MSG(result->is_error(), {}, string_name_from_rust_string(result->get_error()));
+
+    auto dictionary = Dictionary();
+    auto conversion = world->component_data_to_dictionary(godot_string_to_rust_string(component),
+                                                          *result->get_result(), dictionary);
+    ERR_FAIL_COND_V_MSG(conversion->is_error(), {}, string_name_from_rust_string(conversion->get_error()));
+    return dictionary;
+}
+
+Error ECSWorldBase::set_component(Ref<Entity> entity, const StringName &component, const Dictionary &data) {
+    ERR_FAIL_COND_V(entity.is_null(), ERR_INVALID_PARAMETER);
+    auto &entity_id = entity->get_entity_id();
+    auto conversion = world->component_data_from_dictionary(entity_id, godot_string_to_rust_string(component), data);
+    ERR_FAIL_COND_V_MSG(conversion->is_error(), error_from_error_code(conversion->get_error_code()),
+                        string_name_from_rust_string(conversion->get_error()));
+
+    auto result = world->set_component_data(entity_id, godot_string_to_rust_string(component),
+                                            *conversion->get_result());
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Array ECSWorldBase::get_all_components(const StringName &component, bool include_disabled) {
+    auto array = Array();
+    auto result = world->get_component_data(godot_string_to_rust_string(component), include_disabled);
+    ERR_FAIL_COND_V_MSG(result->is_error(), array, string_name_from_rust_string(result->get_error()));
+
+    for (size_t i = 0; i < result->get_len(); ++i) {
+        const auto &component_data = result->get_item(i);
+        auto entity = entity_from_id(component_data.get_entity().into_raw());
+
+        auto data = Dictionary();
+        auto conversion = world->component_data_to_dictionary(godot_string_to_rust_string(component),
+                                                              component_data, data);
+        ERR_FAIL_COND_V_MSG(conversion->is_error(), array, string_name_from_rust_string(conversion->get_error()));
+
+        auto item = Dictionary();
+        item["entity"] = entity;
+        item["data"] = data;
+        array.push_back(item);
+    }
+    return array;
+}
+
+PoolStringArray ECSWorldBase::spawn_batch(int count, const StringName &component, const Dictionary &data) {
+    auto entities = PoolStringArray();
+    ERR_FAIL_COND_V_MSG(count < 0, entities, "Batch size must not be negative");
+    auto result = world->spawn_batch(static_cast<size_t>(count), godot_string_to_rust_string(component), data);
+    ERR_FAIL_COND_V_MSG(result->is_error(), entities, string_name_from_rust_string(result->get_error()));
+
+    auto entity_ids = result->get_entities();
+    entities.resize(static_cast<int>(entity_ids.size()));
+    {
+        auto writer = entities.write();
+        for (size_t i = 0; i < entity_ids.size(); ++i) {
+            writer[static_cast<int>(i)] = string_name_from_rust_string(entity_ids[i]);
+        }
+    }
+    return entities;
+}
+
+PoolIntArray ECSWorldBase::set_component_batch(const PoolStringArray &entities, const StringName &component,
+                                               const Array &data) {
+    auto entity_ids = ::rust::Vec<::rust::String>();
+    entity_ids.reserve(entities.size());
+    for (int i = 0; i < entities.size(); ++i) {
+        entity_ids.push_back(godot_string_to_rust_string(entities[i]));
+    }
+    auto result = world->set_component_data_batch(std::move(entity_ids), godot_string_to_rust_string(component),
+                                                  Variant(data));
+    if (result->is_error()) {
+        ERR_PRINT(String(string_name_from_rust_string(result->get_error())));
+    }
+
+    // Without items the whole batch failed, which is the error of every entity.
+    auto codes = result->get_error_codes();
+    auto batch_error = error_from_error_code(result->get_error_code());
+    auto errors = PoolIntArray();
+    errors.resize(entities.size());
+    {
+        auto writer = errors.write();
+        for (int i = 0; i < entities.size(); ++i) {
+            writer[i] = static_cast<size_t>(i) < codes.size()
+                            ? error_from_error_code(static_cast<gcs::ffi::ErrorCode>(codes[i]))
+                            : batch_error;
+        }
+    }
+    return errors;
+}
+
+Error ECSWorldBase::despawn_entity(Ref<Entity> entity) {
+    auto result = world->despawn_entity(entity->get_entity_id());
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::remove_component_from_entity(Ref<Entity> entity, const StringName &component) {
+    auto result = world->remove_component_from_entity(entity->get_entity_id(), godot_string_to_rust_string(component));
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::set_entity_enabled(Ref<Entity> entity, bool enabled) {
+    ERR_FAIL_COND_V(entity.is_null(), ERR_INVALID_PARAMETER);
+    auto result = world->set_entity_enabled(entity->get_entity_id(), enabled);
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+bool ECSWorldBase::is_entity_enabled(Ref<Entity> entity) const {
+    ERR_FAIL_COND_V(entity.is_null(), false);
+    return world->is_entity_enabled(entity->get_entity_id());
+}
+
+Error ECSWorldBase::set_component_enabled(Ref<Entity> entity, const StringName &component, bool enabled) {
+    ERR_FAIL_COND_V(entity.is_null(), ERR_INVALID_PARAMETER);
+    auto result = world->set_component_enabled(entity->get_entity_id(), godot_string_to_rust_string(component),
+                                               enabled);
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+bool ECSWorldBase::is_component_enabled(Ref<Entity> entity, const StringName &component) const {
+    ERR_FAIL_COND_V(entity.is_null(), false);
+    return world->is_component_enabled(entity->get_entity_id(), godot_string_to_rust_string(component));
+}
+
+void ECSWorldBase::set_journal_enabled(bool enabled) {
+    if (enabled) {
+        world->enable_journal();
+    } else {
+        world->disable_journal();
+    }
+}
+
+bool ECSWorldBase::is_journal_enabled() const {
+    return world->is_journal_enabled();
+}
+
+Error ECSWorldBase::begin_journal_group(const StringName &name) {
+    auto result = world->begin_journal_group(godot_string_to_rust_string(name));
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::end_journal_group() {
+    auto result = world->end_journal_group();
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::undo() {
+    auto result = world->undo();
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+Error ECSWorldBase::redo() {
+    auto result = world->redo();
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+StringName ECSWorldBase::get_undo_name() const {
+    return string_name_from_rust_string(world->get_undo_name());
+}
+
+StringName ECSWorldBase::get_redo_name() const {
+    return string_name_from_rust_string(world->get_redo_name());
+}
+
+void ECSWorldBase::start_recording(uint64_t seed) {
+    world->start_recording(seed);
+}
+
+Error ECSWorldBase::stop_recording(const String &path) {
+    auto result = world->stop_recording(godot_string_to_rust_string(path));
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
+
+bool ECSWorldBase::is_recording() const {
+    return world->is_recording();
+}
+
+void ECSWorldBase::advance_frame() {
+    world->advance_frame();
+}
+
+uint64_t ECSWorldBase::get_frame() const {
+    return world->get_frame();
+}
+
+uint64_t ECSWorldBase::checksum() const {
+    return world->checksum();
+}
+
+Error ECSWorldBase::replay(const String &path) {
+    auto result = world->replay(godot_string_to_rust_string(path));
+    ERR_FAIL_COND_V_MSG(result->is_error(), error_from_error_code(result->get_error_code()),
+                        string_name_from_rust_string(result->get_error()));
+    return OK;
+}
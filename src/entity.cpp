@@ -1,7 +1,7 @@
 #include "entity.h"
 #include "gcs-cxx/include/godot/string.h" // NOLINT(modernize-deprecated-headers)
 
-Entity::Entity() : entityId(gcs::ffi::create_entity()) {
+Entity::Entity() : entityId(gcs::ffi::create_entity()), generation(0) {
 
 }
 
@@ -17,6 +17,11 @@ StringName Entity::get_id() const {
     return string_name_from_rust_string(entityId->as_string());
 }
 
+uint32_t Entity::get_generation() const {
+    return generation;
+}
+
 void Entity::_bind_methods() {
     ClassDB::bind_method(D_METHOD("get_id"), &Entity::get_id);
+    ClassDB::bind_method(D_METHOD("get_generation"), &Entity::get_generation);
 }